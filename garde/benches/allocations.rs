@@ -0,0 +1,241 @@
+//! Counts the allocations made while validating an invalid struct with many fields,
+//! comparing a report without a capacity hint against the one used by the derive.
+//!
+//! Also counts the allocations of a flat struct with a single field, whose path is stored inline,
+//! and of a nested one, whose path is too deep to be stored inline.
+//!
+//! Then counts the allocations of a struct whose failures all have constant messages,
+//! which are stored without copying them, against the same failures with owned messages.
+//! Only the messages longer than 24 bytes, which can't be stored inline, make a difference.
+//!
+//! Finally counts the allocations of validating a batch of values, with a report per value
+//! and with a single report which is reused via `validate_at`.
+//!
+//! The savings are asserted by `tests/allocations.rs`, this only prints the counts.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use garde::{Path, Report, Validate};
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    a: &'a str,
+    #[garde(length(min = 1))]
+    b: &'a str,
+    #[garde(length(min = 1))]
+    c: &'a str,
+    #[garde(length(min = 1))]
+    d: &'a str,
+    #[garde(length(min = 1))]
+    e: &'a str,
+    #[garde(length(min = 1))]
+    f: &'a str,
+    #[garde(length(min = 1))]
+    g: &'a str,
+    #[garde(length(min = 1))]
+    h: &'a str,
+    #[garde(range(min = 1))]
+    i: i32,
+    #[garde(range(min = 1))]
+    j: i32,
+    #[garde(range(min = 1))]
+    k: i32,
+    #[garde(range(min = 1))]
+    l: i32,
+    #[garde(range(min = 1))]
+    m: i32,
+    #[garde(range(min = 1))]
+    n: i32,
+    #[garde(range(min = 1))]
+    o: i32,
+    #[garde(range(min = 1))]
+    p: i32,
+    #[garde(ascii)]
+    q: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Flat<'a> {
+    #[garde(ascii)]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Nested<'a> {
+    #[garde(dive)]
+    inner: Flat<'a>,
+    #[garde(dive)]
+    items: Vec<Nested<'a>>,
+}
+
+#[derive(Debug, Clone, garde::Validate)]
+struct Static<'a> {
+    #[garde(isbn)]
+    a: &'a str,
+    #[garde(checksum(algo = "mod10"))]
+    b: &'a str,
+    #[garde(not(ipv4))]
+    c: &'a str,
+    #[garde(not(alphanumeric))]
+    d: &'a str,
+    #[garde(required)]
+    e: Option<&'a str>,
+}
+
+fn owned(message: &'static str) -> impl FnOnce(&&str, &()) -> garde::Result {
+    move |_, _| Err(garde::Error::new(message))
+}
+
+#[derive(Debug, garde::Validate)]
+struct Owned<'a> {
+    #[garde(custom(owned("ISBN must have 10 or 13 digits")))]
+    a: &'a str,
+    #[garde(custom(owned("too short to contain a check digit")))]
+    b: &'a str,
+    #[garde(custom(owned("must not be an IPv4 address")))]
+    c: &'a str,
+    #[garde(custom(owned("must not be alphanumeric")))]
+    d: &'a str,
+    #[garde(custom(owned("not set")))]
+    e: &'a str,
+}
+
+fn count(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn main() {
+    let input = Test {
+        a: "",
+        b: "",
+        c: "",
+        d: "",
+        e: "",
+        f: "",
+        g: "",
+        h: "",
+        i: 0,
+        j: 0,
+        k: 0,
+        l: 0,
+        m: 0,
+        n: 0,
+        o: 0,
+        p: 0,
+        q: "😂",
+    };
+
+    let without_hint = count(|| {
+        let mut report = Report::new();
+        input.validate_into(&(), &mut Path::empty, &mut report);
+        drop(std::hint::black_box(report));
+    });
+    let with_hint = count(|| {
+        let report = input.validate(&());
+        drop(std::hint::black_box(report));
+    });
+
+    println!("allocations without capacity hint: {without_hint}");
+    println!("allocations with capacity hint:    {with_hint}");
+
+    let flat = Flat { value: "😂" };
+    let flat = count(|| {
+        let report = flat.validate(&());
+        drop(std::hint::black_box(report));
+    });
+    let nested = Nested {
+        inner: Flat { value: "a" },
+        items: vec![Nested {
+            inner: Flat { value: "😂" },
+            items: vec![],
+        }],
+    };
+    let nested = count(|| {
+        let report = nested.validate(&());
+        drop(std::hint::black_box(report));
+    });
+
+    println!("allocations for a flat struct:     {flat}");
+    println!("allocations for a nested struct:   {nested}");
+
+    let input = Static {
+        a: "123",
+        b: "1",
+        c: "127.0.0.1",
+        d: "abc",
+        e: None,
+    };
+    let static_messages = count(|| {
+        let report = input.validate(&());
+        drop(std::hint::black_box(report));
+    });
+
+    let owned = Owned {
+        a: "",
+        b: "",
+        c: "",
+        d: "",
+        e: "",
+    };
+    let owned_messages = count(|| {
+        let report = owned.validate(&());
+        drop(std::hint::black_box(report));
+    });
+
+    println!("allocations for static messages:   {static_messages}");
+    println!("allocations for owned messages:    {owned_messages}");
+    println!(
+        "  saved by static messages:        {}",
+        owned_messages - static_messages
+    );
+
+    let batch = vec![Static { ..input }; 100];
+    let per_value = count(|| {
+        for value in &batch {
+            drop(std::hint::black_box(value.validate(&())));
+        }
+    });
+    let mut report = Report::new();
+    // Warm up, so the report's buffer is already large enough.
+    for value in &batch {
+        report.clear();
+        value.validate_at(&(), &mut report, Path::empty());
+    }
+    let reused = count(|| {
+        for value in &batch {
+            report.clear();
+            value.validate_at(&(), &mut report, Path::empty());
+            std::hint::black_box(&report);
+        }
+    });
+
+    println!("allocations for a batch of 100:    {per_value}");
+    println!("  with a reused report:            {reused}");
+}
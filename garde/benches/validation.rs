@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use garde::Validate;
+use garde::{Path, Report, Validate};
 
 #[derive(Debug, garde::Validate)]
 struct Test<'a> {
@@ -150,6 +150,30 @@ fn validate(c: &mut Criterion) {
     }
 }
 
+fn batch(c: &mut Criterion) {
+    let inputs = (0..100)
+        .map(|_| invalid_input!(invalid_input!()))
+        .collect::<Vec<_>>();
+
+    c.bench_function("batch `validate`", |b| {
+        b.iter(|| {
+            for input in &inputs {
+                let _ = black_box(input.validate(&()));
+            }
+        })
+    });
+    c.bench_function("batch `validate_at` with a reused report", |b| {
+        let mut report = Report::new();
+        b.iter(|| {
+            report.clear();
+            for (i, input) in inputs.iter().enumerate() {
+                input.validate_at(&(), &mut report, Path::new(i));
+            }
+            black_box(&report);
+        })
+    });
+}
+
 fn display(c: &mut Criterion) {
     let inputs = vec![
         (
@@ -174,5 +198,5 @@ fn display(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, validate, display);
+criterion_group!(benches, validate, batch, display);
 criterion_main!(benches);
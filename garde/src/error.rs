@@ -3,13 +3,33 @@
 //! The entrypoint of this module is the [`Error`] type.
 #![allow(dead_code)]
 
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+mod json;
 mod rc_list;
+mod selector;
+#[cfg(feature = "insta")]
+mod snapshot;
+#[cfg(feature = "serde")]
+mod tree;
+#[cfg(feature = "validator-compat")]
+mod validator_compat;
 use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::hash::BuildHasher;
 
 use compact_str::{CompactString, ToCompactString};
 use smallvec::SmallVec;
 
 use self::rc_list::List;
+pub use self::selector::Selector;
+#[cfg(feature = "insta")]
+pub use self::snapshot::Snapshot;
+#[cfg(feature = "serde")]
+pub use self::tree::Tree;
+#[cfg(feature = "validator-compat")]
+pub use self::validator_compat::ValidatorCompat;
+use crate::validate::ValidationOptions;
 
 /// A validation error report.
 ///
@@ -17,33 +37,616 @@ use self::rc_list::List;
 /// It is a flat list of `(Path, Error)`.
 /// A single field or list item may have any number of errors attached to it.
 ///
-/// It is possible to extract all errors for specific field using the [`select`] macro.
-#[derive(Debug)]
+/// It is possible to extract all errors for specific field using the [`select`] macro,
+/// or using [`Report::select`] with a [`Selector`] built at runtime.
+///
+/// Cloning a report copies its list of errors, but not all of their contents:
+/// deep paths share their components with the original, and constant messages are not copied.
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Report {
     errors: Vec<(Path, Error)>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    capacity_hint: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    trace: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    value_debug: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    limit: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    collect_warnings: bool,
 }
 
 impl Report {
     /// Create an empty [`Report`].
     #[allow(clippy::new_without_default)]
     pub fn new() -> Self {
-        Self { errors: Vec::new() }
+        Self::with_capacity(0)
+    }
+
+    /// Create an empty [`Report`] with room for at least `capacity` errors.
+    ///
+    /// Nothing is allocated until the first error is appended,
+    /// so validating a valid value stays allocation-free.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            errors: Vec::new(),
+            capacity_hint: capacity,
+            trace: false,
+            value_debug: false,
+            limit: None,
+            collect_warnings: true,
+        }
+    }
+
+    /// Create a [`Report`] from `(Path, Error)` pairs, in order.
+    ///
+    /// This is mostly useful for testing code which consumes reports.
+    ///
+    /// ```rust
+    /// use garde::error::{Error, Path, Report};
+    ///
+    /// let report = Report::from_pairs([
+    ///     (Path::new("name"), Error::new_static("not set")),
+    ///     (Path::new("tags").join(0), Error::new_static("too long")),
+    /// ]);
+    /// assert_eq!(report.to_string(), "name: not set\ntags[0]: too long\n");
+    /// ```
+    pub fn from_pairs(pairs: impl IntoIterator<Item = (Path, Error)>) -> Self {
+        Self {
+            errors: pairs.into_iter().collect(),
+            ..Self::new()
+        }
+    }
+
+    /// Create an empty [`Report`] configured by `options`, as used by [`Validate::validate_with_options`][`crate::Validate::validate_with_options`].
+    ///
+    /// Once the report holds as many errors as the limit of `options`, further entries are dropped and [`Report::is_full`] returns `true`,
+    /// which stops the derived implementations of [`Validate`][`crate::Validate`] from validating any more fields.
+    pub fn with_options(options: &ValidationOptions) -> Self {
+        Self {
+            trace: options.trace,
+            value_debug: options.value_debug,
+            limit: options.limit(),
+            collect_warnings: options.collect_warnings,
+            ..Self::new()
+        }
+    }
+
+    /// Returns `true` if the report has reached the limit it was created with, see [`Report::with_options`].
+    ///
+    /// Only entries with [`Severity::Error`] count towards the limit, so warnings and notes never stop validation.
+    pub fn is_full(&self) -> bool {
+        self.limit.is_some_and(|limit| self.error_count() >= limit)
+    }
+
+    fn error_count(&self) -> usize {
+        self.errors
+            .iter()
+            .filter(|(_, error)| error.severity().is_error())
+            .count()
+    }
+
+    /// Record a note for every `None` which is skipped by `dive`.
+    ///
+    /// The notes have [`Severity::Info`] and the code `dive.none`, which makes it possible
+    /// to confirm that an optional subtree was skipped on purpose:
+    ///
+    /// ```rust
+    /// use garde::{Path, Report, Validate};
+    /// use garde::error::Severity;
+    ///
+    /// #[derive(garde::Validate)]
+    /// struct User {
+    ///     #[garde(dive)]
+    ///     address: Option<Address>,
+    /// }
+    ///
+    /// #[derive(garde::Validate)]
+    /// struct Address {
+    ///     #[garde(length(min = 1))]
+    ///     city: String,
+    /// }
+    ///
+    /// let mut report = Report::new().with_trace();
+    /// User { address: None }.validate_into(&(), &mut Path::empty, &mut report);
+    ///
+    /// let (path, note) = report.iter().next().unwrap();
+    /// assert_eq!(path.to_string(), "address");
+    /// assert_eq!(note.severity(), Severity::Info);
+    /// ```
+    ///
+    /// This is off by default. To enable it for [`Validate::validate_with_options`][`crate::Validate::validate_with_options`],
+    /// set [`ValidationOptions::trace`]. The notes don't count as errors, so a valid value still validates as `Ok`.
+    pub fn with_trace(mut self) -> Self {
+        self.trace = true;
+        self
+    }
+
+    /// Returns `true` if this report records skipped values, see [`Report::with_trace`].
+    pub fn is_tracing(&self) -> bool {
+        self.trace
+    }
+
+    /// Attach the `Debug` representation of the offending value to every error of a built-in rule,
+    /// which helps when debugging from logs.
+    ///
+    /// The representation is truncated to [`Error::VALUE_DEBUG_MAX_LEN`] bytes, and is only attached
+    /// if the type of the value implements `Debug`. Fields marked with `#[garde(sensitive)]` never
+    /// include their value, and neither do errors returned by `custom` validators.
+    ///
+    /// ```rust
+    /// use garde::{Path, Report, Validate};
+    ///
+    /// #[derive(garde::Validate)]
+    /// struct User {
+    ///     #[garde(length(min = 3))]
+    ///     name: String,
+    ///     #[garde(sensitive, length(min = 8))]
+    ///     password: String,
+    /// }
+    ///
+    /// let user = User { name: "ab".into(), password: "hunter2".into() };
+    /// let mut report = Report::new().with_value_debug();
+    /// user.validate_into(&(), &mut Path::empty, &mut report);
+    ///
+    /// let values = report.iter().map(|(_, e)| e.value_debug()).collect::<Vec<_>>();
+    /// assert_eq!(values, [Some(r#""ab""#), None]);
+    /// ```
+    ///
+    /// This is off by default. To enable it for [`Validate::validate_with_options`][`crate::Validate::validate_with_options`],
+    /// set [`ValidationOptions::value_debug`].
+    pub fn with_value_debug(mut self) -> Self {
+        self.value_debug = true;
+        self
+    }
+
+    /// Returns `true` if this report captures offending values, see [`Report::with_value_debug`].
+    pub fn captures_value_debug(&self) -> bool {
+        self.value_debug
     }
 
     /// Append an [`Error`] into this report at the given [`Path`].
     pub fn append(&mut self, path: Path, error: Error) {
+        if self.is_full() || (!self.collect_warnings && error.severity() == Severity::Warning) {
+            return;
+        }
+        if self.errors.capacity() == 0 {
+            let capacity = match self.limit {
+                Some(limit) => self.capacity_hint.min(limit),
+                None => self.capacity_hint,
+            };
+            self.errors.reserve(capacity);
+        }
         self.errors.push((path, error));
     }
 
+    /// Insert an [`Error`] at the given [`Path`] before all other errors in this report.
+    ///
+    /// This is useful for a summary error which should come before the errors of the fields.
+    /// Errors are skipped in the same cases as by [`Report::append`].
+    ///
+    /// This moves every error already in the report, so it takes `O(n)` time. Prefer [`Report::append`] when building a report in a loop.
+    pub fn prepend(&mut self, path: Path, error: Error) {
+        if self.is_full() || (!self.collect_warnings && error.severity() == Severity::Warning) {
+            return;
+        }
+        self.errors.insert(0, (path, error));
+    }
+
+    /// Insert all errors from `other` before the errors in this report, preserving the order within each report.
+    ///
+    /// Errors are skipped in the same cases as by [`Report::append`], so once this report is full, the remaining errors of `other` are dropped.
+    /// Like [`Report::prepend`], this moves every error already in the report, so it takes `O(n + m)` time.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append(Path::new("name"), Error::new("too short"));
+    ///
+    /// let mut summary = Report::new();
+    /// summary.append(Path::empty(), Error::new("the form has errors"));
+    /// report.prepend_report(summary);
+    ///
+    /// assert_eq!(report.iter().next().unwrap().1.message(), "the form has errors");
+    /// ```
+    pub fn prepend_report(&mut self, other: Report) {
+        let (limit, collect_warnings) = (self.limit, self.collect_warnings);
+        let mut count = self.error_count();
+        let errors = other.errors.into_iter().filter(|(_, error)| {
+            if limit.is_some_and(|limit| count >= limit)
+                || (!collect_warnings && error.severity() == Severity::Warning)
+            {
+                return false;
+            }
+            count += error.severity().is_error() as usize;
+            true
+        });
+        self.errors.splice(0..0, errors);
+    }
+
+    /// Append an [`Error`] at the given [`Path`], unless this report already contains an identical pair.
+    ///
+    /// Returns `true` if the error was appended. As with [`Report::append`],
+    /// it is also not appended if the report is full, or if it is a warning which is not collected.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// assert!(report.insert_unique(Path::new("name"), Error::new("too short")));
+    /// assert!(!report.insert_unique(Path::new("name"), Error::new("too short")));
+    /// assert_eq!(report.iter().count(), 1);
+    /// ```
+    pub fn insert_unique(&mut self, path: Path, error: Error) -> bool {
+        if self.errors.iter().any(|(p, e)| *p == path && *e == error) {
+            return false;
+        }
+        let len = self.errors.len();
+        self.append(path, error);
+        self.errors.len() > len
+    }
+
+    /// Append all errors from `other` into this report, removing duplicate `(Path, Error)` pairs.
+    ///
+    /// Only the first occurrence of each pair is kept, and the order of the remaining errors is preserved.
+    /// The errors of `other` are skipped in the same cases as by [`Report::append`].
+    pub fn merge_dedup(&mut self, other: Report) {
+        let existing = std::mem::take(&mut self.errors);
+        self.errors.reserve(existing.len() + other.errors.len());
+
+        // The hashes of the kept entries, pointing at their indices in `self.errors`,
+        // which don't change because entries are only ever pushed.
+        let state = std::collections::hash_map::RandomState::new();
+        let mut seen = HashMap::<u64, SmallVec<[usize; 1]>>::with_capacity(self.errors.capacity());
+        let mut insert = |errors: &mut Vec<(Path, Error)>, entry: (Path, Error)| {
+            let indices = seen.entry(state.hash_one(&entry)).or_default();
+            if indices.iter().any(|&i| errors[i] == entry) {
+                return false;
+            }
+            indices.push(errors.len());
+            errors.push(entry);
+            true
+        };
+
+        for entry in existing {
+            insert(&mut self.errors, entry);
+        }
+        let mut count = self.error_count();
+        for entry in other.errors {
+            let is_error = entry.1.severity().is_error();
+            if self.limit.is_some_and(|limit| count >= limit)
+                || (!self.collect_warnings && entry.1.severity() == Severity::Warning)
+            {
+                continue;
+            }
+            if insert(&mut self.errors, entry) && is_error {
+                count += 1;
+            }
+        }
+    }
+
+    /// Keep only the first `max` errors, and return how many were dropped.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// for i in 0..5usize {
+    ///     report.append(Path::new(i), Error::new("invalid"));
+    /// }
+    ///
+    /// assert_eq!(report.truncate(3), 2);
+    /// assert_eq!(report.iter().count(), 3);
+    /// ```
+    pub fn truncate(&mut self, max: usize) -> usize {
+        let dropped = self.errors.len().saturating_sub(max);
+        self.errors.truncate(max);
+        dropped
+    }
+
+    /// Like [`Report::truncate`], but if any errors were dropped, also appends an error at the root path
+    /// with the message `and N more errors`, so the truncation is visible to whoever receives the report.
+    ///
+    /// The appended error has the code `truncated`, and the number of dropped errors as the `count` parameter.
+    /// It comes after the first `max` errors, so the report may end up with `max + 1` errors.
+    pub fn truncate_with_marker(&mut self, max: usize) -> usize {
+        let dropped = self.truncate(max);
+        if dropped > 0 {
+            let message = match dropped {
+                1 => String::from("and 1 more error"),
+                n => format!("and {n} more errors"),
+            };
+            self.errors.push((
+                Path::empty(),
+                Error::builder()
+                    .message(message)
+                    .code("truncated")
+                    .param("count", dropped)
+                    .build(),
+            ));
+        }
+        dropped
+    }
+
+    /// A cheap estimate of how many bytes this report takes up once serialized.
+    ///
+    /// This is the size of the output of [`Report::write_json`] without escaping,
+    /// so it is a lower bound for that output, and adding an error always increases it.
+    /// Other formats, such as the `serde` representation, differ in their fixed overhead
+    /// per error, but scale with the same path, message, and code lengths.
+    ///
+    /// Unlike serializing, this does not allocate, which makes it suitable for deciding
+    /// whether to [`truncate`][`Report::truncate`] a report first.
+    pub fn estimated_size(&self) -> usize {
+        // `[` and `]`, and a `,` between errors
+        let mut size = 2 + self.errors.len().saturating_sub(1);
+        for (path, error) in &self.errors {
+            // `{"path":"` + `","message":"` + `"}`
+            size += 9 + 13 + 2;
+            for (i, (kind, component)) in path.__iter().rev().enumerate() {
+                size += component.len();
+                size += match kind {
+                    Kind::None => 0,
+                    Kind::Key => usize::from(i > 0),
+                    Kind::Index => 2,
+                };
+            }
+            size += error.message().len();
+            if let Some(code) = error.code() {
+                // `,"code":"` + `"`
+                size += 9 + 1 + code.len();
+            }
+        }
+        size
+    }
+
+    /// Mutable access to the list of `(Path, Error)` pairs, for bulk operations which have no dedicated method.
+    ///
+    /// **This is not covered by semver.** The representation of a report may change in any release,
+    /// in which case this method changes or is removed. Options such as [`Report::with_options`]'s error limit
+    /// are not applied to errors pushed through it.
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append(Path::new("b"), Error::new("invalid"));
+    /// report.append(Path::new("a"), Error::new("invalid"));
+    ///
+    /// report.as_mut_vec().sort_by_key(|(path, _)| path.to_string());
+    /// assert_eq!(report.paths().next().unwrap().to_string(), "a");
+    /// ```
+    #[cfg(feature = "unstable-internals")]
+    pub fn as_mut_vec(&mut self) -> &mut Vec<(Path, Error)> {
+        &mut self.errors
+    }
+
     /// Iterate over all `(Path, Error)` pairs.
     pub fn iter(&self) -> impl Iterator<Item = &(Path, Error)> {
         self.errors.iter()
     }
 
+    /// Iterate over the [`Path`] of every error, in order.
+    ///
+    /// A path appears once for every error attached to it.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.errors.iter().map(|(path, _)| path)
+    }
+
+    /// Returns every [`Path`] which has at least one error, without duplicates.
+    ///
+    /// Paths are in the order in which they first appear in the report.
+    pub fn distinct_paths(&self) -> Vec<&Path> {
+        let mut seen = HashSet::with_capacity(self.errors.len());
+        self.paths().filter(|path| seen.insert(*path)).collect()
+    }
+
+    /// Group the errors by the first component of their path, which is usually a top-level field.
+    ///
+    /// Errors at the empty path, such as those of container-level `custom` rules, are grouped under `root`.
+    /// Within a group, errors keep their full path and are in report order.
+    ///
+    /// ```rust
+    /// use garde::{Error, Path, Report};
+    ///
+    /// let mut report = Report::new();
+    /// report.append(Path::new("name"), Error::new("too short"));
+    /// report.append(Path::new("tags").join(0usize), Error::new("not alphanumeric"));
+    /// report.append(Path::new("tags").join(2usize), Error::new("not alphanumeric"));
+    ///
+    /// let groups = report.by_top_level("");
+    /// assert_eq!(groups["name"].len(), 1);
+    /// assert_eq!(groups["tags"].len(), 2);
+    /// ```
+    pub fn by_top_level(&self, root: &str) -> HashMap<CompactString, Vec<(Path, &Error)>> {
+        let mut groups = HashMap::<CompactString, Vec<_>>::new();
+        for (path, error) in &self.errors {
+            let key = match path.components().next() {
+                Some(component) => component.as_str(),
+                None => root,
+            };
+            groups
+                .entry(CompactString::from(key))
+                .or_default()
+                .push((path.clone(), error));
+        }
+        groups
+    }
+
+    /// Rewrite the message of every [`Error`] in this report.
+    ///
+    /// The path of each error is passed to `f` along with its current message,
+    /// which is useful for context-sensitive translation.
+    pub fn map_messages<F: FnMut(&Path, &str) -> String>(&mut self, mut f: F) {
+        for (path, error) in self.errors.iter_mut() {
+            error.message = Message::Owned(f(path, error.message()).into());
+        }
+    }
+
+    /// Returns `true` if both reports contain the same errors at the same paths, in any order.
+    ///
+    /// Errors which appear more than once must appear the same number of times in both reports.
+    pub fn eq_unordered(&self, other: &Report) -> bool {
+        if self.errors.len() != other.errors.len() {
+            return false;
+        }
+        fn sorted(report: &Report) -> Vec<&(Path, Error)> {
+            let mut errors = report.errors.iter().collect::<Vec<_>>();
+            errors.sort_unstable();
+            errors
+        }
+        sorted(self) == sorted(other)
+    }
+
+    /// Rewrite the path of every [`Error`] in this report.
+    ///
+    /// Each path is moved into `f`, and replaced by the path it returns.
+    /// Together with [`Path::join_path`], this relocates a report under a new parent:
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append(Path::new("city"), Error::new("empty"));
+    ///
+    /// let parent = Path::new("address");
+    /// report.map_paths(|path| parent.join_path(&path));
+    /// assert_eq!(report.to_string(), "address.city: empty\n");
+    /// ```
+    pub fn map_paths<F: FnMut(Path) -> Path>(&mut self, mut f: F) {
+        for (path, _) in self.errors.iter_mut() {
+            *path = f(std::mem::replace(path, Path::empty()));
+        }
+    }
+
+    /// Returns a view of this report which serializes as a tree mirroring the structure of the validated value.
+    ///
+    /// See [`Tree`] for the exact shape.
+    #[cfg(feature = "serde")]
+    pub fn tree(&self) -> Tree<'_> {
+        Tree::new(self)
+    }
+
+    /// Returns a view of this report for snapshot testing, e.g. with `insta::assert_yaml_snapshot!`.
+    ///
+    /// See [`Snapshot`] for the exact shape.
+    #[cfg(feature = "insta")]
+    pub fn snapshot(&self) -> Snapshot<'_> {
+        Snapshot::new(self)
+    }
+
+    /// Returns a view of this report which serializes in the JSON shape used by the `validator` crate.
+    ///
+    /// See [`ValidatorCompat`] for the exact shape.
+    #[cfg(feature = "validator-compat")]
+    pub fn validator_compat(&self) -> ValidatorCompat<'_> {
+        ValidatorCompat::new(self)
+    }
+
+    /// Write this report as a flat JSON array of `{"path", "message"}` objects.
+    ///
+    /// This is available without the `serde` feature. Errors with a code also have a `"code"` entry:
+    ///
+    /// ```rust
+    /// # use garde::{Error, Path, Report};
+    /// let mut report = Report::new();
+    /// report.append(Path::new("name"), Error::new("length is lower than 1"));
+    ///
+    /// let mut json = Vec::new();
+    /// report.write_json(&mut json).unwrap();
+    /// assert_eq!(json, br#"[{"path":"name","message":"length is lower than 1"}]"#);
+    /// ```
+    pub fn write_json(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        json::write(self, w)
+    }
+
+    /// Split this report into one with the errors of [`Severity::Error`], and one with everything else,
+    /// i.e. warnings and informational notes. Both keep the order of this report, and its options.
+    ///
+    /// ```rust
+    /// use garde::error::{Error, Path, Report, Severity};
+    ///
+    /// let mut report = Report::new();
+    /// report.append(Path::new("name"), Error::new("empty"));
+    /// report.append(
+    ///     Path::new("bio"),
+    ///     Error::builder().message("very long").severity(Severity::Warning).build(),
+    /// );
+    ///
+    /// let (errors, warnings) = report.partition_severity();
+    /// assert_eq!(errors.to_string(), "name: empty\n");
+    /// assert_eq!(warnings.to_string(), "bio: very long\n");
+    /// ```
+    pub fn partition_severity(self) -> (Report, Report) {
+        let Report {
+            errors,
+            capacity_hint: _,
+            trace,
+            value_debug,
+            limit,
+            collect_warnings,
+        } = self;
+        let (errors, warnings) = errors
+            .into_iter()
+            .partition(|(_, error)| error.severity().is_error());
+        let report = |errors| Report {
+            errors,
+            capacity_hint: 0,
+            trace,
+            value_debug,
+            limit,
+            collect_warnings,
+        };
+        (report(errors), report(warnings))
+    }
+
+    /// Removes all errors, keeping the allocation, so the report can be reused.
+    ///
+    /// The options of the report, such as [`Report::with_trace`], are kept.
+    pub fn clear(&mut self) {
+        self.errors.clear();
+    }
+
     /// Returns `true` if the report contains no validation errors.
+    ///
+    /// Only entries with [`Severity::Error`] are counted, so a report which only holds warnings or notes is empty.
     pub fn is_empty(&self) -> bool {
-        self.errors.is_empty()
+        self.error_count() == 0
+    }
+
+    /// Convert this report into the result returned by [`Validate::validate`][`crate::Validate::validate`].
+    ///
+    /// The result is `Ok` if the report is empty, and `Err` with the report otherwise,
+    /// so warnings and notes on their own don't fail validation. This is useful when errors are accumulated manually:
+    ///
+    /// ```rust
+    /// use garde::{Error, Path, Report};
+    ///
+    /// fn validate_pair(a: &str, b: &str) -> Result<(), Report> {
+    ///     let mut report = Report::new();
+    ///     if a.is_empty() {
+    ///         report.append(Path::new("a"), Error::new("empty"));
+    ///     }
+    ///     if b.is_empty() {
+    ///         report.append(Path::new("b"), Error::new("empty"));
+    ///     }
+    ///     report.into_result()
+    /// }
+    ///
+    /// assert!(validate_pair("a", "b").is_ok());
+    /// assert_eq!(validate_pair("", "").unwrap_err().iter().count(), 2);
+    /// ```
+    pub fn into_result(self) -> Result<(), Report> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self),
+        }
+    }
+
+    /// Like [`Report::into_result`], but borrows the report.
+    pub fn as_result(&self) -> Result<(), &Report> {
+        match self.is_empty() {
+            true => Ok(()),
+            false => Err(self),
+        }
     }
 }
 
@@ -58,45 +661,427 @@ impl std::fmt::Display for Report {
 
 impl std::error::Error for Report {}
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+/// Reports are equal if they contain the same errors at the same paths, in the same order.
+///
+/// Options such as [`Report::with_trace`] are not compared. See [`Report::eq_unordered`] to ignore the order.
+impl PartialEq for Report {
+    fn eq(&self, other: &Self) -> bool {
+        self.errors == other.errors
+    }
+}
+
+impl Eq for Report {}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Error {
-    message: CompactString,
+    message: Message,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    code: Option<CompactString>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Vec::is_empty"))]
+    params: Vec<(CompactString, CompactString)>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Severity::is_error"))]
+    severity: Severity,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    label: Option<CompactString>,
+    // Boxed instead of a `CompactString`, because it is rarely set, and `Error` is returned by value.
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    value_debug: Option<Box<str>>,
+}
+
+/// How severe an [`Error`] is.
+///
+/// Errors produced by the built-in rules are always [`Severity::Error`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+    Info,
+}
+
+impl Severity {
+    pub fn is_error(&self) -> bool {
+        matches!(self, Severity::Error)
+    }
+}
+
+/// The message of an [`Error`], which is either borrowed for the lifetime of the program,
+/// or owned if it had to be formatted.
+#[derive(Clone)]
+enum Message {
+    Static(&'static str),
+    Owned(CompactString),
+}
+
+impl Message {
+    fn as_str(&self) -> &str {
+        match self {
+            Message::Static(message) => message,
+            Message::Owned(message) => message.as_str(),
+        }
+    }
+}
+
+impl Default for Message {
+    fn default() -> Self {
+        Message::Static("")
+    }
+}
+
+impl std::fmt::Debug for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for Message {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for Message {}
+
+impl PartialOrd for Message {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Message {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl std::hash::Hash for Message {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
 impl Error {
     pub fn new(message: impl ToCompactString) -> Self {
+        Self::from_message(Message::Owned(message.to_compact_string()))
+    }
+
+    /// Create an error with a constant message, which is stored without copying it.
+    ///
+    /// This is what the built-in rules use for messages which don't contain any values.
+    ///
+    /// ```rust
+    /// let error = garde::Error::new_static("not set");
+    /// assert_eq!(error.message(), "not set");
+    /// ```
+    pub const fn new_static(message: &'static str) -> Self {
+        Self::from_message(Message::Static(message))
+    }
+
+    const fn from_message(message: Message) -> Self {
         Self {
-            message: message.to_compact_string(),
+            message,
+            code: None,
+            params: Vec::new(),
+            severity: Severity::Error,
+            label: None,
+            value_debug: None,
+        }
+    }
+
+    /// Start building an [`Error`] with a code, parameters, or a severity.
+    ///
+    /// ```rust
+    /// use garde::error::{Error, Severity};
+    ///
+    /// let error = Error::builder()
+    ///     .message("password is too weak")
+    ///     .code("password.strength")
+    ///     .param("score", 2)
+    ///     .severity(Severity::Warning)
+    ///     .build();
+    ///
+    /// assert_eq!(error.message(), "password is too weak");
+    /// assert_eq!(error.code(), Some("password.strength"));
+    /// assert_eq!(error.params().collect::<Vec<_>>(), [("score", "2")]);
+    /// assert_eq!(error.severity(), Severity::Warning);
+    /// ```
+    pub fn builder() -> ErrorBuilder {
+        ErrorBuilder::default()
+    }
+
+    /// Replace the message of this error, keeping its code and other details.
+    pub fn with_message(mut self, message: impl ToCompactString) -> Self {
+        self.message = Message::Owned(message.to_compact_string());
+        self
+    }
+
+    /// Replace the message of this error with a constant one, which is not copied.
+    pub fn with_static_message(mut self, message: &'static str) -> Self {
+        self.message = Message::Static(message);
+        self
+    }
+
+    /// Attach a machine-readable `code` to this error, replacing any existing one.
+    ///
+    /// Built-in rules produce codes such as `length.min` or `email`.
+    pub fn with_code(mut self, code: impl ToCompactString) -> Self {
+        self.code = Some(code.to_compact_string());
+        self
+    }
+
+    /// Prepend `prefix.` to the code of this error.
+    ///
+    /// Errors without a code are returned unchanged.
+    pub fn with_code_prefix(mut self, prefix: &str) -> Self {
+        if let Some(code) = &self.code {
+            self.code = Some(compact_str::format_compact!("{prefix}.{code}"));
         }
+        self
+    }
+
+    /// Attach a human-readable `label` for the value this error is about,
+    /// replacing any existing one.
+    ///
+    /// Unlike the [`Path`], the label is meant for display, e.g. `Email address`.
+    pub fn with_label(mut self, label: impl ToCompactString) -> Self {
+        self.label = Some(label.to_compact_string());
+        self
+    }
+
+    /// The maximum length in bytes of [`Error::value_debug`], not counting the `…` appended when it is truncated.
+    pub const VALUE_DEBUG_MAX_LEN: usize = 64;
+
+    /// Attach the `Debug` representation of the offending value, replacing any existing one.
+    ///
+    /// It is truncated to [`Error::VALUE_DEBUG_MAX_LEN`] bytes. See [`Report::with_value_debug`].
+    pub fn with_value_debug(mut self, value: &(impl std::fmt::Debug + ?Sized)) -> Self {
+        use std::fmt::Write;
+
+        let mut debug = String::new();
+        let _ = write!(debug, "{value:?}");
+        if debug.len() > Self::VALUE_DEBUG_MAX_LEN {
+            let mut end = Self::VALUE_DEBUG_MAX_LEN;
+            while !debug.is_char_boundary(end) {
+                end -= 1;
+            }
+            debug.truncate(end);
+            debug.push('…');
+        }
+        self.value_debug = Some(debug.into_boxed_str());
+        self
     }
 
     pub fn message(&self) -> &str {
-        self.message.as_ref()
+        self.message.as_str()
+    }
+
+    /// The `Debug` representation of the offending value, if it was captured.
+    pub fn value_debug(&self) -> Option<&str> {
+        self.value_debug.as_deref()
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// Iterate over the `(key, value)` parameters of this error, in insertion order.
+    pub fn params(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.params.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// The label set via `#[garde(label = "..")]` or [`Error::with_label`], if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Display this error as `[code] message`, or only as the message if it has no code.
+    ///
+    /// ```rust
+    /// use garde::Error;
+    ///
+    /// let error = Error::new("length is lower than 3").with_code("length.min");
+    /// assert_eq!(error.display_with_code().to_string(), "[length.min] length is lower than 3");
+    /// assert_eq!(error.to_string(), "length is lower than 3");
+    /// ```
+    pub fn display_with_code(&self) -> DisplayWithCode<'_> {
+        DisplayWithCode(self)
+    }
+}
+
+/// Displays an [`Error`] with its code, see [`Error::display_with_code`].
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayWithCode<'a>(&'a Error);
+
+impl<'a> std::fmt::Display for DisplayWithCode<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0.code() {
+            Some(code) => write!(f, "[{code}] {}", self.0),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+/// A builder for [`Error`], created by [`Error::builder`].
+#[derive(Debug, Default)]
+pub struct ErrorBuilder {
+    message: Message,
+    code: Option<CompactString>,
+    params: Vec<(CompactString, CompactString)>,
+    severity: Severity,
+    label: Option<CompactString>,
+}
+
+impl ErrorBuilder {
+    pub fn message(mut self, message: impl ToCompactString) -> Self {
+        self.message = Message::Owned(message.to_compact_string());
+        self
+    }
+
+    /// Set a constant message, see [`Error::new_static`].
+    pub fn static_message(mut self, message: &'static str) -> Self {
+        self.message = Message::Static(message);
+        self
+    }
+
+    pub fn code(mut self, code: impl ToCompactString) -> Self {
+        self.code = Some(code.to_compact_string());
+        self
+    }
+
+    /// Add a parameter, which is usually a value the message was formatted with.
+    pub fn param(mut self, key: impl ToCompactString, value: impl ToCompactString) -> Self {
+        self.params
+            .push((key.to_compact_string(), value.to_compact_string()));
+        self
+    }
+
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    pub fn label(mut self, label: impl ToCompactString) -> Self {
+        self.label = Some(label.to_compact_string());
+        self
+    }
+
+    pub fn build(self) -> Error {
+        let ErrorBuilder {
+            message,
+            code,
+            params,
+            severity,
+            label,
+        } = self;
+        Error {
+            message,
+            code,
+            params,
+            severity,
+            label,
+            value_debug: None,
+        }
     }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        write!(f, "{}", self.message.as_str())
     }
 }
 
 impl std::error::Error for Error {}
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// The location of an error within the validated value, e.g. `users[0].email`.
+///
+/// Paths with up to two components, such as `email` or `tags[0]`, are stored inline, so building them doesn't allocate.
+/// Deeper paths are stored in a persistent list, which is shared between a path and the paths joined onto it.
+#[derive(Clone)]
 pub struct Path {
-    components: List<(Kind, CompactString)>,
+    components: Components,
 }
 
-#[doc(hidden)]
+/// The number of components a [`Path`] stores without allocating.
+const INLINE_COMPONENTS: usize = 2;
+
+type Component = (Kind, CompactString);
+
+#[derive(Clone)]
+enum Components {
+    /// From the root to the leaf, with at most [`INLINE_COMPONENTS`] items.
+    Inline(SmallVec<[Component; INLINE_COMPONENTS]>),
+    /// From the leaf to the root, with more than [`INLINE_COMPONENTS`] items.
+    Shared(List<Component>),
+}
+
+/// The kind of a [`Path`] component.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Kind {
+    /// A component which does not appear in the path's string representation,
+    /// such as the inner value of an `Option`.
     None,
+    /// A struct field or map key.
     Key,
+    /// A list or tuple index.
     Index,
 }
 
+/// A single component of a [`Path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PathComponent<'a> {
+    kind: Kind,
+    value: &'a str,
+}
+
+impl<'a> PathComponent<'a> {
+    pub fn kind(&self) -> Kind {
+        self.kind
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.value
+    }
+
+    pub fn is_key(&self) -> bool {
+        self.kind == Kind::Key
+    }
+
+    pub fn is_index(&self) -> bool {
+        self.kind == Kind::Index
+    }
+
+    /// Returns the index if this is an [`Kind::Index`] component.
+    pub fn index(&self) -> Option<usize> {
+        match self.kind {
+            Kind::Index => self.value.parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> std::fmt::Display for PathComponent<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.value)
+    }
+}
+
 #[doc(hidden)]
 #[derive(Default)]
 pub struct NoKey(());
@@ -129,8 +1114,8 @@ impl_path_component_kind!(String => Key);
 impl_path_component_kind!(CompactString => Key);
 impl_path_component_kind!(NoKey => None);
 
-impl<'a, T: PathComponentKind> private::Sealed for &'a T {}
-impl<'a, T: PathComponentKind> PathComponentKind for &'a T {
+impl<T: PathComponentKind> private::Sealed for &T {}
+impl<T: PathComponentKind> PathComponentKind for &T {
     fn component_kind() -> Kind {
         T::component_kind()
     }
@@ -143,42 +1128,129 @@ mod private {
 impl Path {
     pub fn empty() -> Self {
         Self {
-            components: List::new(),
+            components: Components::Inline(SmallVec::new()),
         }
     }
 
     pub fn len(&self) -> usize {
-        self.components.len()
+        match &self.components {
+            Components::Inline(v) => v.len(),
+            Components::Shared(v) => v.len(),
+        }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.components.is_empty()
+        self.len() == 0
     }
 
     pub fn new<C: PathComponentKind>(component: C) -> Self {
-        Self {
-            components: List::new().append((C::component_kind(), component.to_compact_string())),
-        }
+        Self::empty().join(component)
     }
 
     pub fn join<C: PathComponentKind>(&self, component: C) -> Self {
-        Self {
-            components: self
-                .components
-                .append((C::component_kind(), component.to_compact_string())),
-        }
+        self.join_component((C::component_kind(), component.to_compact_string()))
     }
 
+    /// Append all components of `other` to this path.
+    pub fn join_path(&self, other: &Path) -> Self {
+        other
+            .__iter()
+            .rev()
+            .fold(self.clone(), |path, (kind, value)| {
+                path.join_component((kind, value.clone()))
+            })
+    }
+
+    fn join_component(&self, component: Component) -> Self {
+        let components = match &self.components {
+            Components::Inline(v) if v.len() < INLINE_COMPONENTS => {
+                let mut v = v.clone();
+                v.push(component);
+                Components::Inline(v)
+            }
+            // The path is too deep to be stored inline, so it moves into a shared list.
+            Components::Inline(v) => {
+                let list = v
+                    .iter()
+                    .cloned()
+                    .fold(List::new(), |list, component| list.append(component));
+                Components::Shared(list.append(component))
+            }
+            Components::Shared(v) => Components::Shared(v.append(component)),
+        };
+        Self { components }
+    }
+
+    /// Iterate over the components of this path, from the root to the leaf.
+    pub fn components(&self) -> impl DoubleEndedIterator<Item = PathComponent<'_>> {
+        self.__iter().rev().map(|(kind, value)| PathComponent {
+            kind,
+            value: value.as_str(),
+        })
+    }
+
+    /// Returns the leaf component of this path, or `None` if the path is empty.
+    pub fn last_component(&self) -> Option<PathComponent<'_>> {
+        let last = match &self.components {
+            Components::Inline(v) => v.last(),
+            Components::Shared(v) => v.iter().next(),
+        };
+        last.map(|(kind, value)| PathComponent {
+            kind: *kind,
+            value: value.as_str(),
+        })
+    }
+
+    /// Iterate over the components of this path, from the leaf to the root.
     #[doc(hidden)]
     pub fn __iter(&self) -> impl DoubleEndedIterator<Item = (Kind, &CompactString)> {
-        let mut components = TempComponents::with_capacity(self.components.len());
-        for (kind, component) in self.components.iter() {
-            components.push((*kind, component));
+        let mut components = TempComponents::with_capacity(self.len());
+        match &self.components {
+            Components::Inline(v) => {
+                for (kind, component) in v.iter().rev() {
+                    components.push((*kind, component));
+                }
+            }
+            Components::Shared(v) => {
+                for (kind, component) in v.iter() {
+                    components.push((*kind, component));
+                }
+            }
         }
         components.into_iter()
     }
 }
 
+impl PartialEq for Path {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.__iter().eq(other.__iter())
+    }
+}
+
+impl Eq for Path {}
+
+impl PartialOrd for Path {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Paths are ordered by their components, from the root to the leaf.
+impl Ord for Path {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.__iter().rev().cmp(other.__iter().rev())
+    }
+}
+
+impl std::hash::Hash for Path {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for component in self.__iter() {
+            component.hash(state);
+        }
+    }
+}
+
 type TempComponents<'a> = SmallVec<[(Kind, &'a CompactString); 8]>;
 
 impl std::fmt::Debug for Path {
@@ -253,6 +1325,481 @@ mod tests {
         assert_eq!(path.to_string(), "a.b.c");
     }
 
+    #[test]
+    fn path_inline_and_shared() {
+        let shallow = Path::new("a").join(0usize);
+        let deep = shallow.join("b").join("c");
+        assert_eq!(shallow.to_string(), "a[0]");
+        assert_eq!(deep.to_string(), "a[0].b.c");
+        assert_eq!(deep.len(), 4);
+        assert_eq!(deep.last_component().map(|c| c.as_str()), Some("c"));
+
+        // Equality and ordering don't depend on how the components are stored.
+        assert_eq!(deep, Path::new("a").join(0usize).join("b").join("c"));
+        assert_ne!(deep, shallow);
+        assert!(shallow < deep);
+        assert!(Path::new("a").join("z") < Path::new("b"));
+    }
+
+    #[test]
+    fn path_components() {
+        let path = Path::new("a").join(0usize).join(NoKey::default()).join("b");
+        let components = path.components().collect::<Vec<_>>();
+        assert_eq!(
+            components
+                .iter()
+                .map(|c| (c.kind(), c.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                (Kind::Key, "a"),
+                (Kind::Index, "0"),
+                (Kind::None, ""),
+                (Kind::Key, "b")
+            ]
+        );
+        assert_eq!(components[1].index(), Some(0));
+        assert_eq!(components[0].index(), None);
+        assert_eq!(path.last_component(), Some(components[3]));
+        assert_eq!(Path::empty().last_component(), None);
+    }
+
+    #[test]
+    fn report_truncate() {
+        let report = || {
+            let mut report = Report::new();
+            for i in 0..4usize {
+                report.append(Path::new("a").join(i), Error::new("invalid"));
+            }
+            report
+        };
+
+        let mut truncated = report();
+        assert_eq!(truncated.truncate(10), 0);
+        assert_eq!(truncated.truncate(4), 0);
+        assert_eq!(truncated.truncate(1), 3);
+        assert_eq!(truncated.to_string(), "a[0]: invalid\n");
+
+        let mut marked = report();
+        assert_eq!(marked.truncate_with_marker(4), 0);
+        assert_eq!(marked.iter().count(), 4);
+        assert_eq!(marked.truncate_with_marker(2), 2);
+        assert_eq!(
+            marked.to_string(),
+            "a[0]: invalid\na[1]: invalid\n: and 2 more errors\n"
+        );
+        let (path, marker) = marked.iter().last().unwrap();
+        assert!(path.is_empty());
+        assert_eq!(marker.code(), Some("truncated"));
+        assert_eq!(marker.params().collect::<Vec<_>>(), [("count", "2")]);
+
+        let mut marked = report();
+        marked.truncate_with_marker(3);
+        assert_eq!(
+            marked.iter().last().unwrap().1.message(),
+            "and 1 more error"
+        );
+    }
+
+    #[test]
+    fn report_by_top_level() {
+        let mut report = Report::new();
+        report.append(Path::new("name"), Error::new("too short"));
+        report.append(Path::new("address").join("city"), Error::new("empty"));
+        report.append(Path::empty(), Error::new("passwords do not match"));
+        report.append(Path::new("address").join("zip"), Error::new("invalid"));
+
+        let groups = report.by_top_level("$root");
+        let summary = |key: &str| {
+            groups[key]
+                .iter()
+                .map(|(path, error)| format!("{path}: {}", error.message()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(groups.len(), 3);
+        assert_eq!(summary("name"), ["name: too short"]);
+        assert_eq!(
+            summary("address"),
+            ["address.city: empty", "address.zip: invalid"]
+        );
+        assert_eq!(summary("$root"), [": passwords do not match"]);
+    }
+
+    #[test]
+    fn report_clone() {
+        let mut report = Report::new().with_trace();
+        report.append(Path::new("name"), Error::new_static("too short"));
+        report.append(
+            Path::new("tags").join(0usize).join("value"),
+            Error::new("empty")
+                .with_code("length.min")
+                .with_label("Tag"),
+        );
+
+        let clone = report.clone();
+        assert!(clone.is_tracing());
+        assert!(clone.iter().eq(report.iter()));
+        assert_eq!(clone.to_string(), report.to_string());
+
+        // The clone is independent of the original.
+        report.append(Path::empty(), Error::new("invalid"));
+        assert_eq!(clone.iter().count(), 2);
+    }
+
+    #[test]
+    fn report_eq() {
+        let report = |errors: &[(&str, &str)]| {
+            let mut report = Report::new();
+            for (path, message) in errors {
+                report.append(Path::new(*path), Error::new(*message));
+            }
+            report
+        };
+
+        let a = report(&[("name", "too short"), ("email", "invalid")]);
+        assert_eq!(a, report(&[("name", "too short"), ("email", "invalid")]));
+        assert_eq!(a, a.clone().with_trace());
+        assert_eq!(report(&[]), Report::new());
+
+        let reordered = report(&[("email", "invalid"), ("name", "too short")]);
+        assert_ne!(a, reordered);
+        assert!(a.eq_unordered(&reordered));
+        assert_ne!(a, report(&[("name", "too short"), ("email", "empty")]));
+        assert!(!a.eq_unordered(&report(&[("name", "too short")])));
+        let duplicated = report(&[("a", "x"), ("a", "x"), ("b", "y")]);
+        assert!(!duplicated.eq_unordered(&report(&[("a", "x"), ("b", "y"), ("b", "y")])));
+    }
+
+    #[test]
+    fn report_partition_severity() {
+        let error =
+            |message: &str, severity| Error::builder().message(message).severity(severity).build();
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("first"));
+        report.append(Path::new("b"), error("second", Severity::Warning));
+        report.append(Path::new("c"), Error::new("third"));
+        report.append(Path::new("d"), error("fourth", Severity::Info));
+        report.append(Path::new("e"), error("fifth", Severity::Warning));
+
+        let (errors, warnings) = report.with_trace().partition_severity();
+        let messages = |report: &Report| {
+            report
+                .iter()
+                .map(|(path, error)| format!("{path}: {}", error.message()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(messages(&errors), ["a: first", "c: third"]);
+        assert_eq!(messages(&warnings), ["b: second", "d: fourth", "e: fifth"]);
+        assert!(errors.is_tracing() && warnings.is_tracing());
+
+        let (errors, warnings) = Report::new().partition_severity();
+        assert!(errors.is_empty() && warnings.is_empty());
+    }
+
+    #[test]
+    fn error_display_with_code() {
+        let error = Error::new_static("not set").with_code("required");
+        assert_eq!(error.display_with_code().to_string(), "[required] not set");
+        assert_eq!(error.to_string(), "not set");
+
+        let error = Error::new_static("not set");
+        assert_eq!(error.display_with_code().to_string(), "not set");
+        assert_eq!(error.to_string(), "not set");
+    }
+
+    #[test]
+    fn report_map_paths() {
+        let mut report = Report::new();
+        report.append(Path::empty(), Error::new("passwords do not match"));
+        report.append(Path::new("name"), Error::new("too short"));
+        report.append(
+            Path::new("tags").join(0usize).join("value"),
+            Error::new("empty"),
+        );
+
+        let prefix = Path::new("form").join(1usize);
+        report.map_paths(|path| prefix.join_path(&path));
+        assert_eq!(
+            report.to_string(),
+            "form[1]: passwords do not match\n\
+             form[1].name: too short\n\
+             form[1].tags[0].value: empty\n"
+        );
+        assert_eq!(
+            report.iter().last().unwrap().0,
+            Path::new("form")
+                .join(1usize)
+                .join("tags")
+                .join(0usize)
+                .join("value")
+        );
+    }
+
+    #[test]
+    fn report_estimated_size() {
+        let mut report = Report::new();
+        let mut estimate = report.estimated_size();
+        for i in 0..4usize {
+            report.append(Path::new("a").join(i).join("b"), Error::new("invalid"));
+            report.append(
+                Path::new("c"),
+                Error::new("length is lower than 1").with_code("length.min"),
+            );
+            assert!(report.estimated_size() > estimate);
+            estimate = report.estimated_size();
+        }
+
+        let mut json = Vec::new();
+        report.write_json(&mut json).unwrap();
+        assert_eq!(report.estimated_size(), json.len());
+
+        report.append(Path::new("d"), Error::new("\"quoted\""));
+        let mut json = Vec::new();
+        report.write_json(&mut json).unwrap();
+        assert!(report.estimated_size() < json.len());
+    }
+
+    #[test]
+    fn error_static_message() {
+        let error = Error::new_static("not set");
+        assert_eq!(error.message(), "not set");
+        assert_eq!(error.to_string(), "not set");
+        assert_eq!(error, Error::new("not set"));
+        assert_eq!(format!("{error:?}"), format!("{:?}", Error::new("not set")));
+    }
+
+    #[test]
+    fn report_map_messages() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lol"));
+        report.append(Path::new("b").join(0usize), Error::new("pog"));
+
+        report.map_messages(|path, message| format!("{path}: {}", message.to_uppercase()));
+
+        assert_eq!(
+            report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message()))
+                .collect::<Vec<_>>(),
+            [
+                ("a".to_string(), "a: LOL"),
+                ("b[0]".to_string(), "b[0]: POG")
+            ]
+        );
+    }
+
+    #[test]
+    fn report_with_capacity() {
+        let mut report = Report::with_capacity(8);
+        assert_eq!(report.errors.capacity(), 0);
+        report.append(Path::new("a"), Error::new("lol"));
+        assert!(report.errors.capacity() >= 8);
+    }
+
+    #[test]
+    fn report_merge_dedup() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lol"));
+        report.append(Path::new("b"), Error::new("pog"));
+        report.append(Path::new("a"), Error::new("lol"));
+
+        let mut other = Report::new();
+        other.append(Path::new("c"), Error::new("lol"));
+        other.append(Path::new("b"), Error::new("pog"));
+        other.append(Path::new("a"), Error::new("pog"));
+        other.append(Path::new("c"), Error::new("lol"));
+
+        report.merge_dedup(other);
+
+        assert_eq!(
+            report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message()))
+                .collect::<Vec<_>>(),
+            [
+                ("a".to_string(), "lol"),
+                ("b".to_string(), "pog"),
+                ("c".to_string(), "lol"),
+                ("a".to_string(), "pog"),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_merge_dedup_filters() {
+        let warning = |message: &str| {
+            Error::builder()
+                .message(message)
+                .severity(Severity::Warning)
+                .build()
+        };
+        let messages = |report: &Report| {
+            report
+                .iter()
+                .map(|(path, error)| format!("{path}: {}", error.message()))
+                .collect::<Vec<_>>()
+        };
+        let other = || {
+            let mut other = Report::new();
+            other.append(Path::new("a"), Error::new("lol"));
+            other.append(Path::new("b"), warning("pog"));
+            other.append(Path::new("c"), Error::new("lol"));
+            other.append(Path::new("d"), Error::new("pog"));
+            other
+        };
+
+        let mut limited = Report::with_options(&crate::ValidationOptions {
+            error_limit: Some(2),
+            ..Default::default()
+        });
+        limited.append(Path::new("a"), Error::new("lol"));
+        limited.merge_dedup(other());
+        // The duplicate doesn't use up the limit, and the warning doesn't count towards it.
+        assert_eq!(messages(&limited), ["a: lol", "b: pog", "c: lol"]);
+        assert!(limited.is_full());
+
+        let mut no_warnings = Report::with_options(&crate::ValidationOptions {
+            collect_warnings: false,
+            ..Default::default()
+        });
+        no_warnings.merge_dedup(other());
+        assert_eq!(messages(&no_warnings), ["a: lol", "c: lol", "d: pog"]);
+    }
+
+    #[test]
+    fn report_prepend() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lol"));
+        report.append(Path::new("b"), Error::new("lol"));
+        report.prepend(Path::new("c"), Error::new("pog"));
+
+        let mut other = Report::new();
+        other.append(Path::new("d"), Error::new("lol"));
+        other.append(Path::new("e"), Error::new("pog"));
+        report.prepend_report(other);
+
+        assert_eq!(
+            report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message()))
+                .collect::<Vec<_>>(),
+            [
+                ("d".to_string(), "lol"),
+                ("e".to_string(), "pog"),
+                ("c".to_string(), "pog"),
+                ("a".to_string(), "lol"),
+                ("b".to_string(), "lol"),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_prepend_report_filters() {
+        let warning = |message: &str| {
+            Error::builder()
+                .message(message)
+                .severity(Severity::Warning)
+                .build()
+        };
+        let mut report = Report::with_options(&crate::ValidationOptions {
+            error_limit: Some(2),
+            collect_warnings: false,
+            ..Default::default()
+        });
+        report.append(Path::new("a"), Error::new("lol"));
+
+        let mut other = Report::new();
+        other.append(Path::new("b"), warning("pog"));
+        other.append(Path::new("c"), Error::new("lol"));
+        other.append(Path::new("d"), Error::new("pog"));
+        report.prepend_report(other);
+
+        assert_eq!(
+            report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message()))
+                .collect::<Vec<_>>(),
+            [("c".to_string(), "lol"), ("a".to_string(), "lol")]
+        );
+        assert!(report.is_full());
+    }
+
+    #[test]
+    fn report_insert_unique() {
+        let mut report = Report::new();
+        assert!(report.insert_unique(Path::new("a"), Error::new("lol")));
+        assert!(report.insert_unique(Path::new("b"), Error::new("lol")));
+        assert!(report.insert_unique(Path::new("a"), Error::new("pog")));
+        assert!(!report.insert_unique(Path::new("a"), Error::new("lol")));
+        assert!(report.insert_unique(Path::new("a"), Error::new("lol").with_code("lol")));
+
+        assert_eq!(
+            report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message()))
+                .collect::<Vec<_>>(),
+            [
+                ("a".to_string(), "lol"),
+                ("b".to_string(), "lol"),
+                ("a".to_string(), "pog"),
+                ("a".to_string(), "lol"),
+            ]
+        );
+
+        let mut full = Report::with_options(&crate::ValidationOptions {
+            fail_fast: true,
+            ..Default::default()
+        });
+        assert!(full.insert_unique(Path::new("a"), Error::new("lol")));
+        assert!(!full.insert_unique(Path::new("b"), Error::new("lol")));
+    }
+
+    #[cfg(feature = "unstable-internals")]
+    #[test]
+    fn report_as_mut_vec() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lol"));
+        report.append(Path::new("b"), Error::new("pog"));
+        report.append(Path::new("c"), Error::new("lol"));
+
+        let errors = report.as_mut_vec();
+        errors.retain(|(_, error)| error.message() == "lol");
+        errors.reverse();
+
+        assert_eq!(
+            report
+                .iter()
+                .map(|(path, error)| (path.to_string(), error.message()))
+                .collect::<Vec<_>>(),
+            [("c".to_string(), "lol"), ("a".to_string(), "lol")]
+        );
+    }
+
+    #[test]
+    fn report_paths() {
+        let mut report = Report::new();
+        report.append(Path::new("b"), Error::new("lol"));
+        report.append(Path::new("a"), Error::new("lol"));
+        report.append(Path::new("b"), Error::new("pog"));
+        report.append(Path::new("a").join(0usize), Error::new("pog"));
+
+        assert_eq!(
+            report
+                .paths()
+                .map(|path| path.to_string())
+                .collect::<Vec<_>>(),
+            ["b", "a", "b", "a[0]"]
+        );
+        assert_eq!(
+            report
+                .distinct_paths()
+                .into_iter()
+                .map(|path| path.to_string())
+                .collect::<Vec<_>>(),
+            ["b", "a", "a[0]"]
+        );
+        assert!(Report::new().distinct_paths().is_empty());
+    }
+
     #[test]
     fn report_select() {
         let mut report = Report::new();
@@ -274,4 +1821,86 @@ mod tests {
             [&Error::new("pog")]
         );
     }
+
+    #[cfg(feature = "serde")]
+    use proptest::strategy::Strategy;
+
+    /// Builds a path from keys and indices, e.g. `[Ok("a"), Err(0)]` is `a[0]`.
+    #[cfg(feature = "serde")]
+    fn build_path(components: &[Result<String, usize>]) -> Path {
+        components
+            .iter()
+            .fold(Path::empty(), |path, component| match component {
+                Ok(key) => path.join(key),
+                Err(index) => path.join(index),
+            })
+    }
+
+    #[cfg(feature = "serde")]
+    proptest::proptest! {
+        #[test]
+        fn report_from_pairs_serde_round_trip(
+            pairs in proptest::collection::vec(
+                (
+                    proptest::collection::vec(
+                        proptest::prop_oneof![
+                            "[a-z]{1,8}".prop_map(Ok),
+                            (0usize..100).prop_map(Err),
+                        ],
+                        0..4,
+                    ),
+                    ".*",
+                    proptest::option::of("[a-z.]{1,16}"),
+                ),
+                0..8,
+            )
+        ) {
+            use proptest::prelude::*;
+
+            let report = Report::from_pairs(pairs.iter().map(|(path, message, code)| {
+                let error = Error::new(message);
+                let error = match code {
+                    Some(code) => error.with_code(code),
+                    None => error,
+                };
+                (build_path(path), error)
+            }));
+
+            let value = serde_json::to_value(&report).unwrap();
+            let errors = value["errors"].as_array().unwrap();
+            prop_assert_eq!(errors.len(), pairs.len());
+            for ((path, error), value) in report.iter().zip(errors) {
+                prop_assert_eq!(value[0].as_str(), Some(&*path.to_string()));
+                prop_assert_eq!(value[1]["message"].as_str(), Some(error.message()));
+                prop_assert_eq!(value[1]["code"].as_str(), error.code());
+            }
+
+            // Rebuilding the report from its serialized form gives the same report.
+            let rebuilt = Report::from_pairs(errors.iter().map(|value| {
+                let error = Error::new(value[1]["message"].as_str().unwrap());
+                let error = match value[1]["code"].as_str() {
+                    Some(code) => error.with_code(code),
+                    None => error,
+                };
+                (Path::new(value[0].as_str().unwrap()), error)
+            }));
+            prop_assert_eq!(serde_json::to_value(&rebuilt).unwrap(), value);
+        }
+    }
+
+    #[cfg(all(feature = "arbitrary", feature = "serde"))]
+    proptest::proptest! {
+        #[test]
+        fn report_arbitrary(bytes in proptest::collection::vec(proptest::prelude::any::<u8>(), 0..1024)) {
+            use ::arbitrary::{Arbitrary, Unstructured};
+            use proptest::prelude::*;
+
+            let report = Report::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+            let copy = Report::from_pairs(report.iter().cloned());
+            prop_assert_eq!(&copy, &report);
+
+            let value = serde_json::to_value(&report).unwrap();
+            prop_assert_eq!(value["errors"].as_array().unwrap().len(), report.iter().count());
+        }
+    }
 }
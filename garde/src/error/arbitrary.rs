@@ -0,0 +1,57 @@
+//! [`Arbitrary`] implementations, for fuzzing and property testing code which consumes reports.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use super::{Error, Kind, Message, NoKey, Path, Report, Severity};
+
+impl<'a> Arbitrary<'a> for Report {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let pairs = u.arbitrary_iter::<(Path, Error)>()?;
+        Ok(Report::from_pairs(pairs.collect::<Result<Vec<_>>>()?))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Path {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut path = Path::empty();
+        for _ in 0..u.int_in_range(0..=4)? {
+            path = match Kind::arbitrary(u)? {
+                Kind::None => path.join(NoKey::default()),
+                Kind::Key => path.join(String::arbitrary(u)?),
+                Kind::Index => path.join(usize::arbitrary(u)?),
+            };
+        }
+        Ok(path)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Kind {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[Kind::None, Kind::Key, Kind::Index])?)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Error {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let message = String::arbitrary(u)?;
+        let code = Option::<String>::arbitrary(u)?;
+        let params = Vec::<(String, String)>::arbitrary(u)?;
+        Ok(Error {
+            message: Message::Owned(message.into()),
+            code: code.map(Into::into),
+            params: params
+                .into_iter()
+                .map(|(key, value)| (key.into(), value.into()))
+                .collect(),
+            severity: Severity::arbitrary(u)?,
+            label: Option::<String>::arbitrary(u)?.map(Into::into),
+            value_debug: Option::<String>::arbitrary(u)?.map(String::into_boxed_str),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Severity {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(*u.choose(&[Severity::Error, Severity::Warning, Severity::Info])?)
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::{self, Write};
+
+use super::Report;
+
+/// Writes `report` as a flat JSON array of `{"path", "message"}` objects.
+///
+/// This is intentionally minimal, so that it does not need `serde`.
+pub(super) fn write(report: &Report, w: &mut impl Write) -> io::Result<()> {
+    w.write_all(b"[")?;
+    for (i, (path, error)) in report.iter().enumerate() {
+        if i > 0 {
+            w.write_all(b",")?;
+        }
+        w.write_all(b"{\"path\":")?;
+        write_str(w, &path.to_string())?;
+        w.write_all(b",\"message\":")?;
+        write_str(w, error.message())?;
+        if let Some(code) = error.code() {
+            w.write_all(b",\"code\":")?;
+            write_str(w, code)?;
+        }
+        w.write_all(b"}")?;
+    }
+    w.write_all(b"]")
+}
+
+fn write_str(w: &mut impl Write, value: &str) -> io::Result<()> {
+    w.write_all(b"\"")?;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        let escape: &[u8] = match c {
+            '"' => b"\\\"",
+            '\\' => b"\\\\",
+            '\n' => b"\\n",
+            '\r' => b"\\r",
+            '\t' => b"\\t",
+            '\u{8}' => b"\\b",
+            '\u{c}' => b"\\f",
+            '\0'..='\u{1f}' => b"",
+            _ => continue,
+        };
+        w.write_all(&value.as_bytes()[start..i])?;
+        match escape.is_empty() {
+            true => write!(w, "\\u{:04x}", c as u32)?,
+            false => w.write_all(escape)?,
+        }
+        start = i + c.len_utf8();
+    }
+    w.write_all(&value.as_bytes()[start..])?;
+    w.write_all(b"\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Path};
+
+    fn json(report: &Report) -> String {
+        let mut out = Vec::new();
+        report.write_json(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(json(&Report::new()), "[]");
+    }
+
+    #[test]
+    fn flat() {
+        let mut report = Report::new();
+        report.append(Path::empty(), Error::new("root"));
+        report.append(
+            Path::new("a").join(0usize),
+            Error::new("length is lower than 1").with_code("length.min"),
+        );
+        assert_eq!(
+            json(&report),
+            r#"[{"path":"","message":"root"},{"path":"a[0]","message":"length is lower than 1","code":"length.min"}]"#
+        );
+    }
+
+    #[test]
+    fn escaping() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("we\"ird"),
+            Error::new("does not contain \"@\"\nline\tbreak \\ \u{1} ünïcode"),
+        );
+        assert_eq!(
+            json(&report),
+            r#"[{"path":"we\"ird","message":"does not contain \"@\"\nline\tbreak \\ \u0001 ünïcode"}]"#
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn round_trip() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("\"quoted\"\r\n\u{7f}\u{1b}"));
+        let value: serde_json::Value = serde_json::from_str(&json(&report)).unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!([{ "path": "a", "message": "\"quoted\"\r\n\u{7f}\u{1b}" }])
+        );
+    }
+}
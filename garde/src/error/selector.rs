@@ -0,0 +1,161 @@
+use compact_str::{CompactString, ToCompactString};
+
+use super::{Error, Kind, Path, Report};
+
+/// A runtime equivalent of the [`select`][`crate::select`] macro.
+///
+/// A selector is a sequence of components, which is matched against the start of each [`Path`] in a [`Report`].
+/// Just like with the macro, a path matches if it begins with the selector, so selecting `a` also yields
+/// the errors at `a.b` and `a[0]`. Components are compared by their string representation,
+/// which means that `.key("0")` and `.index(0)` are equivalent.
+///
+/// In addition, [`Selector::any_index`] matches any single index component.
+///
+/// ```rust
+/// # use garde::{Error, Path, Report, Selector};
+/// let mut report = Report::new();
+/// report.append(Path::new("users").join(0usize).join("name"), Error::new("too short"));
+/// report.append(Path::new("users").join(3usize).join("name"), Error::new("too long"));
+/// report.append(Path::new("users").join(3usize).join("email"), Error::new("invalid"));
+///
+/// let selector = Selector::new().key("users").any_index().key("name");
+/// assert_eq!(
+///     report.select(&selector).map(|e| e.message()).collect::<Vec<_>>(),
+///     ["too short", "too long"],
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Selector {
+    components: Vec<Component>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Component {
+    Value(CompactString),
+    AnyIndex,
+}
+
+impl Selector {
+    /// Create an empty selector, which matches every path.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a struct field or map key.
+    pub fn key(mut self, key: impl ToCompactString) -> Self {
+        self.components
+            .push(Component::Value(key.to_compact_string()));
+        self
+    }
+
+    /// Append a list or tuple index.
+    pub fn index(mut self, index: usize) -> Self {
+        self.components
+            .push(Component::Value(index.to_compact_string()));
+        self
+    }
+
+    /// Append a wildcard, which matches any index.
+    pub fn any_index(mut self) -> Self {
+        self.components.push(Component::AnyIndex);
+        self
+    }
+
+    /// Returns `true` if `path` begins with this selector.
+    pub fn matches(&self, path: &Path) -> bool {
+        if self.components.len() > path.len() {
+            return false;
+        }
+        let mut path = path.__iter().rev();
+        self.components.iter().all(|left| match path.next() {
+            Some((kind, right)) => match left {
+                Component::Value(left) => left == right,
+                Component::AnyIndex => kind == Kind::Index,
+            },
+            None => false,
+        })
+    }
+}
+
+impl Report {
+    /// Iterate over all errors whose path matches `selector`.
+    ///
+    /// See [`Selector`] for the exact matching rules.
+    pub fn select<'a>(&'a self, selector: &'a Selector) -> impl Iterator<Item = &'a Error> + 'a {
+        self.iter()
+            .filter(move |(path, _)| selector.matches(path))
+            .map(|(_, error)| error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        let mut report = Report::new();
+        report.append(Path::new("a").join("b"), Error::new("lol"));
+        report.append(
+            Path::new("a").join("b").join("c"),
+            Error::new("that seems wrong"),
+        );
+        report.append(Path::new("a").join("b").join("c"), Error::new("pog"));
+        report.append(Path::new("array").join(0usize).join("c"), Error::new("pog"));
+        report.append(Path::new("array").join(1usize).join("c"), Error::new("lol"));
+        report
+    }
+
+    fn select<'a>(report: &'a Report, selector: &'a Selector) -> Vec<&'a str> {
+        report.select(selector).map(|e| e.message()).collect()
+    }
+
+    #[test]
+    fn matches_macro() {
+        let report = report();
+
+        let selector = Selector::new().key("a").key("b").key("c");
+        assert_eq!(
+            select(&report, &selector),
+            crate::select!(report, a.b.c)
+                .map(|e| e.message())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(select(&report, &selector), ["that seems wrong", "pog"]);
+
+        let selector = Selector::new().key("array").index(0).key("c");
+        assert_eq!(
+            select(&report, &selector),
+            crate::select!(report, array[0].c)
+                .map(|e| e.message())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(select(&report, &selector), ["pog"]);
+    }
+
+    #[test]
+    fn prefix() {
+        let report = report();
+        assert_eq!(
+            select(&report, &Selector::new().key("a")),
+            ["lol", "that seems wrong", "pog"]
+        );
+        assert_eq!(select(&report, &Selector::new()).len(), 5);
+        assert!(select(&report, &Selector::new().key("b")).is_empty());
+        assert!(select(
+            &report,
+            &Selector::new().key("a").key("b").key("c").key("d")
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn any_index() {
+        let report = report();
+        assert_eq!(
+            select(&report, &Selector::new().key("array").any_index().key("c")),
+            ["pog", "lol"]
+        );
+        // `b` is a key, not an index
+        assert!(select(&report, &Selector::new().key("a").any_index()).is_empty());
+    }
+}
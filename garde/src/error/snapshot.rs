@@ -0,0 +1,159 @@
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+use super::{Error, Report};
+
+/// A view of a [`Report`] for snapshot testing, created using [`Report::snapshot`].
+///
+/// The report serializes as a list of `{path, message}` entries, with `code`, `params`,
+/// `severity`, and `label` only present if the error has them:
+///
+/// ```rust
+/// # use garde::{Error, Path, Report};
+/// let mut report = Report::new();
+/// report.append(Path::new("name"), Error::new("length is lower than 1").with_code("length.min"));
+/// report.append(Path::new("age"), Error::new("greater than 100").with_code("range.max"));
+///
+/// // - path: age
+/// //   message: greater than 100
+/// //   code: range.max
+/// // - path: name
+/// //   message: length is lower than 1
+/// //   code: length.min
+/// # let _ = report.snapshot();
+/// ```
+///
+/// Entries are sorted by path, then by code and message, so the output doesn't depend on the order
+/// in which rules ran. Paths are serialized as strings such as `address.city` or `tags[0]`,
+/// which makes them easy to match in `insta` redactions, e.g. `"[].message"`.
+pub struct Snapshot<'a> {
+    entries: Vec<(String, &'a Error)>,
+}
+
+impl<'a> Snapshot<'a> {
+    pub(super) fn new(report: &'a Report) -> Self {
+        let mut entries = report
+            .iter()
+            .map(|(path, error)| (path.to_string(), error))
+            .collect::<Vec<_>>();
+        entries.sort_by(|(a_path, a), (b_path, b)| {
+            (a_path, a.code(), a.message()).cmp(&(b_path, b.code(), b.message()))
+        });
+        Self { entries }
+    }
+}
+
+impl<'a> Serialize for Snapshot<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.entries.len()))?;
+        for (path, error) in &self.entries {
+            seq.serialize_element(&Entry { path, error })?;
+        }
+        seq.end()
+    }
+}
+
+struct Entry<'a> {
+    path: &'a str,
+    error: &'a Error,
+}
+
+impl<'a> Serialize for Entry<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Entry { path, error } = self;
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("path", path)?;
+        map.serialize_entry("message", error.message())?;
+        if let Some(code) = error.code() {
+            map.serialize_entry("code", code)?;
+        }
+        if !error.params.is_empty() {
+            map.serialize_entry("params", &Params(error))?;
+        }
+        if !error.severity().is_error() {
+            map.serialize_entry("severity", &error.severity())?;
+        }
+        if let Some(label) = error.label() {
+            map.serialize_entry("label", label)?;
+        }
+        map.end()
+    }
+}
+
+struct Params<'a>(&'a Error);
+
+impl<'a> Serialize for Params<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (key, value) in self.0.params() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::{Path, Severity};
+
+    fn entries() -> Vec<(Path, Error)> {
+        vec![
+            (
+                Path::new("tags").join(0usize),
+                Error::new("not alphanumeric").with_code("alphanumeric"),
+            ),
+            (
+                Path::new("name"),
+                Error::builder()
+                    .message("length is lower than 1")
+                    .code("length.min")
+                    .param("min", 1)
+                    .build(),
+            ),
+            (
+                Path::new("name"),
+                Error::builder()
+                    .message("name is unusual")
+                    .severity(Severity::Warning)
+                    .build(),
+            ),
+            (Path::empty(), Error::new("passwords do not match")),
+        ]
+    }
+
+    fn report(entries: impl IntoIterator<Item = (Path, Error)>) -> Report {
+        let mut report = Report::new();
+        for (path, error) in entries {
+            report.append(path, error);
+        }
+        report
+    }
+
+    #[test]
+    fn stable_output() {
+        insta::assert_yaml_snapshot!(report(entries()).snapshot(), @r###"
+        - path: ""
+          message: passwords do not match
+        - path: name
+          message: name is unusual
+          severity: warning
+        - path: name
+          message: length is lower than 1
+          code: length.min
+          params:
+            min: "1"
+        - path: "tags[0]"
+          message: not alphanumeric
+          code: alphanumeric
+        "###);
+    }
+
+    #[test]
+    fn independent_of_report_order() {
+        assert_eq!(
+            serde_json::to_string(&report(entries().into_iter().rev()).snapshot()).unwrap(),
+            serde_json::to_string(&report(entries()).snapshot()).unwrap(),
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::BTreeMap;
+
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+
+use super::{Kind, Report};
+
+/// A nested view of a [`Report`], created using [`Report::tree`].
+///
+/// Every node in the tree serializes as an object with up to three entries:
+/// - `errors`, the error messages attached to the path of this node,
+/// - `fields`, an object containing child nodes created from [`Kind::Key`] components,
+/// - `items`, an array containing child nodes created from [`Kind::Index`] components.
+///
+/// Entries which would be empty are omitted.
+///
+/// ```rust
+/// # use garde::{Error, Path, Report};
+/// let mut report = Report::new();
+/// report.append(Path::new("name"), Error::new("length is lower than 1"));
+/// report.append(Path::new("tags").join(1usize), Error::new("not alphanumeric"));
+///
+/// // {
+/// //   "fields": {
+/// //     "name": { "errors": ["length is lower than 1"] },
+/// //     "tags": { "items": [null, { "errors": ["not alphanumeric"] }] }
+/// //   }
+/// // }
+/// # let _ = report.tree();
+/// ```
+///
+/// Keys and indices never collide:
+/// - `fields` always has string keys, even if the key looks like a number,
+/// - `items` is only formed from [`Kind::Index`] components, and indices without errors are `null`,
+/// - a key `0` and an index `0` at the same level end up in `fields` and `items` respectively.
+///
+/// Components of kind [`Kind::None`] do not create a node, so the errors are attached to their parent.
+/// An index component which is not a valid `usize` is treated as a key.
+///
+/// Entries in `fields` are ordered by key, and entries in `items` by index,
+/// so the output does not depend on the order in which errors were reported.
+/// Errors within a node keep the order in which they were reported.
+pub struct Tree<'a> {
+    root: Node<'a>,
+}
+
+#[derive(Default)]
+struct Node<'a> {
+    errors: Vec<&'a str>,
+    fields: BTreeMap<&'a str, Node<'a>>,
+    items: BTreeMap<usize, Node<'a>>,
+}
+
+impl<'a> Tree<'a> {
+    pub(super) fn new(report: &'a Report) -> Self {
+        let mut root = Node::default();
+        for (path, error) in report.iter() {
+            let mut node = &mut root;
+            for component in path.components() {
+                node = match (component.kind(), component.index()) {
+                    (Kind::None, _) => continue,
+                    (Kind::Index, Some(index)) => node.items.entry(index).or_default(),
+                    (Kind::Index, None) | (Kind::Key, _) => {
+                        node.fields.entry(component.as_str()).or_default()
+                    }
+                };
+            }
+            node.errors.push(error.message());
+        }
+        Self { root }
+    }
+}
+
+impl<'a> Serialize for Tree<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.root.serialize(serializer)
+    }
+}
+
+impl<'a> Serialize for Node<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        if !self.errors.is_empty() {
+            map.serialize_entry("errors", &self.errors)?;
+        }
+        if !self.fields.is_empty() {
+            map.serialize_entry("fields", &self.fields)?;
+        }
+        if !self.items.is_empty() {
+            map.serialize_entry("items", &Items(&self.items))?;
+        }
+        map.end()
+    }
+}
+
+struct Items<'a, 'b>(&'b BTreeMap<usize, Node<'a>>);
+
+impl<'a, 'b> Serialize for Items<'a, 'b> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let len = self.0.keys().next_back().map_or(0, |max| max + 1);
+        let mut seq = serializer.serialize_seq(Some(len))?;
+        for index in 0..len {
+            seq.serialize_element(&self.0.get(&index))?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::error::NoKey;
+    use crate::{Error, Path};
+
+    fn tree(errors: &[(Path, &str)]) -> serde_json::Value {
+        let mut report = Report::new();
+        for (path, message) in errors {
+            report.append(path.clone(), Error::new(*message));
+        }
+        serde_json::to_value(report.tree()).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(tree(&[]), json!({}));
+    }
+
+    #[test]
+    fn nested() {
+        assert_eq!(
+            tree(&[
+                (Path::empty(), "root"),
+                (Path::new("a"), "a"),
+                (Path::new("a").join("b"), "a.b"),
+                (Path::new("list").join(2usize).join("c"), "list[2].c"),
+                (Path::new("a"), "a again"),
+            ]),
+            json!({
+                "errors": ["root"],
+                "fields": {
+                    "a": {
+                        "errors": ["a", "a again"],
+                        "fields": { "b": { "errors": ["a.b"] } },
+                    },
+                    "list": {
+                        "items": [null, null, { "fields": { "c": { "errors": ["list[2].c"] } } }],
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn numeric_key_and_index_at_same_level() {
+        assert_eq!(
+            tree(&[
+                (Path::new("v").join("0"), "key"),
+                (Path::new("v").join(0usize), "index"),
+            ]),
+            json!({
+                "fields": {
+                    "v": {
+                        "fields": { "0": { "errors": ["key"] } },
+                        "items": [{ "errors": ["index"] }],
+                    },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn reserved_names_as_keys() {
+        assert_eq!(
+            tree(&[
+                (Path::new("errors"), "a"),
+                (Path::new("fields").join("items"), "b"),
+            ]),
+            json!({
+                "fields": {
+                    "errors": { "errors": ["a"] },
+                    "fields": { "fields": { "items": { "errors": ["b"] } } },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn no_key_components_are_transparent() {
+        assert_eq!(
+            tree(&[(Path::new("opt").join(NoKey::default()), "inner")]),
+            json!({ "fields": { "opt": { "errors": ["inner"] } } })
+        );
+    }
+
+    #[test]
+    fn ordering_is_independent_of_report_order() {
+        let to_string = |errors: &[(Path, &str)]| {
+            let mut report = Report::new();
+            for (path, message) in errors {
+                report.append(path.clone(), Error::new(*message));
+            }
+            serde_json::to_string(&report.tree()).unwrap()
+        };
+
+        assert_eq!(
+            to_string(&[
+                (Path::new("b"), "b"),
+                (Path::new("a"), "a"),
+                (Path::new("l").join(1usize), "1"),
+                (Path::new("l").join(0usize), "0"),
+            ]),
+            to_string(&[
+                (Path::new("l").join(0usize), "0"),
+                (Path::new("a"), "a"),
+                (Path::new("l").join(1usize), "1"),
+                (Path::new("b"), "b"),
+            ]),
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+use super::{Error, Path, Report};
+
+/// A view of a [`Report`] in the JSON shape used by the `validator` crate, created using [`Report::validator_compat`].
+///
+/// The report serializes as an object which maps each path to the list of errors reported at that path:
+///
+/// ```rust
+/// # use garde::{Error, Path, Report};
+/// let mut report = Report::new();
+/// report.append(
+///     Path::new("name"),
+///     Error::builder()
+///         .message("length is lower than 1")
+///         .code("length.min")
+///         .param("min", 1)
+///         .build(),
+/// );
+///
+/// // {
+/// //   "name": [
+/// //     { "code": "length.min", "message": "length is lower than 1", "params": { "min": "1" } }
+/// //   ]
+/// // }
+/// # let _ = report.validator_compat();
+/// ```
+///
+/// Paths are flattened into keys such as `address.city` or `tags[0]`, the same way they are displayed.
+/// Errors reported at the root (e.g. by a struct-level `custom` rule) use the key `__all__`.
+/// Errors without a code use the code `invalid`.
+///
+/// Keys appear in the order in which their first error was reported, and errors within a key keep the order in which they were reported.
+pub struct ValidatorCompat<'a> {
+    entries: Vec<(String, Vec<&'a Error>)>,
+}
+
+impl<'a> ValidatorCompat<'a> {
+    pub(super) fn new(report: &'a Report) -> Self {
+        let mut entries = Vec::<(String, Vec<&'a Error>)>::new();
+        for (path, error) in report.iter() {
+            let key = key(path);
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, errors)) => errors.push(error),
+                None => entries.push((key, vec![error])),
+            }
+        }
+        Self { entries }
+    }
+}
+
+fn key(path: &Path) -> String {
+    let key = path.to_string();
+    match key.is_empty() {
+        true => String::from("__all__"),
+        false => key,
+    }
+}
+
+impl<'a> Serialize for ValidatorCompat<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+        for (key, errors) in &self.entries {
+            let errors = errors.iter().map(|error| Entry(error)).collect::<Vec<_>>();
+            map.serialize_entry(key, &errors)?;
+        }
+        map.end()
+    }
+}
+
+struct Entry<'a>(&'a Error);
+
+impl<'a> Serialize for Entry<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(3))?;
+        map.serialize_entry("code", self.0.code().unwrap_or("invalid"))?;
+        map.serialize_entry("message", self.0.message())?;
+        map.serialize_entry("params", &Params(self.0))?;
+        map.end()
+    }
+}
+
+struct Params<'a>(&'a Error);
+
+impl<'a> Serialize for Params<'a> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (key, value) in self.0.params() {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn golden() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("name"),
+            Error::builder()
+                .message("length is lower than 1")
+                .code("length.min")
+                .param("min", 1)
+                .build(),
+        );
+        report.append(
+            Path::new("tags").join(0usize),
+            Error::new("not alphanumeric").with_code("alphanumeric"),
+        );
+        report.append(Path::new("name"), Error::new("taken"));
+        report.append(Path::empty(), Error::new("passwords do not match"));
+
+        assert_eq!(
+            serde_json::to_string_pretty(&report.validator_compat()).unwrap(),
+            r#"{
+  "name": [
+    {
+      "code": "length.min",
+      "message": "length is lower than 1",
+      "params": {
+        "min": "1"
+      }
+    },
+    {
+      "code": "invalid",
+      "message": "taken",
+      "params": {}
+    }
+  ],
+  "tags[0]": [
+    {
+      "code": "alphanumeric",
+      "message": "not alphanumeric",
+      "params": {}
+    }
+  ],
+  "__all__": [
+    {
+      "code": "invalid",
+      "message": "passwords do not match",
+      "params": {}
+    }
+  ]
+}"#
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(
+            serde_json::to_string(&Report::new().validator_compat()).unwrap(),
+            "{}"
+        );
+    }
+}
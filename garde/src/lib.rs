@@ -1,17 +1,23 @@
 #![doc = include_str!("../README.md")]
 
 pub mod error;
+#[cfg(feature = "reflection")]
+pub mod reflection;
 pub mod rules;
+#[cfg(feature = "schema")]
+pub mod schema;
 pub mod validate;
 
-pub use error::{Error, Path, Report};
+pub use error::{Error, Path, PathComponent, Report, Selector};
 #[cfg(feature = "derive")]
-pub use garde_derive::{select, Validate};
-pub use validate::{Unvalidated, Valid, Validate};
+pub use garde_derive::{select, Discriminants, Validate};
+pub use validate::{validate, validate_with, Unvalidated, Valid, Validate, ValidationOptions};
 
 pub type Result = ::core::result::Result<(), Error>;
 
 pub mod external {
+    #[cfg(feature = "schema")]
+    pub use serde_json;
     pub use {compact_str, smallvec};
 }
 
@@ -67,4 +73,33 @@ pub mod util {
             }
         }
     }
+
+    /// Wraps the value a built-in rule failed on, so that its `Debug` representation
+    /// can be attached to the error if the type implements `Debug`.
+    ///
+    /// Call as `(&Capture(value)).capture(error)` with both traits in scope.
+    /// If `T: Debug`, method resolution picks [`CaptureDebug`], otherwise it falls back to [`CaptureNone`].
+    pub struct Capture<'a, T: ?Sized>(pub &'a T);
+
+    pub trait CaptureDebug {
+        fn capture(&self, error: crate::Error) -> crate::Error;
+    }
+
+    impl<'a, T: std::fmt::Debug + ?Sized> CaptureDebug for Capture<'a, T> {
+        #[inline]
+        fn capture(&self, error: crate::Error) -> crate::Error {
+            error.with_value_debug(self.0)
+        }
+    }
+
+    pub trait CaptureNone {
+        fn capture(&self, error: crate::Error) -> crate::Error;
+    }
+
+    impl<'a, T: ?Sized> CaptureNone for &Capture<'a, T> {
+        #[inline]
+        fn capture(&self, error: crate::Error) -> crate::Error {
+            error
+        }
+    }
 }
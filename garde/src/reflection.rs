@@ -0,0 +1,83 @@
+//! Runtime access to the validation rules of a type.
+//!
+//! With the `reflection` feature, `#[derive(Validate)]` also implements [`Reflect`],
+//! which describes the rules of every field, e.g. to render client-side hints or to generate schemas:
+//!
+//! ```rust
+//! use garde::reflection::{Category, Reflect};
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(length(min = 3, max = 20))]
+//!     name: String,
+//! }
+//!
+//! let name = &User::rules()[0];
+//! assert_eq!(name.name, "name");
+//! assert_eq!(name.category, Some(Category::Strings));
+//! assert_eq!(name.rules[0].name, "length");
+//! assert_eq!(name.rules[0].param("min"), Some("3"));
+//! ```
+//!
+//! Parameters are the tokens of the rule's arguments rather than their values,
+//! so a bound read from the context is described as e.g. `ctx.max`. Their whitespace may differ from the source.
+
+/// Implemented by `#[derive(Validate)]` if the `reflection` feature is enabled.
+pub trait Reflect {
+    /// The fields of this type which have rules, in declaration order.
+    ///
+    /// The fields of an enum are named `<variant>.<field>`.
+    fn rules() -> &'static [FieldRuleMeta];
+}
+
+/// The rules of a single field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldRuleMeta {
+    /// The name of the field, which is its index for tuple structs.
+    pub name: &'static str,
+    /// The kind of field, inferred from the name of its type.
+    pub category: Option<Category>,
+    /// Whether the type of the field is an `Option`.
+    pub optional: bool,
+    /// Whether the type of the field is an integer primitive, e.g. `u8` or `Option<i64>`.
+    pub integer: bool,
+    /// The rules on the field itself, in the order they were declared in.
+    pub rules: &'static [RuleMeta],
+    /// The rules applied to the items of the field via `inner`.
+    pub inner: &'static [RuleMeta],
+}
+
+impl FieldRuleMeta {
+    /// Returns the rule with the given name, if the field has it.
+    pub fn rule(&self, name: &str) -> Option<&RuleMeta> {
+        self.rules.iter().find(|rule| rule.name == name)
+    }
+}
+
+/// A single rule, e.g. `length` with the parameters `min` and `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleMeta {
+    /// The name of the rule, or `not.<rule>` for rules inside of `not`.
+    pub name: &'static str,
+    pub params: &'static [(&'static str, &'static str)],
+}
+
+impl RuleMeta {
+    /// Returns the parameter with the given name.
+    pub fn param(&self, name: &str) -> Option<&'static str> {
+        self.params
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// A kind of field, inferred from the shape of its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Strings,
+    Numbers,
+    Lists,
+    Maps,
+    Paths,
+}
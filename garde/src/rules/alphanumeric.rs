@@ -8,6 +8,16 @@
 //! }
 //! ```
 //!
+//! Additional characters may be allowed via `extra`, in which case the error lists the disallowed characters found:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(alphanumeric(extra = "-_"))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Alphanumeric`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(alphanumeric)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -17,19 +27,54 @@ use crate::error::Error;
 
 pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
     if !v.validate_alphanumeric() {
-        return Err(Error::new("not alphanumeric"));
+        return Err(Error::new_static("not alphanumeric").with_code("alphanumeric"));
+    }
+    Ok(())
+}
+
+pub fn apply_extra<T: Alphanumeric>(v: &T, (extra,): (&str,)) -> Result<(), Error> {
+    let disallowed = v.find_disallowed_alphanumeric(extra);
+    if !disallowed.is_empty() {
+        return Err(Error::new(format!(
+            "not alphanumeric, found disallowed characters {disallowed:?}"
+        ))
+        .with_code("alphanumeric"));
     }
     Ok(())
 }
 
 pub trait Alphanumeric {
     fn validate_alphanumeric(&self) -> bool;
+
+    /// Returns every distinct character which is neither alphanumeric nor in `extra`,
+    /// in order of first appearance.
+    ///
+    /// The default implementation can't inspect the characters of the value, so it ignores `extra`,
+    /// and returns [`char::REPLACEMENT_CHARACTER`] if [`Alphanumeric::validate_alphanumeric`] fails.
+    fn find_disallowed_alphanumeric(&self, extra: &str) -> Vec<char> {
+        let _ = extra;
+        if self.validate_alphanumeric() {
+            Vec::new()
+        } else {
+            vec![char::REPLACEMENT_CHARACTER]
+        }
+    }
 }
 
 impl<T: AsStr> Alphanumeric for T {
     fn validate_alphanumeric(&self) -> bool {
         self.as_str().chars().all(|c| c.is_alphanumeric())
     }
+
+    fn find_disallowed_alphanumeric(&self, extra: &str) -> Vec<char> {
+        let mut disallowed = Vec::new();
+        for c in self.as_str().chars() {
+            if !c.is_alphanumeric() && !extra.contains(c) && !disallowed.contains(&c) {
+                disallowed.push(c);
+            }
+        }
+        disallowed
+    }
 }
 
 impl<T: Alphanumeric> Alphanumeric for Option<T> {
@@ -39,4 +84,11 @@ impl<T: Alphanumeric> Alphanumeric for Option<T> {
             None => true,
         }
     }
+
+    fn find_disallowed_alphanumeric(&self, extra: &str) -> Vec<char> {
+        match self {
+            Some(value) => value.find_disallowed_alphanumeric(extra),
+            None => Vec::new(),
+        }
+    }
 }
@@ -8,6 +8,27 @@
 //! }
 //! ```
 //!
+//! Additional characters may be allowed via `extra`, in which case the error lists the disallowed characters found:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ascii(extra = "-_"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! `printable` only allows printable ASCII characters, from `' '` to `'~'`, so it rejects control characters such as `'\n'`.
+//! The error reports the first character which is not allowed, and its position in characters:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ascii(printable))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Ascii`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ascii)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -17,19 +38,83 @@ use crate::error::Error;
 
 pub fn apply<T: Ascii>(v: &T, _: ()) -> Result<(), Error> {
     if !v.validate_ascii() {
-        return Err(Error::new("not ascii"));
+        return Err(Error::new_static("not ascii").with_code("ascii"));
+    }
+    Ok(())
+}
+
+pub fn apply_extra<T: Ascii>(v: &T, (extra,): (&str,)) -> Result<(), Error> {
+    let disallowed = v.find_disallowed_ascii(extra);
+    if !disallowed.is_empty() {
+        return Err(Error::new(format!(
+            "not ascii, found disallowed characters {disallowed:?}"
+        ))
+        .with_code("ascii"));
+    }
+    Ok(())
+}
+
+pub fn apply_printable<T: Ascii>(v: &T, _: ()) -> Result<(), Error> {
+    if let Some((position, c)) = v.find_non_printable_ascii() {
+        return Err(Error::new(format!(
+            "not printable ascii, found {c:?} at position {position}"
+        ))
+        .with_code("ascii"));
     }
     Ok(())
 }
 
 pub trait Ascii {
     fn validate_ascii(&self) -> bool;
+
+    /// Returns every distinct character which is neither ASCII nor in `extra`,
+    /// in order of first appearance.
+    ///
+    /// The default implementation can't inspect the characters of the value, so it ignores `extra`,
+    /// and returns [`char::REPLACEMENT_CHARACTER`] if [`Ascii::validate_ascii`] fails.
+    fn find_disallowed_ascii(&self, extra: &str) -> Vec<char> {
+        let _ = extra;
+        if self.validate_ascii() {
+            Vec::new()
+        } else {
+            vec![char::REPLACEMENT_CHARACTER]
+        }
+    }
+
+    /// Returns the first character which is not printable ASCII, and its position in characters.
+    ///
+    /// The default implementation can't inspect the characters of the value, so it only rejects values
+    /// for which [`Ascii::validate_ascii`] fails, reporting [`char::REPLACEMENT_CHARACTER`] at position 0.
+    fn find_non_printable_ascii(&self) -> Option<(usize, char)> {
+        if self.validate_ascii() {
+            None
+        } else {
+            Some((0, char::REPLACEMENT_CHARACTER))
+        }
+    }
 }
 
 impl<T: AsStr> Ascii for T {
     fn validate_ascii(&self) -> bool {
         self.as_str().is_ascii()
     }
+
+    fn find_disallowed_ascii(&self, extra: &str) -> Vec<char> {
+        let mut disallowed = Vec::new();
+        for c in self.as_str().chars() {
+            if !c.is_ascii() && !extra.contains(c) && !disallowed.contains(&c) {
+                disallowed.push(c);
+            }
+        }
+        disallowed
+    }
+
+    fn find_non_printable_ascii(&self) -> Option<(usize, char)> {
+        self.as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !matches!(c, ' '..='~'))
+    }
 }
 
 impl<T: Ascii> Ascii for Option<T> {
@@ -39,4 +124,15 @@ impl<T: Ascii> Ascii for Option<T> {
             None => true,
         }
     }
+
+    fn find_disallowed_ascii(&self, extra: &str) -> Vec<char> {
+        match self {
+            Some(value) => value.find_disallowed_ascii(extra),
+            None => Vec::new(),
+        }
+    }
+
+    fn find_non_printable_ascii(&self) -> Option<(usize, char)> {
+        self.as_ref().and_then(Ascii::find_non_printable_ascii)
+    }
 }
@@ -0,0 +1,72 @@
+//! At-least-one-of validation for groups of fields.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! #[garde(at_least_one(email, phone))]
+//! struct Contact {
+//!     #[garde(skip)]
+//!     email: Option<String>,
+//!     #[garde(skip)]
+//!     phone: Option<String>,
+//! }
+//! ```
+//!
+//! This is a container-level rule: it is placed on the struct, and names the fields it checks.
+//! It fails if none of the fields are present. The error is reported at the path of the struct,
+//! or at `path` if given, e.g. `#[garde(at_least_one(email, phone, path = "contact"))]`.
+//!
+//! A value is present if it is a string which is not empty or only whitespace, `Some` of a present value,
+//! or any number, `bool` or `char`.
+//!
+//! The entrypoint is the [`Present`] trait. Implementing this trait for a type allows fields of that type
+//! to be named in the `#[garde(at_least_one(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+/// Checks a list of `(field name, is present)` pairs.
+pub fn apply(fields: &[(&str, bool)]) -> Result<(), Error> {
+    if fields.iter().any(|(_, present)| *present) {
+        return Ok(());
+    }
+    let names = fields
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(Error::new(format!("at least one of {names} must be set")).with_code("at_least_one"))
+}
+
+pub trait Present {
+    fn is_present(&self) -> bool;
+}
+
+impl<T: AsStr> Present for T {
+    fn is_present(&self) -> bool {
+        !self.as_str().trim().is_empty()
+    }
+}
+
+impl<T: Present> Present for Option<T> {
+    fn is_present(&self) -> bool {
+        self.as_ref().is_some_and(Present::is_present)
+    }
+}
+
+macro_rules! impl_always_present {
+    ($($T:ty),*) => {
+        $(
+            impl Present for $T {
+                fn is_present(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_always_present!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char
+);
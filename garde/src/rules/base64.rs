@@ -0,0 +1,66 @@
+//! Base64 validation using the [`base64`](https://docs.rs/base64) crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(base64(decoded_len = 32))]
+//!     key: String,
+//!     #[garde(base64)]
+//!     data: String,
+//! }
+//! ```
+//!
+//! The value must use the standard alphabet with padding, as defined in [RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-4).
+//! If `decoded_len` is given, the value must also decode to exactly that many bytes.
+//! The decoded bytes are discarded.
+//!
+//! The entrypoint is the [`Base64`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(base64)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Base64>(v: &T, (decoded_len,): (Option<usize>,)) -> Result<(), Error> {
+    let actual = match v.decoded_len() {
+        Ok(Some(len)) => len,
+        Ok(None) => return Ok(()),
+        Err(_) => return Err(Error::new_static("not valid base64").with_code("base64.invalid")),
+    };
+    match decoded_len {
+        Some(expected) if actual != expected => Err(Error::new(format!(
+            "decodes to {actual} bytes instead of {expected}"
+        ))
+        .with_code("base64.length")),
+        _ => Ok(()),
+    }
+}
+
+pub trait Base64 {
+    type Error;
+
+    /// Returns the number of decoded bytes, or `None` if there is no value to validate.
+    fn decoded_len(&self) -> Result<Option<usize>, Self::Error>;
+}
+
+impl<T: AsStr> Base64 for T {
+    type Error = ::base64::DecodeError;
+
+    fn decoded_len(&self) -> Result<Option<usize>, Self::Error> {
+        use ::base64::Engine as _;
+
+        let decoded = ::base64::engine::general_purpose::STANDARD.decode(self.as_str())?;
+        Ok(Some(decoded.len()))
+    }
+}
+
+impl<T: Base64> Base64 for Option<T> {
+    type Error = T::Error;
+
+    fn decoded_len(&self) -> Result<Option<usize>, Self::Error> {
+        match self {
+            Some(value) => value.decoded_len(),
+            None => Ok(None),
+        }
+    }
+}
@@ -16,6 +16,8 @@
 //! In case of string types, [`HasByteLength::byte_length`] should return the number of _bytes_ as opposed to the number of _characters_.
 //! For validation of length counted in _characters_, see the [`crate::rules::length`] rule.
 //!
+//! `OsStr` and `OsString` also implement [`HasByteLength`], counting the bytes of their platform-specific encoding, whether or not they are valid UTF-8.
+//!
 //! Here's what implementing the trait for a custom string-like type might look like:
 //! ```rust
 //! #[repr(transparent)]
@@ -35,10 +37,12 @@ pub fn apply<T: ByteLength>(v: &T, (min, max): (usize, usize)) -> Result<(), Err
     if let Err(e) = v.validate_byte_length(min, max) {
         match e {
             InvalidLength::Min => {
-                return Err(Error::new(format!("byte length is lower than {min}")))
+                return Err(Error::new(format!("byte length is lower than {min}"))
+                    .with_code("byte_length.min"))
             }
             InvalidLength::Max => {
-                return Err(Error::new(format!("byte length is greater than {max}")))
+                return Err(Error::new(format!("byte length is greater than {max}"))
+                    .with_code("byte_length.max"))
             }
         }
     }
@@ -81,6 +85,18 @@ impl<T: ByteLength> ByteLength for Option<T> {
     }
 }
 
+impl HasByteLength for std::ffi::OsString {
+    fn byte_length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl HasByteLength for &std::ffi::OsStr {
+    fn byte_length(&self) -> usize {
+        self.len()
+    }
+}
+
 impl<T: AsByteSlice> HasByteLength for T {
     fn byte_length(&self) -> usize {
         self.as_byte_slice().len()
@@ -91,7 +107,7 @@ pub trait AsByteSlice {
     fn as_byte_slice(&self) -> &[u8];
 }
 
-impl<'a> AsByteSlice for &'a [u8] {
+impl AsByteSlice for &[u8] {
     fn as_byte_slice(&self) -> &[u8] {
         self
     }
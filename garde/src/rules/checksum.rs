@@ -0,0 +1,159 @@
+//! Check digit validation of digit strings.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(checksum(algo = "mod10"))]
+//!     account: String,
+//!     #[garde(checksum(algo = "mod11"))]
+//!     reference: String,
+//! }
+//! ```
+//!
+//! The value must consist of ASCII digits only, the last of which is the check digit.
+//!
+//! - `mod10` is the [Luhn algorithm](https://en.wikipedia.org/wiki/Luhn_algorithm), which is also used by credit card numbers and IMEIs.
+//! - `mod11` weighs each digit by its position from the right, starting at `1` for the check digit,
+//!   and requires the sum to be divisible by 11. The check digit may be `X`, which stands for `10`, as in ISBN-10.
+//!
+//! See also the [`isbn`][`super::isbn`] and [`imei`][`super::imei`] rules, which are built on top of these algorithms.
+//!
+//! The entrypoint is the [`Checksum`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(checksum(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Checksum>(v: &T, (algorithm,): (Algorithm,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_checksum(algorithm) {
+        let error = match e {
+            InvalidChecksum::Format => {
+                Error::new_static("not a string of digits").with_code("checksum.format")
+            }
+            InvalidChecksum::Length => {
+                Error::new_static("too short to contain a check digit").with_code("checksum.length")
+            }
+            InvalidChecksum::Checksum => {
+                Error::new(format!("invalid {algorithm} check digit")).with_code("checksum")
+            }
+        };
+        return Err(error);
+    }
+    Ok(())
+}
+
+/// The check digit algorithm used by the `checksum` rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Algorithm {
+    Mod10,
+    Mod11,
+}
+
+impl Display for Algorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Algorithm::Mod10 => f.write_str("mod10"),
+            Algorithm::Mod11 => f.write_str("mod11"),
+        }
+    }
+}
+
+/// Why a value failed a checksum-based rule.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InvalidChecksum {
+    /// The value contains something other than digits.
+    Format,
+    /// The value has the wrong number of digits.
+    Length,
+    /// The check digit doesn't match.
+    Checksum,
+}
+
+pub trait Checksum {
+    fn validate_checksum(&self, algorithm: Algorithm) -> Result<(), InvalidChecksum>;
+}
+
+impl<T: AsStr> Checksum for T {
+    fn validate_checksum(&self, algorithm: Algorithm) -> Result<(), InvalidChecksum> {
+        let digits = digits(self.as_str(), &[], algorithm == Algorithm::Mod11)?;
+        if digits.clone().count() < 2 {
+            return Err(InvalidChecksum::Length);
+        }
+        let valid = match algorithm {
+            Algorithm::Mod10 => mod10(digits),
+            Algorithm::Mod11 => mod11(digits),
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(InvalidChecksum::Checksum),
+        }
+    }
+}
+
+impl<T: Checksum> Checksum for Option<T> {
+    fn validate_checksum(&self, algorithm: Algorithm) -> Result<(), InvalidChecksum> {
+        match self {
+            Some(value) => value.validate_checksum(algorithm),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Separators which may appear between the digits of an ISBN or IMEI.
+pub(crate) const SEPARATORS: &[u8] = b"- ";
+
+/// Iterates over the digits of a string, after checking that it only contains digits.
+///
+/// Any of the `ignore` bytes are skipped. If `check_x` is set, the last digit may be `X` or `x`, which is converted to `10`.
+pub(crate) fn digits<'a>(
+    value: &'a str,
+    ignore: &'a [u8],
+    check_x: bool,
+) -> Result<impl DoubleEndedIterator<Item = u8> + Clone + 'a, InvalidChecksum> {
+    let significant = move || {
+        value
+            .bytes()
+            .enumerate()
+            .filter(move |(_, c)| !ignore.contains(c))
+    };
+    let last = significant().next_back().map(|(i, _)| i);
+    let digit = move |(i, c): (usize, u8)| match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'X' | b'x' if check_x && Some(i) == last => Some(10),
+        _ => None,
+    };
+    if significant().any(|c| digit(c).is_none()) {
+        return Err(InvalidChecksum::Format);
+    }
+    Ok(significant().filter_map(digit))
+}
+
+/// The Luhn algorithm, where every second digit from the right is doubled.
+pub(crate) fn mod10(digits: impl DoubleEndedIterator<Item = u8>) -> bool {
+    let sum: u32 = digits
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            let d = u32::from(d);
+            match i % 2 {
+                0 => d,
+                _ if d * 2 > 9 => d * 2 - 9,
+                _ => d * 2,
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Each digit is weighed by its position from the right, starting at `1`.
+pub(crate) fn mod11(digits: impl DoubleEndedIterator<Item = u8>) -> bool {
+    let sum: u64 = digits
+        .rev()
+        .enumerate()
+        .map(|(i, d)| (i as u64 + 1) * u64::from(d))
+        .sum();
+    sum.is_multiple_of(11)
+}
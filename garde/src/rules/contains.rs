@@ -12,6 +12,19 @@
 //! }
 //! ```
 //!
+//! Multiple needles may be given with `any` (the value must contain at least one of them)
+//! or `all` (the value must contain every one of them):
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains(any = ["foo", "bar"]))]
+//!     v: String,
+//!     #[garde(contains(all = ["foo", "bar"]))]
+//!     w: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Contains`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -21,7 +34,21 @@ use crate::error::Error;
 
 pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_contains(pat) {
-        return Err(Error::new(format!("does not contain \"{pat}\"")));
+        return Err(Error::new(format!("does not contain \"{pat}\"")).with_code("contains"));
+    }
+    Ok(())
+}
+
+pub fn apply_any<T: Contains>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_contains(pat)) {
+        return Err(Error::new(format!("does not contain any of {pats:?}")).with_code("contains"));
+    }
+    Ok(())
+}
+
+pub fn apply_all<T: Contains>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().all(|pat| v.validate_contains(pat)) {
+        return Err(Error::new(format!("does not contain all of {pats:?}")).with_code("contains"));
     }
     Ok(())
 }
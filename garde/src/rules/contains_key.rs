@@ -0,0 +1,65 @@
+//! Map key presence validation.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains_key("version"))]
+//!     v: HashMap<String, String>,
+//! }
+//! ```
+//!
+//! The key is looked up via [`std::borrow::Borrow`], so a `&str` may be used to look up a `String` key.
+//!
+//! The entrypoint is the [`ContainsKey`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains_key)]` rule.
+//!
+//! This trait is implemented for [`HashMap`] and [`BTreeMap`].
+
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash};
+
+use crate::error::Error;
+
+pub fn apply<T: ContainsKey<Q>, Q: Debug + ?Sized>(v: &T, (key,): (&Q,)) -> Result<(), Error> {
+    if !v.validate_contains_key(key) {
+        return Err(Error::new(format!("does not contain key {key:?}")).with_code("contains_key"));
+    }
+    Ok(())
+}
+
+pub trait ContainsKey<Q: ?Sized> {
+    fn validate_contains_key(&self, key: &Q) -> bool;
+}
+
+impl<K, V, S, Q> ContainsKey<Q> for HashMap<K, V, S>
+where
+    K: Borrow<Q> + Hash + Eq,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    fn validate_contains_key(&self, key: &Q) -> bool {
+        self.contains_key(key)
+    }
+}
+
+impl<K, V, Q> ContainsKey<Q> for BTreeMap<K, V>
+where
+    K: Borrow<Q> + Ord,
+    Q: Ord + ?Sized,
+{
+    fn validate_contains_key(&self, key: &Q) -> bool {
+        self.contains_key(key)
+    }
+}
+
+impl<T: ContainsKey<Q>, Q: ?Sized> ContainsKey<Q> for Option<T> {
+    fn validate_contains_key(&self, key: &Q) -> bool {
+        match self {
+            Some(value) => value.validate_contains_key(key),
+            None => true,
+        }
+    }
+}
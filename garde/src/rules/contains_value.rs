@@ -0,0 +1,57 @@
+//! Map value presence validation.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(contains_value("admin"))]
+//!     v: HashMap<String, String>,
+//! }
+//! ```
+//!
+//! The values are compared via [`PartialEq`], so a `&str` may be compared to a `String` value.
+//! Unlike [`contains_key`][`super::contains_key`], this visits every value of the map.
+//!
+//! The entrypoint is the [`ContainsValue`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains_value)]` rule.
+//!
+//! This trait is implemented for [`HashMap`] and [`BTreeMap`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Debug;
+
+use crate::error::Error;
+
+pub fn apply<T: ContainsValue<Q>, Q: Debug + ?Sized>(v: &T, (value,): (&Q,)) -> Result<(), Error> {
+    if !v.validate_contains_value(value) {
+        return Err(
+            Error::new(format!("does not contain value {value:?}")).with_code("contains_value")
+        );
+    }
+    Ok(())
+}
+
+pub trait ContainsValue<Q: ?Sized> {
+    fn validate_contains_value(&self, value: &Q) -> bool;
+}
+
+impl<K, V: PartialEq<Q>, S, Q: ?Sized> ContainsValue<Q> for HashMap<K, V, S> {
+    fn validate_contains_value(&self, value: &Q) -> bool {
+        self.values().any(|v| v == value)
+    }
+}
+
+impl<K, V: PartialEq<Q>, Q: ?Sized> ContainsValue<Q> for BTreeMap<K, V> {
+    fn validate_contains_value(&self, value: &Q) -> bool {
+        self.values().any(|v| v == value)
+    }
+}
+
+impl<T: ContainsValue<Q>, Q: ?Sized> ContainsValue<Q> for Option<T> {
+    fn validate_contains_value(&self, value: &Q) -> bool {
+        match self {
+            Some(map) => map.validate_contains_value(value),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Custom validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(custom(is_even))]
+//!     v: u32,
+//! }
+//!
+//! fn is_even(value: &u32, _: &()) -> garde::Result {
+//!     if value % 2 != 0 {
+//!         return Err(garde::Error::new("not even"));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Extra arguments may be passed to the validator with `args(...)`, after the value and the context.
+//! This allows one validator to be used with different parameters:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(custom(is_multiple_of, args(2)))]
+//!     v: u32,
+//!     #[garde(custom(is_multiple_of, args(3)))]
+//!     w: u32,
+//! }
+//!
+//! fn is_multiple_of(value: &u32, _: &(), n: u32) -> garde::Result {
+//!     if value % n != 0 {
+//!         return Err(garde::Error::new(format!("not a multiple of {n}")));
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Custom validators usually return a [`garde::Result`][`crate::Result`]. They may instead return a [`Flow`],
+//! which additionally allows them to control how the rest of the validation proceeds.
+//!
+//! Returning [`Flow::Abort`] stops the validation of the struct (or enum variant) containing the field.
+//! Errors which were already reported are kept, and the error carried by [`Flow::Abort`] (if any) is
+//! added to the report, but none of the remaining fields or rules are validated. If the struct is nested
+//! inside another one via `dive`, validation of the parent continues as normal.
+//!
+//! ```rust
+//! use garde::rules::custom::Flow;
+//!
+//! #[derive(garde::Validate)]
+//! struct Document {
+//!     #[garde(custom(supported_version))]
+//!     version: u32,
+//!     #[garde(length(min = 1))]
+//!     body: String,
+//! }
+//!
+//! fn supported_version(value: &u32, _: &()) -> Flow {
+//!     match value {
+//!         1 | 2 => Flow::Ok,
+//!         _ => Flow::Abort(Some(garde::Error::new("unsupported version"))),
+//!     }
+//! }
+//! ```
+//!
+//! Returning [`Flow::Skip`] is more targeted: it only stops the validation of the current field.
+//! The remaining rules of that field (including `dive`, `inner`, and `keys`) are not run, but the other fields are
+//! validated as normal. Within `inner(..)`, it stops the validation of the current item only.
+//!
+//! Rules on a field run in the order they are declared, so a custom rule placed first
+//! can suppress errors which would be meaningless after it:
+//!
+//! ```rust
+//! use garde::rules::custom::Flow;
+//!
+//! #[derive(garde::Validate)]
+//! struct Account {
+//!     #[garde(custom(not_reserved), pattern(r"^[a-z]+$"))]
+//!     username: String,
+//! }
+//!
+//! fn not_reserved(value: &str, _: &()) -> Flow {
+//!     match value {
+//!         "ROOT" | "ADMIN" => Flow::Skip(Some(garde::Error::new("reserved username"))),
+//!         _ => Flow::Ok,
+//!     }
+//! }
+//! ```
+
+use crate::error::Error;
+
+/// The result of a custom validator.
+///
+/// More variants may be added in the future, so matches on this enum need a wildcard arm.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Flow {
+    /// The value is valid.
+    Ok,
+    /// The value is invalid, validation continues as normal.
+    Err(Error),
+    /// Stop validating the struct this field belongs to, optionally reporting an error.
+    Abort(Option<Error>),
+    /// Stop validating the current field, optionally reporting an error.
+    Skip(Option<Error>),
+}
+
+/// Conversion of the return value of a custom validator into a [`Flow`].
+///
+/// This is implemented for [`garde::Result`][`crate::Result`] and [`Flow`].
+pub trait IntoFlow {
+    fn into_flow(self) -> Flow;
+}
+
+impl IntoFlow for Result<(), Error> {
+    #[inline]
+    fn into_flow(self) -> Flow {
+        match self {
+            Ok(()) => Flow::Ok,
+            Err(error) => Flow::Err(error),
+        }
+    }
+}
+
+impl IntoFlow for Flow {
+    #[inline]
+    fn into_flow(self) -> Flow {
+        self
+    }
+}
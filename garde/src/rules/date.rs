@@ -0,0 +1,154 @@
+//! Validation of dates relative to the current time.
+//!
+//! ```rust
+//! # #[cfg(feature = "chrono")] {
+//! struct Clock {
+//!     today: chrono::NaiveDate,
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! #[garde(context(Clock as ctx))]
+//! struct Test {
+//!     #[garde(date(past))]
+//!     birthday: chrono::NaiveDate,
+//!     #[garde(date(future, now = ctx.today))]
+//!     expires: chrono::NaiveDate,
+//! }
+//! # }
+//! ```
+//!
+//! The value must be strictly before (`past`) or after (`future`) the current time.
+//! The current time is taken from the system clock, unless it is given via `now`, which is usually read from the context for deterministic tests.
+//!
+//! The entrypoint is the [`Temporal`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(date)]` rule.
+//!
+//! If the `chrono` feature is enabled, this trait is implemented for `chrono::NaiveDate`, `chrono::NaiveDateTime`, and `chrono::DateTime`.
+//! If the `time` feature is enabled, it is implemented for `time::Date`, `time::PrimitiveDateTime`, and `time::OffsetDateTime`.
+
+use crate::error::Error;
+
+pub fn apply<T: Temporal>(v: &T, (tense, now): (Tense, Option<T::Instant>)) -> Result<(), Error> {
+    let Some(value) = v.instant() else {
+        return Ok(());
+    };
+    let now = now.unwrap_or_else(T::now);
+    let valid = match tense {
+        Tense::Past => value < now,
+        Tense::Future => value > now,
+    };
+    if !valid {
+        return Err(match tense {
+            Tense::Past => Error::new_static("must be in the past").with_code("date.past"),
+            Tense::Future => Error::new_static("must be in the future").with_code("date.future"),
+        });
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tense {
+    Past,
+    Future,
+}
+
+pub trait Temporal {
+    /// The type which values are compared as, e.g. `DateTime<Utc>` for any `DateTime`.
+    type Instant: PartialOrd;
+
+    /// Returns the current time according to the system clock.
+    fn now() -> Self::Instant;
+
+    /// Returns the value to compare, or `None` if there is nothing to validate.
+    fn instant(&self) -> Option<Self::Instant>;
+}
+
+#[cfg(feature = "chrono")]
+impl Temporal for chrono::NaiveDate {
+    type Instant = chrono::NaiveDate;
+
+    fn now() -> Self::Instant {
+        chrono::Utc::now().date_naive()
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Temporal for chrono::NaiveDateTime {
+    type Instant = chrono::NaiveDateTime;
+
+    fn now() -> Self::Instant {
+        chrono::Utc::now().naive_utc()
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> Temporal for chrono::DateTime<Tz> {
+    type Instant = chrono::DateTime<chrono::Utc>;
+
+    fn now() -> Self::Instant {
+        chrono::Utc::now()
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        Some(self.with_timezone(&chrono::Utc))
+    }
+}
+
+#[cfg(feature = "time")]
+impl Temporal for time::Date {
+    type Instant = time::Date;
+
+    fn now() -> Self::Instant {
+        time::OffsetDateTime::now_utc().date()
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Temporal for time::PrimitiveDateTime {
+    type Instant = time::PrimitiveDateTime;
+
+    fn now() -> Self::Instant {
+        let now = time::OffsetDateTime::now_utc();
+        time::PrimitiveDateTime::new(now.date(), now.time())
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        Some(*self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl Temporal for time::OffsetDateTime {
+    type Instant = time::OffsetDateTime;
+
+    fn now() -> Self::Instant {
+        time::OffsetDateTime::now_utc()
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        Some(*self)
+    }
+}
+
+impl<T: Temporal> Temporal for Option<T> {
+    type Instant = T::Instant;
+
+    fn now() -> Self::Instant {
+        T::now()
+    }
+
+    fn instant(&self) -> Option<Self::Instant> {
+        self.as_ref().and_then(Temporal::instant)
+    }
+}
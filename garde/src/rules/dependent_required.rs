@@ -0,0 +1,30 @@
+//! Conditionally required fields.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! #[garde(dependent_required(if = self.has_company, then = company_name))]
+//! struct Signup {
+//!     #[garde(skip)]
+//!     has_company: bool,
+//!     #[garde(length(max = 100))]
+//!     company_name: Option<String>,
+//! }
+//! ```
+//!
+//! This is a container-level rule: it is placed on the struct, and names the field it checks.
+//! If the condition is `true`, the field must be present, otherwise the error is reported at the path of the field.
+//! The condition is any `bool` expression, which may use `self` and the context. It may also be given as a string,
+//! e.g. `if = "self.has_company"`.
+//!
+//! Presence is checked like in [`at_least_one`][super::at_least_one], so a string which is empty or only whitespace is not present.
+//! The entrypoint is the [`Present`] trait, which is shared with that rule.
+
+use super::at_least_one::Present;
+use crate::error::Error;
+
+pub fn apply<T: Present>(v: &T, condition: bool) -> Result<(), Error> {
+    if condition && !v.is_present() {
+        return Err(Error::new_static("not set").with_code("dependent_required"));
+    }
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! E.164 phone number format validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(e164)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if it has the shape of an [E.164](https://en.wikipedia.org/wiki/E.164) number,
+//! i.e. it matches `^\+[1-9]\d{1,14}$`: a `+` followed by 2 to 15 digits, the first of which is not `0`.
+//!
+//! This only checks the format, not whether the number could actually exist, such as whether its country code is assigned.
+//! It is a lightweight alternative to the `phone_number` rule, which needs the `phone-number` feature.
+//!
+//! The entrypoint is the [`E164`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(e164)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: E164>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_e164() {
+        return Err(Error::new_static("not an E.164 phone number").with_code("e164"));
+    }
+    Ok(())
+}
+
+pub trait E164 {
+    fn validate_e164(&self) -> bool;
+}
+
+impl<T: AsStr> E164 for T {
+    fn validate_e164(&self) -> bool {
+        let Some(digits) = self.as_str().strip_prefix('+') else {
+            return false;
+        };
+        (2..=15).contains(&digits.len())
+            && !digits.starts_with('0')
+            && digits.bytes().all(|b| b.is_ascii_digit())
+    }
+}
+
+impl<T: E164> E164 for Option<T> {
+    fn validate_e164(&self) -> bool {
+        match self {
+            Some(value) => value.validate_e164(),
+            None => true,
+        }
+    }
+}
@@ -11,6 +11,24 @@
 //! The entrypoint is the [`Email`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(email)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! A type holding an email address which was already parsed may implement [`Email`] directly,
+//! returning `Ok(())` from [`Email::validate_email`] so the address is not parsed again.
+//! Implementing [`Email::email_len`] keeps the `max_len` check working for such a type.
+//!
+//! Values longer than [`DEFAULT_MAX_LEN`] bytes fail with the code `email.length` before they are parsed,
+//! which bounds the time spent on inputs of any size. The limit may be changed with `max_len`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(email(max_len = 254))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The `email` rule is purely syntactic. If the `dns` feature is enabled, the [`mx`] module
+//! additionally provides an async check that the domain of an email address has an MX record.
 
 use std::fmt::Display;
 use std::str::FromStr;
@@ -21,9 +39,21 @@ use regex::Regex;
 use super::AsStr;
 use crate::error::Error;
 
+/// The default maximum length of an email address in bytes: 64 for the user, 1 for the `@`, and 255 for the domain.
+pub const DEFAULT_MAX_LEN: usize = 320;
+
 pub fn apply<T: Email>(v: &T, _: ()) -> Result<(), Error> {
+    apply_max_len(v, (DEFAULT_MAX_LEN,))
+}
+
+pub fn apply_max_len<T: Email>(v: &T, (max,): (usize,)) -> Result<(), Error> {
+    if v.email_len().is_some_and(|len| len > max) {
+        return Err(
+            Error::new(format!("email is longer than {max} bytes")).with_code("email.length")
+        );
+    }
     if let Err(e) = v.validate_email() {
-        return Err(Error::new(format!("not a valid email: {e}")));
+        return Err(Error::new(format!("not a valid email: {e}")).with_code("email"));
     }
     Ok(())
 }
@@ -32,6 +62,13 @@ pub trait Email {
     type Error: Display;
 
     fn validate_email(&self) -> Result<(), Self::Error>;
+
+    /// The length of the value in bytes, which is checked against `max_len` before calling [`Email::validate_email`].
+    ///
+    /// The default implementation returns `None`, which skips the check.
+    fn email_len(&self) -> Option<usize> {
+        None
+    }
 }
 
 impl<T: AsStr> Email for T {
@@ -40,6 +77,10 @@ impl<T: AsStr> Email for T {
     fn validate_email(&self) -> Result<(), Self::Error> {
         parse_email(self.as_str())
     }
+
+    fn email_len(&self) -> Option<usize> {
+        Some(self.as_str().len())
+    }
 }
 
 impl<T: Email> Email for Option<T> {
@@ -51,6 +92,10 @@ impl<T: Email> Email for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn email_len(&self) -> Option<usize> {
+        self.as_ref().and_then(Email::email_len)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -150,6 +195,208 @@ fn is_smtp_addr(domain: &str) -> bool {
     std::net::IpAddr::from_str(domain).is_ok()
 }
 
+/// MX record verification for email domains.
+///
+/// **This performs network I/O**. A DNS query is sent for the domain of every distinct email address,
+/// which is why it is only available through an async function and is never run by `#[garde(email)]`.
+///
+/// ```rust,no_run
+/// use garde::rules::email::mx::{self, MxCache};
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let cache = MxCache::from_system_conf()?;
+/// mx::apply(&"test@example.com", &cache).await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Lookups are cached per domain in the [`MxCache`], so a single cache should be created for each validation run
+/// and shared by all of the fields validated in that run. If a lookup fails for any other reason than the domain
+/// having no records, the error has the code `email.mx_lookup`, and the domain is looked up again the next time.
+///
+/// There is no `#[garde(email(mx))]`, because derived validation is synchronous.
+/// Run [`apply`] after `validate` succeeds instead, e.g. in the signup handler.
+#[cfg(feature = "dns")]
+pub mod mx {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use compact_str::CompactString;
+    use hickory_resolver::error::{ResolveError, ResolveErrorKind};
+    use hickory_resolver::proto::op::ResponseCode;
+    use hickory_resolver::TokioAsyncResolver;
+
+    use super::{parse_email, AsStr};
+    use crate::error::Error;
+
+    pub async fn apply<T: AsStr>(v: &T, cache: &MxCache) -> Result<(), Error> {
+        let value = v.as_str();
+        if let Err(e) = parse_email(value) {
+            return Err(Error::new(format!("not a valid email: {e}")).with_code("email"));
+        }
+        // `parse_email` succeeded, so there is always an `@`.
+        let (_, domain) = value.split_once('@').unwrap();
+        match cache.has_mx(domain).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(Error::new(format!(
+                "not a valid email: domain `{domain}` has no MX record"
+            ))
+            .with_code("email.mx")),
+            Err(e) => Err(Error::new(format!(
+                "could not look up the MX records of `{domain}`: {e}"
+            ))
+            .with_code("email.mx_lookup")),
+        }
+    }
+
+    /// A per-validation-run cache of MX lookups.
+    pub struct MxCache {
+        resolver: TokioAsyncResolver,
+        lookups: Mutex<HashMap<CompactString, bool>>,
+    }
+
+    impl MxCache {
+        /// Create a cache which performs lookups using `resolver`.
+        pub fn new(resolver: TokioAsyncResolver) -> Self {
+            Self {
+                resolver,
+                lookups: Mutex::new(HashMap::new()),
+            }
+        }
+
+        /// Create a cache which performs lookups using the system's DNS configuration.
+        pub fn from_system_conf() -> Result<Self, ResolveError> {
+            Ok(Self::new(TokioAsyncResolver::tokio_from_system_conf()?))
+        }
+
+        /// Returns `true` if `domain` accepts mail, which is the case if it has at least one MX record.
+        ///
+        /// As per RFC 5321, a domain without MX records but with an A or AAAA record has an implicit MX record,
+        /// and is accepted, while a domain with only a "null MX" record (RFC 7505) is not.
+        /// Address literals (`[127.0.0.1]`) have no MX records, and are always accepted.
+        ///
+        /// Only the absence of records is cached. Any other failure, such as a timeout or an unreachable name server,
+        /// is returned as an error, and the domain is looked up again the next time.
+        pub async fn has_mx(&self, domain: &str) -> Result<bool, ResolveError> {
+            if domain.starts_with('[') {
+                return Ok(true);
+            }
+
+            let domain = domain.to_ascii_lowercase();
+            if let Some(found) = self.lookups.lock().unwrap().get(domain.as_str()) {
+                return Ok(*found);
+            }
+
+            let found = match self.resolver.mx_lookup(domain.as_str()).await {
+                Ok(lookup) if lookup.iter().next().is_some() => {
+                    lookup.iter().any(|mx| !mx.exchange().is_root())
+                }
+                Ok(_) => self.has_implicit_mx(&domain).await?,
+                Err(e) => match e.kind() {
+                    ResolveErrorKind::NoRecordsFound { response_code, .. }
+                        if *response_code != ResponseCode::NXDomain =>
+                    {
+                        self.has_implicit_mx(&domain).await?
+                    }
+                    ResolveErrorKind::NoRecordsFound { .. } => false,
+                    _ => return Err(e),
+                },
+            };
+            self.lookups
+                .lock()
+                .unwrap()
+                .insert(CompactString::from(domain), found);
+            Ok(found)
+        }
+
+        /// Returns `true` if `domain` has an A or AAAA record, which is its implicit MX record.
+        async fn has_implicit_mx(&self, domain: &str) -> Result<bool, ResolveError> {
+            match self.resolver.lookup_ip(domain).await {
+                Ok(lookup) => Ok(lookup.iter().next().is_some()),
+                Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => Ok(false),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use hickory_resolver::config::{ResolverConfig, ResolverOpts};
+        use hickory_resolver::TokioAsyncResolver;
+
+        use super::{apply, MxCache};
+
+        /// A cache whose resolver has no name servers, so every lookup fails without network access.
+        fn offline_cache() -> MxCache {
+            MxCache::new(TokioAsyncResolver::tokio(
+                ResolverConfig::new(),
+                ResolverOpts::default(),
+            ))
+        }
+
+        fn block_on<F: std::future::Future>(future: F) -> F::Output {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(future)
+        }
+
+        #[test]
+        fn address_literal_is_accepted_without_lookup() {
+            let cache = offline_cache();
+            assert!(block_on(cache.has_mx("[127.0.0.1]")).unwrap());
+            assert!(cache.lookups.lock().unwrap().is_empty());
+        }
+
+        #[test]
+        fn lookup_failures_are_not_cached() {
+            let cache = offline_cache();
+            assert!(block_on(cache.has_mx("example.test")).is_err());
+            assert!(cache.lookups.lock().unwrap().is_empty());
+        }
+
+        #[test]
+        fn lookups_are_cached_by_lowercase_domain() {
+            let cache = offline_cache();
+            // The resolver answers `.invalid` with NXDOMAIN without asking a name server (RFC 6761).
+            assert!(!block_on(cache.has_mx("Example.Invalid")).unwrap());
+            assert_eq!(
+                cache.lookups.lock().unwrap().get("example.invalid"),
+                Some(&false)
+            );
+
+            // A second lookup for the same domain is answered from the cache.
+            cache
+                .lookups
+                .lock()
+                .unwrap()
+                .insert("example.invalid".into(), true);
+            assert!(block_on(cache.has_mx("EXAMPLE.invalid")).unwrap());
+            assert_eq!(cache.lookups.lock().unwrap().len(), 1);
+        }
+
+        #[test]
+        fn apply_reports_missing_mx() {
+            let cache = offline_cache();
+            let error = block_on(apply(&"test@example.invalid", &cache)).unwrap_err();
+            assert_eq!(error.code(), Some("email.mx"));
+            assert_eq!(
+                error.message(),
+                "not a valid email: domain `example.invalid` has no MX record"
+            );
+
+            let error = block_on(apply(&"test@example.test", &cache)).unwrap_err();
+            assert_eq!(error.code(), Some("email.mx_lookup"));
+
+            let error = block_on(apply(&"not an email", &cache)).unwrap_err();
+            assert_eq!(error.code(), Some("email"));
+            // Neither the failed lookup nor the invalid address are cached.
+            assert_eq!(cache.lookups.lock().unwrap().len(), 1);
+        }
+    }
+}
+
 // Tests taken from `validator`, modified for this API
 // https://github.com/Keats/validator/blob/09efa7e78e6fbc853a6a56af6904a00e2e6632b8/validator/src/validation/email.rs#L76
 #[cfg(test)]
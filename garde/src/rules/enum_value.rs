@@ -0,0 +1,76 @@
+//! Raw enum discriminant validation.
+//!
+//! ```rust
+//! #[derive(garde::Discriminants)]
+//! #[repr(i32)]
+//! enum Color {
+//!     Red = 1,
+//!     Green = 2,
+//!     Blue = 4,
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(enum_value(Color))]
+//!     v: i32,
+//! }
+//! ```
+//!
+//! The value passes if it is one of the discriminants declared by the enum, so a `3` would fail in the example above.
+//! The discriminants are provided by the [`Discriminants`] trait, which may be derived for fieldless enums with a `#[repr(<integer>)]` attribute.
+//! The field must have the same integer type as that `repr`.
+//!
+//! The entrypoint is the [`EnumValue`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(enum_value)]` rule.
+//!
+//! This trait is implemented for all primitive integer types.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: EnumValue>(v: &T, (discriminants,): (&[T::Repr],)) -> Result<(), Error> {
+    if let Some(value) = v.enum_value() {
+        if !discriminants.contains(&value) {
+            return Err(Error::new(format!("unknown enum value {value}")).with_code("enum_value"));
+        }
+    }
+    Ok(())
+}
+
+/// The discriminants of a fieldless enum, in declaration order.
+pub trait Discriminants {
+    type Repr: 'static;
+
+    const DISCRIMINANTS: &'static [Self::Repr];
+}
+
+pub trait EnumValue {
+    type Repr: PartialEq + Display;
+
+    /// Returns the raw value, or `None` if there is nothing to validate.
+    fn enum_value(&self) -> Option<Self::Repr>;
+}
+
+macro_rules! impl_enum_value {
+    ($($T:ty),*) => {
+        $(
+            impl EnumValue for $T {
+                type Repr = $T;
+
+                fn enum_value(&self) -> Option<Self::Repr> {
+                    Some(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_enum_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<T: EnumValue> EnumValue for Option<T> {
+    type Repr = T::Repr;
+
+    fn enum_value(&self) -> Option<Self::Repr> {
+        self.as_ref().and_then(EnumValue::enum_value)
+    }
+}
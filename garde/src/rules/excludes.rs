@@ -0,0 +1,129 @@
+//! Forbidden term validation.
+//!
+//! ```rust
+//! const RESERVED: &[&str] = &["admin", "root"];
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(excludes(["admin", "root"]))]
+//!     v: String,
+//!     #[garde(excludes(RESERVED, ignore_case))]
+//!     w: Vec<String>,
+//! }
+//! ```
+//!
+//! A string fails if it contains any of the terms as a substring, and a collection fails if any of its items is equal to one of the terms.
+//! The error reports the first term which was found. With `ignore_case`, values and terms are compared by their lowercase forms.
+//!
+//! The entrypoint is the [`Excludes`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(excludes)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`,
+//! and is implemented for collections of `T: garde::rules::AsStr`, such as `Vec<String>` or `&[&str]`.
+
+use std::collections::{BTreeSet, HashSet, VecDeque};
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Excludes>(v: &T, (terms,): (&[&str],)) -> Result<(), Error> {
+    check(v.find_excluded(terms, false))
+}
+
+pub fn apply_ignore_case<T: Excludes>(v: &T, (terms,): (&[&str],)) -> Result<(), Error> {
+    check(v.find_excluded(terms, true))
+}
+
+fn check(found: Option<&str>) -> Result<(), Error> {
+    match found {
+        Some(term) => {
+            Err(Error::new(format!("contains forbidden term \"{term}\"")).with_code("excludes"))
+        }
+        None => Ok(()),
+    }
+}
+
+pub trait Excludes {
+    /// Returns the first of `terms` which is present in the value.
+    fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str>;
+}
+
+impl<T: AsStr> Excludes for T {
+    fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str> {
+        let value = self.as_str();
+        if ignore_case {
+            let value = value.to_lowercase();
+            terms
+                .iter()
+                .find(|term| value.contains(&term.to_lowercase()))
+                .copied()
+        } else {
+            terms.iter().find(|term| value.contains(**term)).copied()
+        }
+    }
+}
+
+impl<T: Excludes> Excludes for Option<T> {
+    fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str> {
+        match self {
+            Some(value) => value.find_excluded(terms, ignore_case),
+            None => None,
+        }
+    }
+}
+
+/// Returns the first of `terms` which is equal to one of the `items`.
+fn find_item<'a, 't, T: AsStr + 'a>(
+    items: impl IntoIterator<Item = &'a T>,
+    terms: &[&'t str],
+    ignore_case: bool,
+) -> Option<&'t str> {
+    if ignore_case {
+        let lowercase = terms
+            .iter()
+            .map(|term| term.to_lowercase())
+            .collect::<Vec<_>>();
+        items.into_iter().find_map(|item| {
+            let item = item.as_str().to_lowercase();
+            let i = lowercase.iter().position(|term| *term == item)?;
+            Some(terms[i])
+        })
+    } else {
+        items
+            .into_iter()
+            .find_map(|item| terms.iter().find(|term| **term == item.as_str()).copied())
+    }
+}
+
+macro_rules! impl_for_collection {
+    ($(@$($G:lifetime)*;)? $T:ty) => {
+        impl<$($($G,)*)? T: AsStr> Excludes for $T {
+            fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str> {
+                find_item(self.iter(), terms, ignore_case)
+            }
+        }
+    };
+}
+
+impl_for_collection!(Vec<T>);
+impl_for_collection!(@'a; &'a [T]);
+impl_for_collection!(VecDeque<T>);
+impl_for_collection!(BTreeSet<T>);
+
+impl<T: AsStr, const N: usize> Excludes for [T; N] {
+    fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str> {
+        find_item(self.iter(), terms, ignore_case)
+    }
+}
+
+impl<T: AsStr, S> Excludes for HashSet<T, S> {
+    fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str> {
+        find_item(self.iter(), terms, ignore_case)
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T: AsStr, S> Excludes for indexmap::IndexSet<T, S> {
+    fn find_excluded<'t>(&self, terms: &[&'t str], ignore_case: bool) -> Option<&'t str> {
+        find_item(self.iter(), terms, ignore_case)
+    }
+}
@@ -0,0 +1,65 @@
+//! File extension validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(extension(["jpg", "png"]))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The value must end with a `.` followed by one of the extensions, compared ignoring ASCII case,
+//! so `photo.PNG` is accepted by the rule above. A leading dot in the extensions is ignored,
+//! so `[".jpg", ".png"]` is equivalent. Extensions may contain dots themselves, e.g. `"tar.gz"`.
+//!
+//! Unlike [`fs_path`][`super::fs_path`], this works on any string, such as a filename from an upload form.
+//!
+//! The entrypoint is the [`Extension`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(extension)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Extension>(v: &T, (extensions,): (&[&str],)) -> Result<(), Error> {
+    if !v.validate_extension(extensions) {
+        let extensions = extensions
+            .iter()
+            .map(|ext| trim_dot(ext))
+            .collect::<Vec<_>>();
+        return Err(
+            Error::new(format!("extension is not one of {extensions:?}")).with_code("extension"),
+        );
+    }
+    Ok(())
+}
+
+pub trait Extension {
+    fn validate_extension(&self, extensions: &[&str]) -> bool;
+}
+
+impl<T: AsStr> Extension for T {
+    fn validate_extension(&self, extensions: &[&str]) -> bool {
+        let value = self.as_str().as_bytes();
+        extensions.iter().any(|ext| {
+            let ext = trim_dot(ext).as_bytes();
+            // There must be at least one character before the dot.
+            value.len() > ext.len() + 1
+                && value[value.len() - ext.len() - 1] == b'.'
+                && value[value.len() - ext.len()..].eq_ignore_ascii_case(ext)
+        })
+    }
+}
+
+impl<T: Extension> Extension for Option<T> {
+    fn validate_extension(&self, extensions: &[&str]) -> bool {
+        match self {
+            Some(value) => value.validate_extension(extensions),
+            None => true,
+        }
+    }
+}
+
+fn trim_dot(ext: &str) -> &str {
+    ext.strip_prefix('.').unwrap_or(ext)
+}
@@ -0,0 +1,67 @@
+//! Finiteness validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(finite)]
+//!     v: f64,
+//! }
+//! ```
+//!
+//! Some JSON parsers accept `NaN` and `Infinity`, which are rarely meaningful as user input, e.g. for coordinates or amounts.
+//! This rule rejects them independently of `range`, so it can be used on its own or combined with it.
+//!
+//! The entrypoint is the [`Finite`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(finite)]` rule.
+//!
+//! This trait is implemented for `f32` and `f64`.
+
+use crate::error::Error;
+
+pub fn apply<T: Finite>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_finite() {
+        let message = match e {
+            NotFinite::NaN => "not a number",
+            NotFinite::Infinite => "not a finite number",
+        };
+        return Err(Error::new_static(message).with_code("finite"));
+    }
+    Ok(())
+}
+
+pub trait Finite {
+    fn validate_finite(&self) -> Result<(), NotFinite>;
+}
+
+pub enum NotFinite {
+    NaN,
+    Infinite,
+}
+
+macro_rules! impl_for_float {
+    ($($T:ty),*) => {
+        $(
+            impl Finite for $T {
+                fn validate_finite(&self) -> Result<(), NotFinite> {
+                    if self.is_nan() {
+                        Err(NotFinite::NaN)
+                    } else if self.is_infinite() {
+                        Err(NotFinite::Infinite)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
+
+impl<T: Finite> Finite for Option<T> {
+    fn validate_finite(&self) -> Result<(), NotFinite> {
+        match self {
+            Some(value) => value.validate_finite(),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Filesystem path validation.
+//!
+//! ```rust
+//! use std::path::PathBuf;
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(fs_path(absolute, extension = ["toml", "yaml"]))]
+//!     v: PathBuf,
+//! }
+//! ```
+//!
+//! The `absolute` and `relative` arguments check the path via [`std::path::Path::is_absolute`] and [`std::path::Path::is_relative`].
+//! The `extension` argument checks that the path has one of the listed extensions, compared case-sensitively and without the leading dot.
+//!
+//! Validation only inspects the path itself, it never touches the filesystem.
+//!
+//! The entrypoint is the [`FsPath`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(fs_path(...))]` rule.
+//!
+//! The [`FsPath`] has a companion trait [`AsFsPath`], which may be implemented for any type which can be viewed as a [`std::path::Path`].
+//! [`FsPath`] is implemented for any `T: AsFsPath`.
+
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+
+use crate::error::Error;
+
+pub fn apply<T: FsPath>(
+    v: &T,
+    (anchor, extensions): (Option<Anchor>, Option<&[&str]>),
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_fs_path(anchor, extensions) {
+        match e {
+            InvalidFsPath::NotAbsolute => {
+                return Err(Error::new_static("path is not absolute").with_code("fs_path.absolute"))
+            }
+            InvalidFsPath::NotRelative => {
+                return Err(Error::new_static("path is not relative").with_code("fs_path.relative"))
+            }
+            InvalidFsPath::Extension => {
+                return Err(Error::new(format!(
+                    "path extension is not one of {:?}",
+                    extensions.unwrap_or_default()
+                ))
+                .with_code("fs_path.extension"))
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether a path must be absolute or relative.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Anchor {
+    Absolute,
+    Relative,
+}
+
+pub trait FsPath {
+    fn validate_fs_path(
+        &self,
+        anchor: Option<Anchor>,
+        extensions: Option<&[&str]>,
+    ) -> Result<(), InvalidFsPath>;
+}
+
+pub enum InvalidFsPath {
+    NotAbsolute,
+    NotRelative,
+    Extension,
+}
+
+pub trait AsFsPath {
+    fn as_fs_path(&self) -> &Path;
+}
+
+impl<T: AsFsPath> FsPath for T {
+    fn validate_fs_path(
+        &self,
+        anchor: Option<Anchor>,
+        extensions: Option<&[&str]>,
+    ) -> Result<(), InvalidFsPath> {
+        let path = self.as_fs_path();
+        match anchor {
+            Some(Anchor::Absolute) if !path.is_absolute() => {
+                return Err(InvalidFsPath::NotAbsolute)
+            }
+            Some(Anchor::Relative) if !path.is_relative() => {
+                return Err(InvalidFsPath::NotRelative)
+            }
+            _ => {}
+        }
+        if let Some(extensions) = extensions {
+            let matches = match path.extension() {
+                Some(extension) => extensions.iter().any(|e| extension == *e),
+                None => false,
+            };
+            if !matches {
+                return Err(InvalidFsPath::Extension);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: FsPath> FsPath for Option<T> {
+    fn validate_fs_path(
+        &self,
+        anchor: Option<Anchor>,
+        extensions: Option<&[&str]>,
+    ) -> Result<(), InvalidFsPath> {
+        match self {
+            Some(value) => value.validate_fs_path(anchor, extensions),
+            None => Ok(()),
+        }
+    }
+}
+
+impl AsFsPath for Path {
+    fn as_fs_path(&self) -> &Path {
+        self
+    }
+}
+
+impl AsFsPath for PathBuf {
+    fn as_fs_path(&self) -> &Path {
+        self.as_path()
+    }
+}
+
+impl AsFsPath for Box<Path> {
+    fn as_fs_path(&self) -> &Path {
+        self
+    }
+}
+
+impl<'a> AsFsPath for Cow<'a, Path> {
+    fn as_fs_path(&self) -> &Path {
+        self
+    }
+}
+
+impl<T: AsFsPath + ?Sized> AsFsPath for &T {
+    fn as_fs_path(&self) -> &Path {
+        T::as_fs_path(self)
+    }
+}
@@ -0,0 +1,62 @@
+//! Hex validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(hex(decoded_len = 16))]
+//!     id: String,
+//!     #[garde(hex)]
+//!     data: String,
+//! }
+//! ```
+//!
+//! The value must be an even number of hex digits, in either lowercase or uppercase, without a `0x` prefix.
+//! If `decoded_len` is given, the value must also decode to exactly that many bytes, i.e. be twice as long.
+//!
+//! The entrypoint is the [`Hex`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(hex)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Hex>(v: &T, (decoded_len,): (Option<usize>,)) -> Result<(), Error> {
+    let actual = match v.decoded_len() {
+        Ok(Some(len)) => len,
+        Ok(None) => return Ok(()),
+        Err(InvalidHex) => return Err(Error::new_static("not valid hex").with_code("hex.invalid")),
+    };
+    match decoded_len {
+        Some(expected) if actual != expected => Err(Error::new(format!(
+            "decodes to {actual} bytes instead of {expected}"
+        ))
+        .with_code("hex.length")),
+        _ => Ok(()),
+    }
+}
+
+pub struct InvalidHex;
+
+pub trait Hex {
+    /// Returns the number of decoded bytes, or `None` if there is no value to validate.
+    fn decoded_len(&self) -> Result<Option<usize>, InvalidHex>;
+}
+
+impl<T: AsStr> Hex for T {
+    fn decoded_len(&self) -> Result<Option<usize>, InvalidHex> {
+        let value = self.as_str();
+        if !value.len().is_multiple_of(2) || !value.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return Err(InvalidHex);
+        }
+        Ok(Some(value.len() / 2))
+    }
+}
+
+impl<T: Hex> Hex for Option<T> {
+    fn decoded_len(&self) -> Result<Option<usize>, InvalidHex> {
+        match self {
+            Some(value) => value.decoded_len(),
+            None => Ok(None),
+        }
+    }
+}
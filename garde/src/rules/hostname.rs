@@ -0,0 +1,104 @@
+//! Hostname validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(hostname)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if it is a hostname according to [RFC 1123](https://datatracker.ietf.org/doc/html/rfc1123#page-13),
+//! such as `example.com` or `my-service.default.svc`:
+//! - it has at most 253 characters, split into labels by `.`,
+//! - each label has 1 to 63 characters, which are ASCII letters, digits, or `-`,
+//! - no label starts or ends with `-`.
+//!
+//! Letters may be in any case. A trailing `.`, as in a fully qualified domain name, is not accepted.
+//! The error names the requirement which was violated, with one of the codes `hostname.length`, `hostname.label`,
+//! `hostname.char`, or `hostname.hyphen`.
+//!
+//! The entrypoint is the [`Hostname`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(hostname)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Hostname>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_hostname() {
+        let error = match e {
+            InvalidHostname::Length => {
+                Error::new_static("hostname must have between 1 and 253 characters")
+                    .with_code("hostname.length")
+            }
+            InvalidHostname::EmptyLabel => {
+                Error::new_static("hostname contains an empty label").with_code("hostname.label")
+            }
+            InvalidHostname::LabelLength(label) => {
+                Error::new(format!("label \"{label}\" is longer than 63 characters"))
+                    .with_code("hostname.label")
+            }
+            InvalidHostname::Char(c) => Error::new(format!(
+                "invalid character {c:?} in hostname, expected a letter, digit, `-` or `.`"
+            ))
+            .with_code("hostname.char"),
+            InvalidHostname::Hyphen(label) => {
+                Error::new(format!("label \"{label}\" starts or ends with a hyphen"))
+                    .with_code("hostname.hyphen")
+            }
+        };
+        return Err(error);
+    }
+    Ok(())
+}
+
+pub trait Hostname {
+    fn validate_hostname(&self) -> Result<(), InvalidHostname<'_>>;
+}
+
+pub enum InvalidHostname<'a> {
+    /// Empty, or more than 253 characters.
+    Length,
+    EmptyLabel,
+    LabelLength(&'a str),
+    Char(char),
+    Hyphen(&'a str),
+}
+
+impl<T: AsStr> Hostname for T {
+    fn validate_hostname(&self) -> Result<(), InvalidHostname<'_>> {
+        let value = self.as_str();
+        if let Some(c) = value
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '.')
+        {
+            return Err(InvalidHostname::Char(c));
+        }
+        // Only ASCII is left, so bytes are characters.
+        if value.is_empty() || value.len() > 253 {
+            return Err(InvalidHostname::Length);
+        }
+        for label in value.split('.') {
+            if label.is_empty() {
+                return Err(InvalidHostname::EmptyLabel);
+            }
+            if label.len() > 63 {
+                return Err(InvalidHostname::LabelLength(label));
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                return Err(InvalidHostname::Hyphen(label));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: Hostname> Hostname for Option<T> {
+    fn validate_hostname(&self) -> Result<(), InvalidHostname<'_>> {
+        match self {
+            Some(value) => value.validate_hostname(),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! IMEI validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(imei)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! An IMEI has 15 digits, the last of which is the `mod10` (Luhn) check digit of the [`checksum`][`super::checksum`] rule.
+//! Hyphens or spaces between the digits are ignored, e.g. `49-015420-323751-8`.
+//!
+//! The entrypoint is the [`Imei`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(imei)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::checksum::{digits, mod10, InvalidChecksum, SEPARATORS};
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Imei>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_imei() {
+        let (message, code) = match e {
+            InvalidChecksum::Format => ("not a valid IMEI", "imei.format"),
+            InvalidChecksum::Length => ("IMEI must have 15 digits", "imei.length"),
+            InvalidChecksum::Checksum => ("invalid IMEI check digit", "imei.checksum"),
+        };
+        return Err(Error::new_static(message).with_code(code));
+    }
+    Ok(())
+}
+
+pub trait Imei {
+    fn validate_imei(&self) -> Result<(), InvalidChecksum>;
+}
+
+impl<T: AsStr> Imei for T {
+    fn validate_imei(&self) -> Result<(), InvalidChecksum> {
+        let digits = digits(self.as_str(), SEPARATORS, false)?;
+        if digits.clone().count() != 15 {
+            return Err(InvalidChecksum::Length);
+        }
+        match mod10(digits) {
+            true => Ok(()),
+            false => Err(InvalidChecksum::Checksum),
+        }
+    }
+}
+
+impl<T: Imei> Imei for Option<T> {
+    fn validate_imei(&self) -> Result<(), InvalidChecksum> {
+        match self {
+            Some(value) => value.validate_imei(),
+            None => Ok(()),
+        }
+    }
+}
@@ -9,6 +9,10 @@
 //! ```
 //!
 //! The entrypoint is the [`Inner`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(inner(..))]` rule.
+//!
+//! Items of a `HashSet`, `BTreeSet` or `IndexSet` are reported at the index at which they were visited.
+//! For a `HashSet`, the iteration order is arbitrary, so the index only tells items apart within a single report.
+//! An `IndexSet` or `IndexMap` is visited in insertion order.
 
 use crate::error::{NoKey, PathComponentKind};
 
@@ -50,7 +54,60 @@ impl<const N: usize, T> Inner<T> for [T; N] {
     }
 }
 
-impl<'a, T> Inner<T> for &'a [T] {
+impl<T> Inner<T> for &[T] {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        for (index, item) in self.iter().enumerate() {
+            f(item, &index);
+        }
+    }
+}
+
+impl<'a, T: Clone> Inner<T> for std::borrow::Cow<'a, [T]> {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        for (index, item) in self.iter().enumerate() {
+            f(item, &index);
+        }
+    }
+}
+
+impl<T, S> Inner<T> for std::collections::HashSet<T, S> {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        for (index, item) in self.iter().enumerate() {
+            f(item, &index);
+        }
+    }
+}
+
+impl<T> Inner<T> for std::collections::BTreeSet<T> {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&T, &Self::Key),
+    {
+        for (index, item) in self.iter().enumerate() {
+            f(item, &index);
+        }
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> Inner<T> for indexmap::IndexSet<T, S> {
     type Key = usize;
 
     fn validate_inner<F>(&self, mut f: F)
@@ -75,3 +132,43 @@ impl<T> Inner<T> for Option<T> {
         }
     }
 }
+
+impl<K: PathComponentKind, V, S> Inner<V> for std::collections::HashMap<K, V, S> {
+    type Key = K;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&V, &Self::Key),
+    {
+        for (key, value) in self.iter() {
+            f(value, key);
+        }
+    }
+}
+
+impl<K: PathComponentKind, V> Inner<V> for std::collections::BTreeMap<K, V> {
+    type Key = K;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&V, &Self::Key),
+    {
+        for (key, value) in self.iter() {
+            f(value, key);
+        }
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: PathComponentKind, V, S> Inner<V> for indexmap::IndexMap<K, V, S> {
+    type Key = K;
+
+    fn validate_inner<F>(&self, mut f: F)
+    where
+        F: FnMut(&V, &Self::Key),
+    {
+        for (key, value) in self.iter() {
+            f(value, key);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! ISBN validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(isbn)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Both ISBN-10 and ISBN-13 are accepted, and hyphens or spaces between the digits are ignored, e.g. `978-3-16-148410-0`.
+//! ISBN-10 uses the `mod11` check digit of the [`checksum`][`super::checksum`] rule, which may be `X`,
+//! and ISBN-13 the EAN-13 check digit, where every second digit is weighed by 3.
+//!
+//! The entrypoint is the [`Isbn`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(isbn)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::checksum::{digits, mod11, InvalidChecksum, SEPARATORS};
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Isbn>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_isbn() {
+        let (message, code) = match e {
+            InvalidChecksum::Format => ("not a valid ISBN", "isbn.format"),
+            InvalidChecksum::Length => ("ISBN must have 10 or 13 digits", "isbn.length"),
+            InvalidChecksum::Checksum => ("invalid ISBN check digit", "isbn.checksum"),
+        };
+        return Err(Error::new_static(message).with_code(code));
+    }
+    Ok(())
+}
+
+pub trait Isbn {
+    fn validate_isbn(&self) -> Result<(), InvalidChecksum>;
+}
+
+impl<T: AsStr> Isbn for T {
+    fn validate_isbn(&self) -> Result<(), InvalidChecksum> {
+        let value = self.as_str();
+        let len = value.bytes().filter(|c| !SEPARATORS.contains(c)).count();
+        let valid = match len {
+            10 => mod11(digits(value, SEPARATORS, true)?),
+            13 => ean13(digits(value, SEPARATORS, false)?),
+            _ => {
+                let _ = digits(value, SEPARATORS, true)?;
+                return Err(InvalidChecksum::Length);
+            }
+        };
+        match valid {
+            true => Ok(()),
+            false => Err(InvalidChecksum::Checksum),
+        }
+    }
+}
+
+impl<T: Isbn> Isbn for Option<T> {
+    fn validate_isbn(&self) -> Result<(), InvalidChecksum> {
+        match self {
+            Some(value) => value.validate_isbn(),
+            None => Ok(()),
+        }
+    }
+}
+
+fn ean13(digits: impl Iterator<Item = u8>) -> bool {
+    let sum: u32 = digits
+        .enumerate()
+        .map(|(i, d)| u32::from(d) * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    sum.is_multiple_of(10)
+}
@@ -0,0 +1,94 @@
+//! Map key validation.
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(keys(length(max = 32)), inner(length(min = 1)))]
+//!     v: HashMap<String, String>,
+//! }
+//! ```
+//!
+//! Rules inside `keys` run on each key of the map, while `inner` and `dive` run on the values.
+//!
+//! Errors for a value are reported at the path of its entry, e.g. `v.some_key`.
+//! Errors for the key itself are reported at the same path followed by a synthetic [`KEY`] component, e.g. `v.some_key.$key`.
+//!
+//! The entrypoint is the [`Keys`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(keys(..))]` rule.
+
+use std::collections::{BTreeMap, HashMap};
+
+use crate::error::PathComponentKind;
+
+/// The path component appended to the path of a map entry when reporting errors for its key.
+pub const KEY: &str = "$key";
+
+pub fn apply<T, K, F>(field: &T, f: F)
+where
+    T: Keys<Key = K>,
+    F: FnMut(&K),
+{
+    field.validate_keys(f)
+}
+
+pub trait Keys {
+    type Key: PathComponentKind;
+
+    fn validate_keys<F>(&self, f: F)
+    where
+        F: FnMut(&Self::Key);
+}
+
+impl<K: PathComponentKind, V, S> Keys for HashMap<K, V, S> {
+    type Key = K;
+
+    fn validate_keys<F>(&self, mut f: F)
+    where
+        F: FnMut(&Self::Key),
+    {
+        for key in self.keys() {
+            f(key);
+        }
+    }
+}
+
+impl<K: PathComponentKind, V> Keys for BTreeMap<K, V> {
+    type Key = K;
+
+    fn validate_keys<F>(&self, mut f: F)
+    where
+        F: FnMut(&Self::Key),
+    {
+        for key in self.keys() {
+            f(key);
+        }
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K: PathComponentKind, V, S> Keys for indexmap::IndexMap<K, V, S> {
+    type Key = K;
+
+    fn validate_keys<F>(&self, mut f: F)
+    where
+        F: FnMut(&Self::Key),
+    {
+        for key in self.keys() {
+            f(key);
+        }
+    }
+}
+
+impl<T: Keys> Keys for Option<T> {
+    type Key = T::Key;
+
+    fn validate_keys<F>(&self, f: F)
+    where
+        F: FnMut(&Self::Key),
+    {
+        if let Some(value) = self {
+            value.validate_keys(f)
+        }
+    }
+}
@@ -16,6 +16,9 @@
 //! In case of string types, [`HasLength::length`] should return the number of _characters_ as opposed to the number of _bytes_.
 //! For validation of length counted in _bytes_, see the [`crate::rules::byte_length`] rule.
 //!
+//! `OsStr` and `OsString` are also counted in characters. Those which are not valid UTF-8 are converted lossily first,
+//! which replaces each invalid sequence with a single `U+FFFD` character, so their length is only approximate.
+//!
 //! Here's what implementing the trait for a custom string-like type might look like:
 //! ```rust
 //! #[repr(transparent)]
@@ -34,8 +37,16 @@ use crate::error::Error;
 pub fn apply<T: Length>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
     if let Err(e) = v.validate_length(min, max) {
         match e {
-            InvalidLength::Min => return Err(Error::new(format!("length is lower than {min}"))),
-            InvalidLength::Max => return Err(Error::new(format!("length is greater than {max}"))),
+            InvalidLength::Min => {
+                return Err(
+                    Error::new(format!("length is lower than {min}")).with_code("length.min")
+                )
+            }
+            InvalidLength::Max => {
+                return Err(
+                    Error::new(format!("length is greater than {max}")).with_code("length.max")
+                )
+            }
         }
     }
     Ok(())
@@ -83,13 +94,13 @@ impl HasLength for String {
     }
 }
 
-impl<'a> HasLength for &'a String {
+impl HasLength for &String {
     fn length(&self) -> usize {
         self.chars().count()
     }
 }
 
-impl<'a> HasLength for &'a str {
+impl HasLength for &str {
     fn length(&self) -> usize {
         self.chars().count()
     }
@@ -101,13 +112,28 @@ impl<'a> HasLength for std::borrow::Cow<'a, str> {
     }
 }
 
+impl HasLength for std::ffi::OsString {
+    fn length(&self) -> usize {
+        self.as_os_str().length()
+    }
+}
+
+impl HasLength for &std::ffi::OsStr {
+    fn length(&self) -> usize {
+        match self.to_str() {
+            Some(s) => s.chars().count(),
+            None => self.to_string_lossy().chars().count(),
+        }
+    }
+}
+
 impl<T> HasLength for Vec<T> {
     fn length(&self) -> usize {
         self.len()
     }
 }
 
-impl<'a, T> HasLength for &'a Vec<T> {
+impl<T> HasLength for &Vec<T> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -119,6 +145,12 @@ impl<T> HasLength for &[T] {
     }
 }
 
+impl<'a, T: Clone> HasLength for std::borrow::Cow<'a, [T]> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
 impl<T, const N: usize> HasLength for [T; N] {
     fn length(&self) -> usize {
         N
@@ -131,7 +163,7 @@ impl<T, const N: usize> HasLength for &[T; N] {
     }
 }
 
-impl<'a, K, V, S> HasLength for &'a std::collections::HashMap<K, V, S> {
+impl<K, V, S> HasLength for &std::collections::HashMap<K, V, S> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -143,7 +175,7 @@ impl<K, V, S> HasLength for std::collections::HashMap<K, V, S> {
     }
 }
 
-impl<'a, T, S> HasLength for &'a std::collections::HashSet<T, S> {
+impl<T, S> HasLength for &std::collections::HashSet<T, S> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -155,7 +187,7 @@ impl<T, S> HasLength for std::collections::HashSet<T, S> {
     }
 }
 
-impl<'a, K, V> HasLength for &'a std::collections::BTreeMap<K, V> {
+impl<K, V> HasLength for &std::collections::BTreeMap<K, V> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -167,7 +199,7 @@ impl<K, V> HasLength for std::collections::BTreeMap<K, V> {
     }
 }
 
-impl<'a, T> HasLength for &'a std::collections::BTreeSet<T> {
+impl<T> HasLength for &std::collections::BTreeSet<T> {
     fn length(&self) -> usize {
         self.len()
     }
@@ -179,6 +211,34 @@ impl<T> HasLength for std::collections::BTreeSet<T> {
     }
 }
 
+#[cfg(feature = "indexmap")]
+impl<K, V, S> HasLength for &indexmap::IndexMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<K, V, S> HasLength for indexmap::IndexMap<K, V, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> HasLength for &indexmap::IndexSet<T, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "indexmap")]
+impl<T, S> HasLength for indexmap::IndexSet<T, S> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
 impl<T> HasLength for std::collections::VecDeque<T> {
     fn length(&self) -> usize {
         self.len()
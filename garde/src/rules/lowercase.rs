@@ -0,0 +1,49 @@
+//! Lowercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(lowercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if it is equal to its lowercase form, i.e. `s == s.to_lowercase()`.
+//! Characters without case, such as digits and punctuation, are always accepted.
+//!
+//! The entrypoint is the [`Lowercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(lowercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Lowercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_lowercase() {
+        return Err(Error::new_static("not lowercase").with_code("lowercase"));
+    }
+    Ok(())
+}
+
+pub trait Lowercase {
+    fn validate_lowercase(&self) -> bool;
+}
+
+impl<T: AsStr> Lowercase for T {
+    fn validate_lowercase(&self) -> bool {
+        let s = self.as_str();
+        if s.is_ascii() {
+            return !s.bytes().any(|b| b.is_ascii_uppercase());
+        }
+        s.chars().flat_map(char::to_lowercase).eq(s.chars())
+    }
+}
+
+impl<T: Lowercase> Lowercase for Option<T> {
+    fn validate_lowercase(&self) -> bool {
+        match self {
+            Some(value) => value.validate_lowercase(),
+            None => true,
+        }
+    }
+}
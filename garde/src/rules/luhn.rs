@@ -0,0 +1,62 @@
+//! Luhn check digit validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(luhn)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The value must consist of at least two ASCII digits, the last of which is checked with the
+//! [Luhn algorithm](https://en.wikipedia.org/wiki/Luhn_algorithm). Its length is otherwise not restricted,
+//! and unlike `credit_card`, it doesn't need to belong to a card network.
+//!
+//! This is the same check as `checksum(algo = "mod10")`, with its own error code `luhn.checksum`.
+//! A value with anything other than digits fails with `luhn.format`, and one with fewer than two digits with `luhn.length`.
+//!
+//! The entrypoint is the [`Luhn`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(luhn)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::checksum::{digits, mod10, InvalidChecksum};
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Luhn>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_luhn() {
+        let (message, code) = match e {
+            InvalidChecksum::Format => ("not a string of digits", "luhn.format"),
+            InvalidChecksum::Length => ("too short to contain a check digit", "luhn.length"),
+            InvalidChecksum::Checksum => ("invalid check digit", "luhn.checksum"),
+        };
+        return Err(Error::new_static(message).with_code(code));
+    }
+    Ok(())
+}
+
+pub trait Luhn {
+    fn validate_luhn(&self) -> Result<(), InvalidChecksum>;
+}
+
+impl<T: AsStr> Luhn for T {
+    fn validate_luhn(&self) -> Result<(), InvalidChecksum> {
+        let digits = digits(self.as_str(), &[], false)?;
+        if digits.clone().count() < 2 {
+            return Err(InvalidChecksum::Length);
+        }
+        match mod10(digits) {
+            true => Ok(()),
+            false => Err(InvalidChecksum::Checksum),
+        }
+    }
+}
+
+impl<T: Luhn> Luhn for Option<T> {
+    fn validate_luhn(&self) -> Result<(), InvalidChecksum> {
+        match self {
+            Some(value) => value.validate_luhn(),
+            None => Ok(()),
+        }
+    }
+}
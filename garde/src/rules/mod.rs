@@ -2,30 +2,66 @@
 
 pub mod alphanumeric;
 pub mod ascii;
+pub mod at_least_one;
+#[cfg(feature = "base64")]
+pub mod base64;
 pub mod byte_length;
+pub mod checksum;
 pub mod contains;
+pub mod contains_key;
+pub mod contains_value;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
+pub mod custom;
+pub mod date;
+pub mod dependent_required;
+pub mod e164;
 #[cfg(feature = "email")]
 pub mod email;
+pub mod enum_value;
+pub mod excludes;
+pub mod extension;
+pub mod finite;
+pub mod fs_path;
+pub mod hex;
+pub mod hostname;
+pub mod imei;
 pub mod inner;
 pub mod ip;
+pub mod isbn;
+pub mod keys;
 pub mod length;
+pub mod lowercase;
+pub mod luhn;
+pub mod mutually_exclusive;
+pub mod no_control_chars;
+pub mod not;
+pub mod not_blank;
+pub mod numeric;
+pub mod one_of;
+pub mod optional;
 pub mod pattern;
 #[cfg(feature = "phone-number")]
 pub mod phone_number;
 pub mod prefix;
 pub mod range;
 pub mod required;
+#[cfg(feature = "semver")]
+pub mod semver;
+pub mod starts_with_uppercase;
 pub mod suffix;
+pub mod titlecase;
+pub mod uppercase;
 #[cfg(feature = "url")]
 pub mod url;
+pub mod variant;
+pub mod words;
 
 pub trait AsStr {
     fn as_str(&self) -> &str;
 }
 
-impl<'a> AsStr for &'a str {
+impl AsStr for &str {
     fn as_str(&self) -> &str {
         self
     }
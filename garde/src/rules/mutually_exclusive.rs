@@ -0,0 +1,44 @@
+//! Mutual exclusion validation for groups of fields.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! #[garde(mutually_exclusive(percentage_discount, fixed_discount))]
+//! struct Coupon {
+//!     #[garde(skip)]
+//!     percentage_discount: Option<u8>,
+//!     #[garde(skip)]
+//!     fixed_discount: Option<u32>,
+//! }
+//! ```
+//!
+//! This is a container-level rule: it is placed on the struct, and names the fields it checks.
+//! It fails if more than one of the fields are present, and the error lists the fields which are.
+//! The error is reported at the path of the struct, or at `path` if given.
+//!
+//! Whether a field is present is decided by the [`Present`] trait, in the same way as for the
+//! [`at_least_one`](super::at_least_one) rule.
+
+pub use super::at_least_one::Present;
+use crate::error::Error;
+
+/// Checks a list of `(field name, is present)` pairs.
+pub fn apply(fields: &[(&str, bool)]) -> Result<(), Error> {
+    let present = fields
+        .iter()
+        .filter(|(_, present)| *present)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+    if present.len() <= 1 {
+        return Ok(());
+    }
+    let names = fields
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(", ");
+    Err(Error::new(format!(
+        "only one of {names} may be set, found {}",
+        present.join(", ")
+    ))
+    .with_code("mutually_exclusive"))
+}
@@ -0,0 +1,67 @@
+//! Control character validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(no_control_chars)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! This rejects every character for which [`char::is_control`] is `true`, such as null bytes, `'\n'`, or `'\t'`.
+//! Some of them may be allowed via `allow`, which is a list of strings containing the allowed characters:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(no_control_chars(allow = ["\n", "\t"]))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The error reports the first control character, and its position in characters.
+//!
+//! The entrypoint is the [`NoControlChars`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(no_control_chars)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: NoControlChars>(v: &T, _: ()) -> Result<(), Error> {
+    apply_allow(v, (&[],))
+}
+
+pub fn apply_allow<T: NoControlChars>(v: &T, (allow,): (&[&str],)) -> Result<(), Error> {
+    if let Some((position, c)) = v.find_control_char(allow) {
+        return Err(Error::new(format!(
+            "contains the control character {c:?} at position {position}"
+        ))
+        .with_code("no_control_chars"));
+    }
+    Ok(())
+}
+
+pub trait NoControlChars {
+    /// Returns the first control character which is not in any of the `allow` strings,
+    /// and its position in characters.
+    fn find_control_char(&self, allow: &[&str]) -> Option<(usize, char)>;
+}
+
+impl<T: AsStr> NoControlChars for T {
+    fn find_control_char(&self, allow: &[&str]) -> Option<(usize, char)> {
+        self.as_str()
+            .chars()
+            .enumerate()
+            .find(|(_, c)| c.is_control() && !allow.iter().any(|allowed| allowed.contains(*c)))
+    }
+}
+
+impl<T: NoControlChars> NoControlChars for Option<T> {
+    fn find_control_char(&self, allow: &[&str]) -> Option<(usize, char)> {
+        match self {
+            Some(value) => value.find_control_char(allow),
+            None => None,
+        }
+    }
+}
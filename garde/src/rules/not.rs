@@ -0,0 +1,55 @@
+//! Negation of a rule.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not(prefix("admin")), not(contains(any = [" ", "@"])))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if the wrapped rule fails on it. A missing value (`None`) always passes,
+//! just like it does for the wrapped rule.
+//!
+//! Only rules which describe a property of a string may be negated, such as `ascii`, `email`, `contains`, or `pattern`.
+//! Rules like `length`, `range`, `required`, or `dive` are rejected at compile time.
+//!
+//! The error has the code `not.<rule>`, for example `not.pattern`.
+//!
+//! The entrypoint is the [`Negate`] trait, which has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Negate, F: FnOnce() -> Error>(
+    v: &T,
+    result: Result<(), Error>,
+    error: F,
+    code: &str,
+) -> Result<(), Error> {
+    if v.is_present() && result.is_ok() {
+        return Err(error().with_code(code));
+    }
+    Ok(())
+}
+
+pub trait Negate {
+    /// Returns `false` if there is no value for the wrapped rule to check,
+    /// in which case its success must not be turned into a failure.
+    fn is_present(&self) -> bool;
+}
+
+impl<T: AsStr> Negate for T {
+    fn is_present(&self) -> bool {
+        true
+    }
+}
+
+impl<T: Negate> Negate for Option<T> {
+    fn is_present(&self) -> bool {
+        match self {
+            Some(value) => value.is_present(),
+            None => false,
+        }
+    }
+}
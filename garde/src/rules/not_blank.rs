@@ -0,0 +1,64 @@
+//! Blank string validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not_blank)]
+//!     v: String,
+//!     #[garde(not_blank)]
+//!     w: std::ffi::OsString,
+//! }
+//! ```
+//!
+//! A value fails if it is empty or only contains whitespace, as defined by [`char::is_whitespace`].
+//!
+//! The entrypoint is the [`NotBlank`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(not_blank)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`, and is implemented for `OsStr` and `OsString`.
+//! OS strings which are not valid UTF-8 are never blank, because the invalid parts are not whitespace.
+
+use std::ffi::{OsStr, OsString};
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: NotBlank>(v: &T, _: ()) -> Result<(), Error> {
+    if v.is_blank() {
+        return Err(Error::new_static("must not be blank").with_code("not_blank"));
+    }
+    Ok(())
+}
+
+pub trait NotBlank {
+    fn is_blank(&self) -> bool;
+}
+
+impl<T: AsStr> NotBlank for T {
+    fn is_blank(&self) -> bool {
+        self.as_str().trim().is_empty()
+    }
+}
+
+impl NotBlank for &OsStr {
+    fn is_blank(&self) -> bool {
+        match self.to_str() {
+            Some(s) => s.trim().is_empty(),
+            None => false,
+        }
+    }
+}
+
+impl NotBlank for OsString {
+    fn is_blank(&self) -> bool {
+        self.as_os_str().is_blank()
+    }
+}
+
+impl<T: NotBlank> NotBlank for Option<T> {
+    fn is_blank(&self) -> bool {
+        match self {
+            Some(value) => value.is_blank(),
+            None => false,
+        }
+    }
+}
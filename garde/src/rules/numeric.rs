@@ -0,0 +1,121 @@
+//! Numeric string validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(numeric(int, min = 1, max = 100))]
+//!     quantity: String,
+//!     #[garde(numeric(float, min = 0, max = 1.5))]
+//!     ratio: String,
+//! }
+//! ```
+//!
+//! The value is parsed as either an integer (`int`) or a floating point number (`float`), and then checked against the optional `min` and `max` bounds.
+//! If no mode is given, the value is parsed as a `float`, which also accepts integers.
+//! Bounds are converted to `i128` in `int` mode, and to `f64` in `float` mode.
+//!
+//! Parsing uses [`str::parse`], so surrounding whitespace is not accepted.
+//! In `float` mode, `NaN` and infinities are rejected, because they are not meaningful user input.
+//!
+//! The entrypoint is the [`Numeric`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(numeric)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply_int<T: Numeric>(v: &T, (min, max): (Option<i128>, Option<i128>)) -> Result<(), Error> {
+    apply(v, "not an integer", min, max)
+}
+
+pub fn apply_float<T: Numeric>(v: &T, (min, max): (Option<f64>, Option<f64>)) -> Result<(), Error> {
+    apply(v, "not a number", min, max)
+}
+
+fn apply<T: Numeric, N: Number>(
+    v: &T,
+    invalid: &'static str,
+    min: Option<N>,
+    max: Option<N>,
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_numeric(min, max) {
+        let error = match e {
+            InvalidNumeric::NotANumber => Error::new_static(invalid).with_code("numeric.invalid"),
+            InvalidNumeric::Min => {
+                Error::new(format!("lower than {}", min.unwrap())).with_code("numeric.min")
+            }
+            InvalidNumeric::Max => {
+                Error::new(format!("greater than {}", max.unwrap())).with_code("numeric.max")
+            }
+        };
+        return Err(error);
+    }
+    Ok(())
+}
+
+pub enum InvalidNumeric {
+    NotANumber,
+    Min,
+    Max,
+}
+
+/// A number which a string may be parsed into.
+pub trait Number: FromStr + PartialOrd + Display + Copy {
+    fn is_finite(&self) -> bool;
+}
+
+impl Number for i128 {
+    fn is_finite(&self) -> bool {
+        true
+    }
+}
+
+impl Number for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+pub trait Numeric {
+    fn validate_numeric<N: Number>(
+        &self,
+        min: Option<N>,
+        max: Option<N>,
+    ) -> Result<(), InvalidNumeric>;
+}
+
+impl<T: AsStr> Numeric for T {
+    fn validate_numeric<N: Number>(
+        &self,
+        min: Option<N>,
+        max: Option<N>,
+    ) -> Result<(), InvalidNumeric> {
+        let value = match self.as_str().parse::<N>() {
+            Ok(value) if value.is_finite() => value,
+            _ => return Err(InvalidNumeric::NotANumber),
+        };
+        if min.is_some_and(|min| value < min) {
+            return Err(InvalidNumeric::Min);
+        }
+        if max.is_some_and(|max| value > max) {
+            return Err(InvalidNumeric::Max);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Numeric> Numeric for Option<T> {
+    fn validate_numeric<N: Number>(
+        &self,
+        min: Option<N>,
+        max: Option<N>,
+    ) -> Result<(), InvalidNumeric> {
+        match self {
+            Some(value) => value.validate_numeric(min, max),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Membership validation.
+//!
+//! ```rust
+//! const ROLES: &[&str] = &["admin", "user"];
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(one_of(["red", "green", "blue"]))]
+//!     v: String,
+//!     #[garde(one_of(ROLES))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! The value must be equal to one of the given strings. Wrapped in `not(..)`, the value must be none of them,
+//! e.g. `not(one_of(["root", "admin"]))` for a username.
+//!
+//! The entrypoint is the [`OneOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(one_of)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: OneOf>(v: &T, (values,): (&[&str],)) -> Result<(), Error> {
+    if !v.validate_one_of(values) {
+        return Err(Error::new(format!("not one of {values:?}")).with_code("one_of"));
+    }
+    Ok(())
+}
+
+pub trait OneOf {
+    fn validate_one_of(&self, values: &[&str]) -> bool;
+}
+
+impl<T: AsStr> OneOf for T {
+    fn validate_one_of(&self, values: &[&str]) -> bool {
+        values.contains(&self.as_str())
+    }
+}
+
+impl<T: OneOf> OneOf for Option<T> {
+    fn validate_one_of(&self, values: &[&str]) -> bool {
+        match self {
+            Some(value) => value.validate_one_of(values),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Validation of the value of an `Option`.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(optional(length(min = 1), dive))]
+//!     v: Option<Vec<Item>>,
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Item {
+//!     #[garde(length(min = 1))]
+//!     name: String,
+//! }
+//! ```
+//!
+//! The rules inside of `optional(..)` only run if the value is `Some`, and apply to the value itself,
+//! so `inner(..)` and `dive` refer to the items of the `Vec` above. Errors are reported at the path of the field.
+//!
+//! Most rules already accept an `Option` and pass on `None`, so `optional` is mostly useful for
+//! rules which otherwise mean something else on an `Option`, such as `inner`, or for readability.
+//!
+//! The entrypoint is the [`Optional`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(optional(..))]` rule.
+//!
+//! This trait is implemented for `Option<T>`.
+
+pub fn apply<T, U, F>(field: &T, f: F)
+where
+    T: Optional<U>,
+    F: FnOnce(&U),
+{
+    field.validate_optional(f)
+}
+
+pub trait Optional<T> {
+    fn validate_optional<F>(&self, f: F)
+    where
+        F: FnOnce(&T);
+}
+
+impl<T> Optional<T> for Option<T> {
+    fn validate_optional<F>(&self, f: F)
+    where
+        F: FnOnce(&T),
+    {
+        if let Some(value) = self {
+            f(value)
+        }
+    }
+}
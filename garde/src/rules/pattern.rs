@@ -1,6 +1,12 @@
 //! Pattern validation.
 //!
 //! The pattern argument can be a regular expression provided as a string literal, which is then parsed by the [`regex`] crate (if the `regex` feature is enabled).
+//! Instead of `regex`, the `regex-lite` feature uses the smaller [`regex-lite`](https://docs.rs/regex-lite) crate,
+//! and the `fancy-regex` feature uses [`fancy-regex`](https://docs.rs/fancy-regex), which supports look-around and backreferences.
+//! If more than one of them is enabled, `fancy-regex` takes precedence over `regex`, which takes precedence over `regex-lite`.
+//! Enabling several engines is not an error, because the `email` feature always enables `regex`, and so does `--all-features`.
+//! To use `regex-lite`, disable the default features and don't enable `email`.
+//! Literal patterns are checked at compile time by the same engine they are matched with.
 //!
 //! ```rust
 //! #[derive(garde::Validate)]
@@ -11,7 +17,8 @@
 //! ```
 //!
 //! Alternatively, it can be an expression of type implementing [`Matcher`] or one that dereferences to a [`Matcher`].
-//! [`Matcher`] is implemented for `regex::Regex` (if the `regex` feature is enabled) and `once_cell::sync::Lazy<T>` with any `T: Matcher`.
+//! [`Matcher`] is implemented for `regex::Regex`, `regex_lite::Regex`, and `fancy_regex::Regex` (if the corresponding feature is enabled),
+//! and `once_cell::sync::Lazy<T>` with any `T: Matcher`.
 //! Please note that the expression will be evaluated each time `validate` is called, so avoid doing any expensive work in the expression.
 //! If the work is unavoidable, at least try to amortize it, such as by using `once_cell::Lazy` or the nightly-only `std::sync::LazyLock`.
 //!
@@ -28,6 +35,18 @@
 //! }
 //! ```
 //!
+//! Named groups can be required to capture a non-empty value with `require`, e.g. to check the parts of a structured string.
+//! The value then has to match the pattern, and each listed group has to be part of that match.
+//! Groups of a literal pattern are checked at compile time. Expressions must implement [`Captures`].
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(pattern(r"^(?P<year>\d{4})?-(?P<month>\d{2})$", require = ["year"]))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Pattern`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(pattern(...))]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -37,14 +56,31 @@ use crate::error::Error;
 
 pub fn apply<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error> {
     if !v.validate_pattern(pat) {
-        return Err(Error::new(format!(
-            "does not match pattern /{}/",
-            pat.as_str()
-        )));
+        return Err(
+            Error::new(format!("does not match pattern /{}/", pat.as_str())).with_code("pattern"),
+        );
     }
     Ok(())
 }
 
+/// Like [`apply`], but also fails if one of the named `groups` did not capture a non-empty value.
+pub fn apply_groups<T: PatternGroups, M: Captures>(
+    v: &T,
+    (pat, groups): (&M, &[&str]),
+) -> Result<(), Error> {
+    match v.validate_pattern_groups(pat, groups) {
+        Ok(()) => Ok(()),
+        Err(None) => Err(
+            Error::new(format!("does not match pattern /{}/", pat.as_str())).with_code("pattern"),
+        ),
+        Err(Some(group)) => Err(Error::new(format!(
+            "group `{group}` of pattern /{}/ did not capture",
+            pat.as_str()
+        ))
+        .with_code("pattern.group")),
+    }
+}
+
 pub trait Matcher: AsStr {
     /// Returns true if and only if there is a match for the pattern anywhere in the haystack given.
     fn is_match(&self, haystack: &str) -> bool;
@@ -69,46 +105,290 @@ impl<T: Pattern> Pattern for Option<T> {
     }
 }
 
+/// A [`Matcher`] which can tell which of its named groups captured a value.
+pub trait Captures: Matcher {
+    /// Checks the leftmost match in the haystack.
+    ///
+    /// Returns `Err(None)` if there is no match, and `Err(Some(group))` for the first of `groups`
+    /// which did not capture a non-empty value.
+    fn captures_groups<'g>(
+        &self,
+        haystack: &str,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>>;
+}
+
+pub trait PatternGroups {
+    fn validate_pattern_groups<'g, M: Captures>(
+        &self,
+        matcher: &M,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>>;
+}
+
+impl<T: AsStr> PatternGroups for T {
+    fn validate_pattern_groups<'g, M: Captures>(
+        &self,
+        matcher: &M,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>> {
+        matcher.captures_groups(self.as_str(), groups)
+    }
+}
+
+impl<T: PatternGroups> PatternGroups for Option<T> {
+    fn validate_pattern_groups<'g, M: Captures>(
+        &self,
+        matcher: &M,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>> {
+        match self {
+            Some(value) => value.validate_pattern_groups(matcher, groups),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns the first of `groups` for which `captured` is false.
+#[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+fn missing<'g>(
+    groups: &[&'g str],
+    mut captured: impl FnMut(&str) -> bool,
+) -> Result<(), Option<&'g str>> {
+    match groups.iter().find(|group| !captured(group)) {
+        Some(group) => Err(Some(*group)),
+        None => Ok(()),
+    }
+}
+
+/// A regular expression engine, which literal patterns such as `pattern("[a-z]+")` are compiled with.
+///
+/// The engine is selected via features. If more than one is enabled, `fancy-regex` takes precedence
+/// over `regex`, which takes precedence over `regex-lite`.
+#[doc(hidden)]
+pub trait PatternEngine {
+    type Regex: Matcher;
+
+    /// Compiles a pattern which was already checked by the derive macro.
+    fn compile(pattern: &str) -> Self::Regex;
+}
+
+#[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+impl<T: Matcher> Matcher for once_cell::sync::Lazy<T> {
+    fn is_match(&self, haystack: &str) -> bool {
+        once_cell::sync::Lazy::force(self).is_match(haystack)
+    }
+}
+
+#[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+impl<T: Captures> Captures for once_cell::sync::Lazy<T> {
+    fn captures_groups<'g>(
+        &self,
+        haystack: &str,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>> {
+        once_cell::sync::Lazy::force(self).captures_groups(haystack, groups)
+    }
+}
+
+#[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+impl<T: AsStr> AsStr for once_cell::sync::Lazy<T> {
+    fn as_str(&self) -> &str {
+        once_cell::sync::Lazy::force(self).as_str()
+    }
+}
+
+/// The [`regex`](https://docs.rs/regex) engine.
 #[cfg(feature = "regex")]
 #[doc(hidden)]
-pub mod regex {
-    pub use ::regex::Regex;
+pub struct RegexEngine;
 
-    use super::*;
+#[cfg(feature = "regex")]
+impl PatternEngine for RegexEngine {
+    type Regex = ::regex::Regex;
 
-    impl Matcher for Regex {
-        fn is_match(&self, haystack: &str) -> bool {
-            self.is_match(haystack)
-        }
+    fn compile(pattern: &str) -> Self::Regex {
+        ::regex::Regex::new(pattern).unwrap()
     }
+}
 
-    impl<T: Matcher> Matcher for once_cell::sync::Lazy<T> {
-        fn is_match(&self, haystack: &str) -> bool {
-            once_cell::sync::Lazy::force(self).is_match(haystack)
-        }
+#[cfg(feature = "regex")]
+impl Matcher for ::regex::Regex {
+    fn is_match(&self, haystack: &str) -> bool {
+        self.is_match(haystack)
     }
+}
 
-    impl AsStr for Regex {
-        fn as_str(&self) -> &str {
-            self.as_str()
-        }
+#[cfg(feature = "regex")]
+impl Captures for ::regex::Regex {
+    fn captures_groups<'g>(
+        &self,
+        haystack: &str,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>> {
+        let captures = self.captures(haystack).ok_or(None)?;
+        missing(groups, |group| {
+            captures.name(group).is_some_and(|m| !m.as_str().is_empty())
+        })
     }
+}
 
-    impl<T: AsStr> AsStr for once_cell::sync::Lazy<T> {
-        fn as_str(&self) -> &str {
-            once_cell::sync::Lazy::force(self).as_str()
-        }
+#[cfg(feature = "regex")]
+impl AsStr for ::regex::Regex {
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// The [`regex-lite`](https://docs.rs/regex-lite) engine, which is smaller, but slower and without Unicode support.
+#[cfg(feature = "regex-lite")]
+#[doc(hidden)]
+pub struct RegexLiteEngine;
+
+#[cfg(feature = "regex-lite")]
+impl PatternEngine for RegexLiteEngine {
+    type Regex = ::regex_lite::Regex;
+
+    fn compile(pattern: &str) -> Self::Regex {
+        ::regex_lite::Regex::new(pattern).unwrap()
+    }
+}
+
+#[cfg(feature = "regex-lite")]
+impl Matcher for ::regex_lite::Regex {
+    fn is_match(&self, haystack: &str) -> bool {
+        self.is_match(haystack)
+    }
+}
+
+#[cfg(feature = "regex-lite")]
+impl Captures for ::regex_lite::Regex {
+    fn captures_groups<'g>(
+        &self,
+        haystack: &str,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>> {
+        let captures = self.captures(haystack).ok_or(None)?;
+        missing(groups, |group| {
+            captures.name(group).is_some_and(|m| !m.as_str().is_empty())
+        })
     }
+}
 
-    pub type StaticPattern = once_cell::sync::Lazy<Regex>;
+#[cfg(feature = "regex-lite")]
+impl AsStr for ::regex_lite::Regex {
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// The [`fancy-regex`](https://docs.rs/fancy-regex) engine, which supports look-around and backreferences.
+#[cfg(feature = "fancy-regex")]
+#[doc(hidden)]
+pub struct FancyRegexEngine;
+
+#[cfg(feature = "fancy-regex")]
+impl PatternEngine for FancyRegexEngine {
+    type Regex = ::fancy_regex::Regex;
+
+    fn compile(pattern: &str) -> Self::Regex {
+        ::fancy_regex::Regex::new(pattern).unwrap()
+    }
+}
+
+#[cfg(feature = "fancy-regex")]
+impl Matcher for ::fancy_regex::Regex {
+    /// A value which exceeds the backtracking limit does not match.
+    fn is_match(&self, haystack: &str) -> bool {
+        self.is_match(haystack).unwrap_or(false)
+    }
+}
+
+#[cfg(feature = "fancy-regex")]
+impl Captures for ::fancy_regex::Regex {
+    fn captures_groups<'g>(
+        &self,
+        haystack: &str,
+        groups: &[&'g str],
+    ) -> Result<(), Option<&'g str>> {
+        let captures = self.captures(haystack).ok().flatten().ok_or(None)?;
+        missing(groups, |group| {
+            captures.name(group).is_some_and(|m| !m.as_str().is_empty())
+        })
+    }
+}
+
+#[cfg(feature = "fancy-regex")]
+impl AsStr for ::fancy_regex::Regex {
+    fn as_str(&self) -> &str {
+        self.as_str()
+    }
+}
+
+#[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+#[doc(hidden)]
+pub mod regex {
+    #[cfg(feature = "regex")]
+    pub use ::regex::Regex;
+
+    use super::*;
+
+    /// The engine selected by the enabled features.
+    #[cfg(feature = "fancy-regex")]
+    pub type Engine = FancyRegexEngine;
+    #[cfg(all(feature = "regex", not(feature = "fancy-regex")))]
+    pub type Engine = RegexEngine;
+    #[cfg(all(
+        feature = "regex-lite",
+        not(any(feature = "regex", feature = "fancy-regex"))
+    ))]
+    pub type Engine = RegexLiteEngine;
+
+    pub type StaticPattern = once_cell::sync::Lazy<<Engine as PatternEngine>::Regex>;
 
     #[macro_export]
     macro_rules! __init_pattern {
         ($pat:literal) => {
             $crate::rules::pattern::regex::StaticPattern::new(|| {
-                $crate::rules::pattern::regex::Regex::new($pat).unwrap()
+                <$crate::rules::pattern::regex::Engine as $crate::rules::pattern::PatternEngine>::compile($pat)
             })
         };
     }
     pub use crate::__init_pattern as init_pattern;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn regex_engine() {
+        let re = RegexEngine::compile(r"^[a-z]+$");
+        assert!(Matcher::is_match(&re, "abc"));
+        assert!(!Matcher::is_match(&re, "ABC"));
+        assert_eq!(AsStr::as_str(&re), r"^[a-z]+$");
+    }
+
+    #[cfg(feature = "regex-lite")]
+    #[test]
+    fn regex_lite_engine() {
+        let re = RegexLiteEngine::compile(r"^[a-z]+$");
+        assert!(Matcher::is_match(&re, "abc"));
+        assert!(!Matcher::is_match(&re, "ABC"));
+        assert_eq!(AsStr::as_str(&re), r"^[a-z]+$");
+    }
+
+    #[cfg(feature = "fancy-regex")]
+    #[test]
+    fn fancy_regex_engine() {
+        let re = FancyRegexEngine::compile(r"^(?!admin$)[a-z]+$");
+        assert!(Matcher::is_match(&re, "alice"));
+        assert!(!Matcher::is_match(&re, "admin"));
+        assert_eq!(AsStr::as_str(&re), r"^(?!admin$)[a-z]+$");
+
+        let re = FancyRegexEngine::compile(r"^(\w)\1$");
+        assert!(Matcher::is_match(&re, "aa"));
+        assert!(!Matcher::is_match(&re, "ab"));
+    }
+}
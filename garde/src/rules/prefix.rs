@@ -12,6 +12,19 @@
 //! }
 //! ```
 //!
+//! Multiple needles may be given with `any` (the value must begin with at least one of them)
+//! or `all` (the value must begin with every one of them):
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(prefix(any = ["foo", "bar"]))]
+//!     v: String,
+//!     #[garde(prefix(all = ["foo", "bar"]))]
+//!     w: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Prefix`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(prefix)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -21,7 +34,21 @@ use crate::error::Error;
 
 pub fn apply<T: Prefix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_prefix(pat) {
-        return Err(Error::new(format!("value does not begin with \"{pat}\"")));
+        return Err(Error::new(format!("value does not begin with \"{pat}\"")).with_code("prefix"));
+    }
+    Ok(())
+}
+
+pub fn apply_any<T: Prefix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_prefix(pat)) {
+        return Err(Error::new(format!("does not begin with any of {pats:?}")).with_code("prefix"));
+    }
+    Ok(())
+}
+
+pub fn apply_all<T: Prefix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().all(|pat| v.validate_prefix(pat)) {
+        return Err(Error::new(format!("does not begin with all of {pats:?}")).with_code("prefix"));
     }
     Ok(())
 }
@@ -9,29 +9,133 @@
 //! ```
 //!
 //! The entrypoint is the [`Bounds`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(range(...))]` rule.
+//! The rule itself accepts any [`RangeBounds`], which is implemented for every `T: Bounds`, and for [`std::time::Duration`].
 //!
-//! This trait is implemented for all primitive integer types.
+//! Either `min` or `max` may be omitted, in which case the value is only checked against the other bound.
+//!
+//! This trait is implemented for all primitive integer and floating point types.
+//! Floating point values which are `NaN` always fail validation, because they can't be compared to any bound.
+//! On integer fields, literal bounds must fit into the field's type, and bounds which are constants or fields
+//! of another integer type are compared by value via [`apply_integer`], so they never overflow or wrap around.
+//! If the `chrono` or `time` features are enabled, it is also implemented for `chrono::NaiveDate` and `time::Date`.
+//!
+//! By default, the error message only mentions the bound which was violated, e.g. `lower than 10`.
+//! If both bounds are given, `combined_message` reports them together instead, e.g. `not between 10 and 100`.
+//! A value still produces at most one error, because it can't be out of both bounds at once:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min = 10, max = 100, combined_message))]
+//!     v: u64,
+//! }
+//! ```
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min = Duration::from_secs(1), max = Duration::from_secs(60)))]
+//!     v: Duration,
+//! }
+//! ```
 
-use std::fmt::Display;
+use std::fmt::{Debug, Display};
+use std::time::Duration;
 
 use crate::error::Error;
 
 #[inline]
-pub fn apply<T: Bounds>(
+pub fn apply<T: RangeBounds>(
     v: &T,
     (min, max): (Option<T::Size>, Option<T::Size>),
 ) -> Result<(), Error> {
     let min = min.unwrap_or(T::MIN);
     let max = max.unwrap_or(T::MAX);
-    if let Err(e) = v.validate_bounds(min, max) {
+    if let Err(e) = v.validate_range(min, max) {
         match e {
-            OutOfBounds::Lower => return Err(Error::new(format!("lower than {min}"))),
-            OutOfBounds::Upper => return Err(Error::new(format!("greater than {max}"))),
+            OutOfBounds::Lower => {
+                return Err(
+                    Error::new(format!("lower than {}", Bound::<T>(&min))).with_code("range.min")
+                )
+            }
+            OutOfBounds::Upper => {
+                return Err(
+                    Error::new(format!("greater than {}", Bound::<T>(&max))).with_code("range.max")
+                )
+            }
+            OutOfBounds::NotANumber => {
+                return Err(Error::new_static("not a number").with_code("range.nan"))
+            }
         }
     }
     Ok(())
 }
 
+#[inline]
+pub fn apply_combined<T: RangeBounds>(v: &T, (min, max): (T::Size, T::Size)) -> Result<(), Error> {
+    if let Err(e) = v.validate_range(min, max) {
+        let code = match e {
+            OutOfBounds::Lower => "range.min",
+            OutOfBounds::Upper => "range.max",
+            OutOfBounds::NotANumber => {
+                return Err(Error::new_static("not a number").with_code("range.nan"))
+            }
+        };
+        return Err(Error::new(format!(
+            "not between {} and {}",
+            Bound::<T>(&min),
+            Bound::<T>(&max)
+        ))
+        .with_code(code));
+    }
+    Ok(())
+}
+
+/// Like [`apply`], but for integer values whose bounds may be of a different integer type.
+///
+/// The value and bounds are compared without converting one into the other,
+/// so a bound which doesn't fit into the value's type never wraps around.
+#[inline]
+pub fn apply_integer<T: IntegerBounds, Min: Integer, Max: Integer>(
+    v: &T,
+    (min, max): (Option<Min>, Option<Max>),
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_integer_bounds(min, max) {
+        match e {
+            OutOfBounds::Lower => {
+                if let Some(min) = min {
+                    return Err(Error::new(format!("lower than {min}")).with_code("range.min"));
+                }
+            }
+            OutOfBounds::Upper => {
+                if let Some(max) = max {
+                    return Err(Error::new(format!("greater than {max}")).with_code("range.max"));
+                }
+            }
+            OutOfBounds::NotANumber => {}
+        }
+    }
+    Ok(())
+}
+
+/// Like [`apply_combined`], but for integer values whose bounds may be of a different integer type.
+#[inline]
+pub fn apply_integer_combined<T: IntegerBounds, Min: Integer, Max: Integer>(
+    v: &T,
+    (min, max): (Min, Max),
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_integer_bounds(Some(min), Some(max)) {
+        let code = match e {
+            OutOfBounds::Upper => "range.max",
+            _ => "range.min",
+        };
+        return Err(Error::new(format!("not between {min} and {max}")).with_code(code));
+    }
+    Ok(())
+}
+
 pub trait Bounds: PartialOrd {
     type Size: Copy + Sized + Display;
 
@@ -48,6 +152,55 @@ pub trait Bounds: PartialOrd {
 pub enum OutOfBounds {
     Lower,
     Upper,
+    /// The value is not comparable to any bound, e.g. a `NaN` float.
+    NotANumber,
+}
+
+/// Values which can be validated by [`apply`] and [`apply_combined`].
+///
+/// This is implemented for every `T: Bounds`, and for [`Duration`], which can't implement [`Bounds`]
+/// because it doesn't implement [`Display`]. Its bounds are formatted with [`Debug`] instead, e.g. `1.5s`.
+pub trait RangeBounds {
+    type Size: Copy;
+
+    const MIN: Self::Size;
+    const MAX: Self::Size;
+
+    fn validate_range(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), OutOfBounds>;
+
+    /// Formats a bound for use in error messages.
+    fn fmt_bound(bound: &Self::Size, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result;
+}
+
+impl<T: Bounds> RangeBounds for T {
+    type Size = T::Size;
+
+    const MIN: Self::Size = T::MIN;
+    const MAX: Self::Size = T::MAX;
+
+    fn validate_range(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), OutOfBounds> {
+        self.validate_bounds(lower_bound, upper_bound)
+    }
+
+    fn fmt_bound(bound: &Self::Size, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(bound, f)
+    }
+}
+
+struct Bound<'a, T: RangeBounds>(&'a T::Size);
+
+impl<T: RangeBounds> Display for Bound<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        T::fmt_bound(self.0, f)
+    }
 }
 
 macro_rules! impl_for_int {
@@ -77,7 +230,120 @@ macro_rules! impl_for_int {
     };
 }
 
-impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64);
+impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128);
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl Bounds for $T {
+                type Size = $T;
+
+                const MIN: Self::Size = $T::NEG_INFINITY;
+                const MAX: Self::Size = $T::INFINITY;
+
+                fn validate_bounds(
+                    &self,
+                    lower_bound: Self::Size,
+                    upper_bound: Self::Size,
+                ) -> Result<(), OutOfBounds> {
+                    if self.is_nan() {
+                        Err(OutOfBounds::NotANumber)
+                    } else if self < &lower_bound {
+                        Err(OutOfBounds::Lower)
+                    } else if self > &upper_bound {
+                        Err(OutOfBounds::Upper)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
+
+impl RangeBounds for Duration {
+    type Size = Duration;
+
+    const MIN: Self::Size = Duration::ZERO;
+    const MAX: Self::Size = Duration::MAX;
+
+    fn validate_range(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), OutOfBounds> {
+        let nanos = self.as_nanos();
+        if nanos < lower_bound.as_nanos() {
+            Err(OutOfBounds::Lower)
+        } else if nanos > upper_bound.as_nanos() {
+            Err(OutOfBounds::Upper)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn fmt_bound(bound: &Self::Size, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(bound, f)
+    }
+}
+
+impl RangeBounds for Option<Duration> {
+    type Size = Duration;
+
+    const MIN: Self::Size = Duration::ZERO;
+    const MAX: Self::Size = Duration::MAX;
+
+    fn validate_range(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), OutOfBounds> {
+        match self {
+            Some(value) => value.validate_range(lower_bound, upper_bound),
+            None => Ok(()),
+        }
+    }
+
+    fn fmt_bound(bound: &Self::Size, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Duration::fmt_bound(bound, f)
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! impl_for_ord {
+    ($($T:ty),*) => {
+        $(
+            impl Bounds for $T {
+                type Size = $T;
+
+                const MIN: Self::Size = <$T>::MIN;
+                const MAX: Self::Size = <$T>::MAX;
+
+                fn validate_bounds(
+                    &self,
+                    lower_bound: Self::Size,
+                    upper_bound: Self::Size,
+                ) -> Result<(), OutOfBounds> {
+                    if self < &lower_bound {
+                        Err(OutOfBounds::Lower)
+                    } else if self > &upper_bound {
+                        Err(OutOfBounds::Upper)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "chrono")]
+impl_for_ord!(chrono::NaiveDate);
+
+#[cfg(feature = "time")]
+impl_for_ord!(time::Date);
 
 impl<T: Bounds> Bounds for Option<T> {
     type Size = T::Size;
@@ -96,3 +362,87 @@ impl<T: Bounds> Bounds for Option<T> {
         }
     }
 }
+
+/// A primitive integer, which may be compared to any other primitive integer.
+pub trait Integer: Copy + Display {
+    /// The value as its sign and magnitude, which every primitive integer fits into.
+    fn sign_magnitude(self) -> (bool, u128);
+}
+
+macro_rules! impl_integer {
+    (unsigned: $($U:ident),*; signed: $($I:ident),*) => {
+        $(
+            impl Integer for $U {
+                fn sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+            }
+        )*
+        $(
+            impl Integer for $I {
+                fn sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, self.unsigned_abs() as u128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(unsigned: u8, u16, u32, u64, usize, u128; signed: i8, i16, i32, i64, isize, i128);
+
+fn cmp_integer<A: Integer, B: Integer>(a: A, b: B) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    match (a.sign_magnitude(), b.sign_magnitude()) {
+        ((true, _), (false, _)) => Ordering::Less,
+        ((false, _), (true, _)) => Ordering::Greater,
+        ((false, a), (false, b)) => a.cmp(&b),
+        ((true, a), (true, b)) => b.cmp(&a),
+    }
+}
+
+/// Values which can be validated by [`apply_integer`].
+pub trait IntegerBounds {
+    fn validate_integer_bounds<Min: Integer, Max: Integer>(
+        &self,
+        lower_bound: Option<Min>,
+        upper_bound: Option<Max>,
+    ) -> Result<(), OutOfBounds>;
+}
+
+macro_rules! impl_integer_bounds {
+    ($($T:ident),*) => {
+        $(
+            impl IntegerBounds for $T {
+                fn validate_integer_bounds<Min: Integer, Max: Integer>(
+                    &self,
+                    lower_bound: Option<Min>,
+                    upper_bound: Option<Max>,
+                ) -> Result<(), OutOfBounds> {
+                    if lower_bound.is_some_and(|min| cmp_integer(*self, min).is_lt()) {
+                        Err(OutOfBounds::Lower)
+                    } else if upper_bound.is_some_and(|max| cmp_integer(*self, max).is_gt()) {
+                        Err(OutOfBounds::Upper)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_bounds!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128);
+
+impl<T: IntegerBounds> IntegerBounds for Option<T> {
+    fn validate_integer_bounds<Min: Integer, Max: Integer>(
+        &self,
+        lower_bound: Option<Min>,
+        upper_bound: Option<Max>,
+    ) -> Result<(), OutOfBounds> {
+        match self {
+            Some(value) => value.validate_integer_bounds(lower_bound, upper_bound),
+            None => Ok(()),
+        }
+    }
+}
@@ -2,7 +2,7 @@ use crate::{Error, Result};
 
 pub fn apply<T: Required>(v: &T, _: ()) -> Result {
     if !v.is_set() {
-        return Err(Error::new("not set"));
+        return Err(Error::new_static("not set").with_code("required"));
     }
     Ok(())
 }
@@ -0,0 +1,130 @@
+//! Semantic version validation using the [`semver`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(semver)]
+//!     v: String,
+//!     #[garde(semver(min = "1.0.0", max = "2.0.0"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! A value passes if it parses as a [SemVer](https://semver.org) version, such as `1.2.3` or `1.0.0-alpha.1+build.5`.
+//! Version requirements like `^1.2` or versions without all three numbers like `1.2` are rejected.
+//!
+//! `min` and `max` are inclusive bounds, which must be version literals. They are checked at compile time,
+//! and parsed once at runtime. Versions are compared using the ordering of [`semver::Version`],
+//! so a pre-release is lower than its release, e.g. `1.0.0-rc.1` fails `min = "1.0.0"`.
+//!
+//! A value which doesn't parse fails with the code `semver.invalid`, and one out of bounds with `semver.min` or `semver.max`.
+//!
+//! The entrypoint is the [`Semver`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(semver)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//! It is also implemented for [`semver::Version`] itself, in which case only the bounds are checked:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(semver(min = "1.70.0"))]
+//!     v: semver::Version,
+//! }
+//! ```
+
+use semver::Version;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Semver>(v: &T, _: ()) -> Result<(), Error> {
+    apply_range(v, (None, None))
+}
+
+pub fn apply_range<T: Semver>(
+    v: &T,
+    (min, max): (Option<&Version>, Option<&Version>),
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_semver(min, max) {
+        let error = match e {
+            InvalidSemver::Parse(e) => {
+                Error::new(format!("not a semantic version: {e}")).with_code("semver.invalid")
+            }
+            InvalidSemver::Min(min) => {
+                Error::new(format!("version is lower than {min}")).with_code("semver.min")
+            }
+            InvalidSemver::Max(max) => {
+                Error::new(format!("version is greater than {max}")).with_code("semver.max")
+            }
+        };
+        return Err(error);
+    }
+    Ok(())
+}
+
+pub trait Semver {
+    fn validate_semver<'a>(
+        &self,
+        min: Option<&'a Version>,
+        max: Option<&'a Version>,
+    ) -> Result<(), InvalidSemver<'a>>;
+}
+
+pub enum InvalidSemver<'a> {
+    Parse(semver::Error),
+    Min(&'a Version),
+    Max(&'a Version),
+}
+
+impl Semver for Version {
+    fn validate_semver<'a>(
+        &self,
+        min: Option<&'a Version>,
+        max: Option<&'a Version>,
+    ) -> Result<(), InvalidSemver<'a>> {
+        if let Some(min) = min.filter(|min| self < *min) {
+            return Err(InvalidSemver::Min(min));
+        }
+        if let Some(max) = max.filter(|max| self > *max) {
+            return Err(InvalidSemver::Max(max));
+        }
+        Ok(())
+    }
+}
+
+impl<T: AsStr> Semver for T {
+    fn validate_semver<'a>(
+        &self,
+        min: Option<&'a Version>,
+        max: Option<&'a Version>,
+    ) -> Result<(), InvalidSemver<'a>> {
+        Version::parse(self.as_str())
+            .map_err(InvalidSemver::Parse)?
+            .validate_semver(min, max)
+    }
+}
+
+impl<T: Semver> Semver for Option<T> {
+    fn validate_semver<'a>(
+        &self,
+        min: Option<&'a Version>,
+        max: Option<&'a Version>,
+    ) -> Result<(), InvalidSemver<'a>> {
+        match self {
+            Some(value) => value.validate_semver(min, max),
+            None => Ok(()),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub type StaticVersion = once_cell::sync::Lazy<Version>;
+
+/// Parses a bound of `semver(min = "..", max = "..")`, which the derive has already checked.
+#[doc(hidden)]
+pub fn parse_bound(version: &str) -> Version {
+    match Version::parse(version) {
+        Ok(version) => version,
+        Err(e) => panic!("invalid version `{version}` in `semver` rule: {e}"),
+    }
+}
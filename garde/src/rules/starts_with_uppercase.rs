@@ -0,0 +1,56 @@
+//! Leading uppercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(starts_with_uppercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if its first character is an uppercase or titlecase letter, e.g. `"Alice"` or `"Élodie"`.
+//! Letters are classified with Unicode case properties, so an empty string or one starting with a digit fails.
+//!
+//! The entrypoint is the [`StartsWithUppercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(starts_with_uppercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: StartsWithUppercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_starts_with_uppercase() {
+        return Err(Error::new_static("does not start with an uppercase letter")
+            .with_code("starts_with_uppercase"));
+    }
+    Ok(())
+}
+
+pub trait StartsWithUppercase {
+    fn validate_starts_with_uppercase(&self) -> bool;
+}
+
+impl<T: AsStr> StartsWithUppercase for T {
+    fn validate_starts_with_uppercase(&self) -> bool {
+        self.as_str().chars().next().is_some_and(is_capital)
+    }
+}
+
+impl<T: StartsWithUppercase> StartsWithUppercase for Option<T> {
+    fn validate_starts_with_uppercase(&self) -> bool {
+        match self {
+            Some(value) => value.validate_starts_with_uppercase(),
+            None => true,
+        }
+    }
+}
+
+/// Whether `c` is an uppercase letter, or a titlecase letter such as `ǅ`.
+///
+/// Titlecase letters are neither uppercase nor lowercase, but still change when lowercased.
+fn is_capital(c: char) -> bool {
+    if c.is_ascii() {
+        return c.is_ascii_uppercase();
+    }
+    c.is_uppercase() || (!c.is_lowercase() && c.to_lowercase().ne([c]))
+}
@@ -12,6 +12,19 @@
 //! }
 //! ```
 //!
+//! Multiple needles may be given with `any` (the value must end with at least one of them)
+//! or `all` (the value must end with every one of them):
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(suffix(any = ["foo", "bar"]))]
+//!     v: String,
+//!     #[garde(suffix(all = ["foo", "bar"]))]
+//!     w: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Suffix`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(suffix)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -21,7 +34,21 @@ use crate::error::Error;
 
 pub fn apply<T: Suffix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     if !v.validate_suffix(pat) {
-        return Err(Error::new(format!("does not end with \"{pat}\"")));
+        return Err(Error::new(format!("does not end with \"{pat}\"")).with_code("suffix"));
+    }
+    Ok(())
+}
+
+pub fn apply_any<T: Suffix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().any(|pat| v.validate_suffix(pat)) {
+        return Err(Error::new(format!("does not end with any of {pats:?}")).with_code("suffix"));
+    }
+    Ok(())
+}
+
+pub fn apply_all<T: Suffix>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+    if !pats.iter().all(|pat| v.validate_suffix(pat)) {
+        return Err(Error::new(format!("does not end with all of {pats:?}")).with_code("suffix"));
     }
     Ok(())
 }
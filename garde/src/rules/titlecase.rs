@@ -0,0 +1,51 @@
+//! Titlecase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(titlecase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if each of its words, separated by whitespace, starts with an uppercase or titlecase letter, e.g. `"Ada Lovelace"`.
+//! Only the first character of a word is checked, so `"McDonald"` passes, and words starting with a character without case, such as `"3rd"`, are accepted.
+//!
+//! The entrypoint is the [`Titlecase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(titlecase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Titlecase>(v: &T, _: ()) -> Result<(), Error> {
+    if let Some(word) = v.find_lowercase_word() {
+        return Err(Error::new(format!(
+            "word \"{word}\" does not start with an uppercase letter"
+        ))
+        .with_code("titlecase"));
+    }
+    Ok(())
+}
+
+pub trait Titlecase {
+    /// Returns the first word which does not start with an uppercase letter.
+    fn find_lowercase_word(&self) -> Option<&str>;
+}
+
+impl<T: AsStr> Titlecase for T {
+    fn find_lowercase_word(&self) -> Option<&str> {
+        self.as_str()
+            .split_whitespace()
+            .find(|word| word.chars().next().is_some_and(char::is_lowercase))
+    }
+}
+
+impl<T: Titlecase> Titlecase for Option<T> {
+    fn find_lowercase_word(&self) -> Option<&str> {
+        match self {
+            Some(value) => value.find_lowercase_word(),
+            None => None,
+        }
+    }
+}
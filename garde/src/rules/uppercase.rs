@@ -0,0 +1,49 @@
+//! Uppercase validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uppercase)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! A value passes if it is equal to its uppercase form, i.e. `s == s.to_uppercase()`.
+//! Characters without case, such as digits and punctuation, are always accepted.
+//!
+//! The entrypoint is the [`Uppercase`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uppercase)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Uppercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_uppercase() {
+        return Err(Error::new_static("not uppercase").with_code("uppercase"));
+    }
+    Ok(())
+}
+
+pub trait Uppercase {
+    fn validate_uppercase(&self) -> bool;
+}
+
+impl<T: AsStr> Uppercase for T {
+    fn validate_uppercase(&self) -> bool {
+        let s = self.as_str();
+        if s.is_ascii() {
+            return !s.bytes().any(|b| b.is_ascii_lowercase());
+        }
+        s.chars().flat_map(char::to_uppercase).eq(s.chars())
+    }
+}
+
+impl<T: Uppercase> Uppercase for Option<T> {
+    fn validate_uppercase(&self) -> bool {
+        match self {
+            Some(value) => value.validate_uppercase(),
+            None => true,
+        }
+    }
+}
@@ -5,32 +5,175 @@
 //! struct Test {
 //!     #[garde(url)]
 //!     v: String,
+//!     #[garde(url(schemes = ["http", "https"]))]
+//!     w: String,
 //! }
 //! ```
 //!
 //! The entrypoint is the [`Url`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(url)]` rule.
 //!
+//! The `schemes` argument restricts the scheme of the URL to one of the given values.
+//! Schemes are compared against [`url::Url::scheme`], which is always lowercase.
+//!
+//! The `host` and `host_suffix` arguments restrict the host of the URL, as returned by [`url::Url::host_str`]:
+//! - `host` allows hosts which are equal to one of the given values,
+//! - `host_suffix` allows hosts which end with one of the given values, e.g. `".example.com"` allows `cdn.example.com`,
+//!   but not `example.com` itself.
+//!
+//! Hosts are compared ignoring ASCII case. If both are given, the host must match either of them.
+//! A URL without a host, such as `mailto:user@example.com`, always fails these checks.
+//! These arguments may be combined with `schemes`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(url(schemes = ["https"], host = ["cdn.example.com"], host_suffix = [".assets.example.com"]))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! Values longer than [`DEFAULT_MAX_LEN`] bytes fail with the code `url.length` before they are parsed,
+//! which bounds the time spent on inputs of any size. The limit may be changed with `max_len`:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(url(max_len = 8192))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The [`url`] crate only allows parsing from a `&str`, which is why this trait has a blanket implementation for all `T: garde::rules::AsStr`.
 //!
 //! If you need to implement this for a string-like type where a contiguous slice of the entire contents cannot be obtained,
 //! then there is currently no way for you to implement this trait.
+//!
+//! The trait is also implemented for [`url::Url`] itself. Such a value is already known to be valid,
+//! so only the additional constraints like `schemes` are checked, without parsing it again.
+//! These constraints need the parsed URL, which is provided by the [`ParsedUrl`] trait:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(url(schemes = ["https"]))]
+//!     v: url::Url,
+//! }
+//! ```
 
+use std::convert::Infallible;
 use std::fmt::Display;
 
 use super::AsStr;
 use crate::error::Error;
 
+/// The default maximum length of a URL in bytes, which is commonly supported by browsers and servers.
+pub const DEFAULT_MAX_LEN: usize = 2048;
+
 pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
+    check_len(v, DEFAULT_MAX_LEN)?;
     if let Err(e) = v.validate_url() {
-        return Err(Error::new(format!("not a valid url: {e}")));
+        return Err(Error::new(format!("not a valid url: {e}")).with_code("url"));
     }
     Ok(())
 }
 
+pub fn apply_schemes<T: ParsedUrl>(v: &T, (schemes,): (&[&str],)) -> Result<(), Error> {
+    apply_options(v, (Some(schemes), None, None, None))
+}
+
+type List<'a> = Option<&'a [&'a str]>;
+
+pub fn apply_options<T: ParsedUrl>(
+    v: &T,
+    (schemes, hosts, host_suffixes, max_len): (List<'_>, List<'_>, List<'_>, Option<usize>),
+) -> Result<(), Error> {
+    check_len(v, max_len.unwrap_or(DEFAULT_MAX_LEN))?;
+    let mut error = None;
+    let result = v.visit_url(&mut |url| {
+        if let Some(schemes) = schemes {
+            if !schemes.contains(&url.scheme()) {
+                error = Some(
+                    Error::new(format!(
+                        "url scheme `{}` is not one of {schemes:?}",
+                        url.scheme()
+                    ))
+                    .with_code("url.scheme"),
+                );
+                return;
+            }
+        }
+        if hosts.is_none() && host_suffixes.is_none() {
+            return;
+        }
+        let Some(host) = url.host_str() else {
+            error = Some(Error::new_static("url has no host").with_code("url.host"));
+            return;
+        };
+        let allowed = hosts
+            .unwrap_or_default()
+            .iter()
+            .any(|allowed| host.eq_ignore_ascii_case(allowed))
+            || host_suffixes
+                .unwrap_or_default()
+                .iter()
+                .any(|suffix| ends_with_ignore_ascii_case(host, suffix));
+        if !allowed {
+            error =
+                Some(Error::new(format!("url host `{host}` is not allowed")).with_code("url.host"));
+        }
+    });
+    if let Err(e) = result {
+        return Err(Error::new(format!("not a valid url: {e}")).with_code("url"));
+    }
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+fn check_len<T: Url>(v: &T, max: usize) -> Result<(), Error> {
+    match v.url_len() {
+        Some(len) if len > max => {
+            Err(Error::new(format!("url is longer than {max} bytes")).with_code("url.length"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn ends_with_ignore_ascii_case(value: &str, suffix: &str) -> bool {
+    value.len() >= suffix.len()
+        && value.as_bytes()[value.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+}
+
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be validated as a URL",
+    label = "the `url` rule requires a string-like value or a `url::Url`",
+    note = "for a custom string type, implement `garde::rules::AsStr` to use it with the `url` rule"
+)]
 pub trait Url {
     type Error: Display;
 
     fn validate_url(&self) -> Result<(), Self::Error>;
+
+    /// The length of the value in bytes, which is checked against `max_len` before calling [`Url::validate_url`].
+    ///
+    /// The default implementation returns `None`, which skips the check.
+    fn url_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// A [`Url`] which provides access to its parsed value, as needed by the arguments of the `url` rule, such as `schemes`.
+#[diagnostic::on_unimplemented(
+    message = "the URL of `{Self}` cannot be checked against `schemes`, `host` or `host_suffix`",
+    label = "these arguments of the `url` rule require a string-like value or a `url::Url`",
+    note = "for a custom string type, implement `garde::rules::AsStr` to use it with the `url` rule"
+)]
+pub trait ParsedUrl: Url {
+    /// Calls `f` with the parsed URL, or returns an error if the value is not a valid URL.
+    ///
+    /// `f` is not called if there is no value to validate, such as for `Option::None`.
+    fn visit_url(&self, f: &mut dyn FnMut(&url::Url)) -> Result<(), Self::Error>;
 }
 
 impl<T: AsStr> Url for T {
@@ -40,6 +183,37 @@ impl<T: AsStr> Url for T {
         let _ = url::Url::parse(self.as_str())?;
         Ok(())
     }
+
+    fn url_len(&self) -> Option<usize> {
+        Some(self.as_str().len())
+    }
+}
+
+impl<T: AsStr> ParsedUrl for T {
+    fn visit_url(&self, f: &mut dyn FnMut(&url::Url)) -> Result<(), Self::Error> {
+        let url = url::Url::parse(self.as_str())?;
+        f(&url);
+        Ok(())
+    }
+}
+
+impl Url for url::Url {
+    type Error = Infallible;
+
+    fn validate_url(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn url_len(&self) -> Option<usize> {
+        Some(self.as_str().len())
+    }
+}
+
+impl ParsedUrl for url::Url {
+    fn visit_url(&self, f: &mut dyn FnMut(&url::Url)) -> Result<(), Self::Error> {
+        f(self);
+        Ok(())
+    }
 }
 
 impl<T: Url> Url for Option<T> {
@@ -51,4 +225,17 @@ impl<T: Url> Url for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn url_len(&self) -> Option<usize> {
+        self.as_ref().and_then(Url::url_len)
+    }
+}
+
+impl<T: ParsedUrl> ParsedUrl for Option<T> {
+    fn visit_url(&self, f: &mut dyn FnMut(&url::Url)) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.visit_url(f),
+            None => Ok(()),
+        }
+    }
 }
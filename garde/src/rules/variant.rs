@@ -0,0 +1,70 @@
+//! Enum variant validation.
+//!
+//! ```rust
+//! #[derive(PartialEq)]
+//! enum Status {
+//!     Active,
+//!     Suspended { reason: String },
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(variant(Active))]
+//!     v: Status,
+//! }
+//! ```
+//!
+//! The variant is looked up on the type of the field, so this only compiles if the field's enum has the named variant.
+//! `Option`s and references to the enum are also supported, in which case the enum is found by removing them from the field type.
+//! Inside `inner`, the variant must be named by its full path instead, e.g. `inner(variant(Status::Active))`.
+//!
+//! The variant's fields are not inspected, only which variant the value is.
+//!
+//! The entrypoint is the [`Variant`] trait. It is implemented for the enum itself, `Option`s, and references.
+
+use crate::error::Error;
+
+pub fn apply<T, E, F>(v: &T, (is_variant, name): (F, &str)) -> Result<(), Error>
+where
+    T: Variant<E>,
+    F: Fn(&E) -> bool,
+{
+    if !v.validate_variant(is_variant) {
+        return Err(Error::new(format!("not the `{name}` variant")).with_code("variant"));
+    }
+    Ok(())
+}
+
+pub trait Variant<E> {
+    fn validate_variant<F: Fn(&E) -> bool>(&self, is_variant: F) -> bool;
+}
+
+impl<E> Variant<E> for E {
+    fn validate_variant<F: Fn(&E) -> bool>(&self, is_variant: F) -> bool {
+        is_variant(self)
+    }
+}
+
+impl<E> Variant<E> for &E {
+    fn validate_variant<F: Fn(&E) -> bool>(&self, is_variant: F) -> bool {
+        is_variant(self)
+    }
+}
+
+impl<E> Variant<E> for Option<E> {
+    fn validate_variant<F: Fn(&E) -> bool>(&self, is_variant: F) -> bool {
+        match self {
+            Some(value) => is_variant(value),
+            None => true,
+        }
+    }
+}
+
+impl<E> Variant<E> for Option<&E> {
+    fn validate_variant<F: Fn(&E) -> bool>(&self, is_variant: F) -> bool {
+        match self {
+            Some(value) => is_variant(value),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Word count validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(words(min = 10, max = 500))]
+//!     v: String,
+//!     #[garde(words(max = 5, separator = ","))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! By default, words are separated by Unicode whitespace. With `separator`, the value is split on the given string instead,
+//! and pieces which are empty or only contain whitespace are not counted, so `"a, b,,"` has two words.
+//! Unlike [`length`][`super::length`], this does not count characters.
+//!
+//! The entrypoint is the [`Words`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(words(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Words>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
+    check(v.validate_words(min, max, None), min, max)
+}
+
+pub fn apply_separator<T: Words>(
+    v: &T,
+    (min, max, separator): (usize, usize, &str),
+) -> Result<(), Error> {
+    check(v.validate_words(min, max, Some(separator)), min, max)
+}
+
+fn check(result: Result<(), InvalidWords>, min: usize, max: usize) -> Result<(), Error> {
+    let words = |count: usize| match count {
+        1 => "1 word".to_string(),
+        count => format!("{count} words"),
+    };
+    match result {
+        Ok(()) => Ok(()),
+        Err(InvalidWords::Min(count)) => Err(Error::new(format!(
+            "has {}, expected at least {min}",
+            words(count)
+        ))
+        .with_code("words.min")),
+        Err(InvalidWords::Max(count)) => Err(Error::new(format!(
+            "has {}, expected at most {max}",
+            words(count)
+        ))
+        .with_code("words.max")),
+    }
+}
+
+pub trait Words {
+    fn validate_words(
+        &self,
+        min: usize,
+        max: usize,
+        separator: Option<&str>,
+    ) -> Result<(), InvalidWords>;
+}
+
+/// The word count is out of range, with the number of words which were found.
+pub enum InvalidWords {
+    Min(usize),
+    Max(usize),
+}
+
+impl<T: AsStr> Words for T {
+    fn validate_words(
+        &self,
+        min: usize,
+        max: usize,
+        separator: Option<&str>,
+    ) -> Result<(), InvalidWords> {
+        let value = self.as_str();
+        let count = match separator {
+            Some(separator) => value
+                .split(separator)
+                .filter(|word| !word.trim().is_empty())
+                .count(),
+            None => value.split_whitespace().count(),
+        };
+        if count < min {
+            Err(InvalidWords::Min(count))
+        } else if count > max {
+            Err(InvalidWords::Max(count))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T: Words> Words for Option<T> {
+    fn validate_words(
+        &self,
+        min: usize,
+        max: usize,
+        separator: Option<&str>,
+    ) -> Result<(), InvalidWords> {
+        match self {
+            Some(value) => value.validate_words(min, max, separator),
+            None => Ok(()),
+        }
+    }
+}
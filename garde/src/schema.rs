@@ -0,0 +1,314 @@
+//! JSON Schema export.
+//!
+//! With the `schema` feature, `#[derive(Validate)]` also implements [`JsonSchema`] for structs,
+//! which describes the type and its rules as a [JSON Schema](https://json-schema.org/):
+//!
+//! ```rust
+//! use garde::schema::JsonSchema;
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(length(min = 3))]
+//!     name: String,
+//!     #[garde(range(max = 150))]
+//!     age: Option<u8>,
+//! }
+//!
+//! assert_eq!(
+//!     User::json_schema(),
+//!     serde_json::json!({
+//!         "type": "object",
+//!         "properties": {
+//!             "name": { "type": "string", "minLength": 3 },
+//!             "age": { "type": "integer", "maximum": 150 },
+//!         },
+//!         "required": ["name"],
+//!     })
+//! );
+//! ```
+//!
+//! The schema is built from the [`Reflect`][`crate::reflection::Reflect`] metadata:
+//!
+//! - Integer primitives have the type `integer`, all other numbers the type `number`.
+//! - `length` becomes `minLength`/`maxLength` on strings, `minItems`/`maxItems` on lists, and `minProperties`/`maxProperties` on maps.
+//! - `range` becomes `minimum`/`maximum`.
+//! - Literal `pattern`s become `pattern`.
+//! - `email`, `url`, `ipv4`, `ipv6`, and `hostname` become the `format`s `email`, `uri`, `ipv4`, `ipv6`, and `hostname`.
+//! - Rules inside of `inner` apply to the `items` of a list, except for `length`.
+//! - Fields are `required` unless they are an `Option` without the `required` rule.
+//! - `dive` embeds the schema of the field's type, or an empty schema if it doesn't implement [`JsonSchema`]. `flatten` merges its properties into the parent.
+//!
+//! All other rules have no schema equivalent and are left out, e.g. `custom`, `not`, `contains`, `prefix`, `suffix`,
+//! `byte_length`, `credit_card`, or `date`. Bounds which aren't literals, such as `ctx.max`, are left out as well.
+//! Enums don't implement [`JsonSchema`].
+//!
+//! Recursive types, such as a struct which dives into a `Vec<Self>`, embed an empty schema where they refer to themselves.
+
+use std::cell::RefCell;
+
+use serde_json::{json, Map, Value};
+
+use crate::reflection::{Category, FieldRuleMeta, RuleMeta};
+
+/// Implemented by `#[derive(Validate)]` for structs if the `schema` feature is enabled.
+pub trait JsonSchema {
+    fn json_schema() -> Value;
+}
+
+/// A nested schema, identified by the name of the field which dives into it.
+#[doc(hidden)]
+pub type Nested = (&'static str, fn() -> Value);
+
+/// Builds the schema of `T`, or an empty schema if it is already being built further up,
+/// which is the case if `T` is recursive.
+#[doc(hidden)]
+pub fn guard<T: ?Sized>(build: impl FnOnce() -> Value) -> Value {
+    thread_local! {
+        static BUILDING: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Removes the type from `BUILDING` even if `build` panics.
+    struct Pop;
+
+    impl Drop for Pop {
+        fn drop(&mut self) {
+            BUILDING.with(|building| building.borrow_mut().pop());
+        }
+    }
+
+    // `TypeId` would require `T: 'static`, which excludes structs with lifetimes.
+    let name = std::any::type_name::<T>();
+    if BUILDING.with(|building| building.borrow().contains(&name)) {
+        return Value::Object(Map::new());
+    }
+    BUILDING.with(|building| building.borrow_mut().push(name));
+    let _pop = Pop;
+    build()
+}
+
+/// Builds the schema of a struct with the given fields.
+#[doc(hidden)]
+pub fn object(fields: &[FieldRuleMeta], nested: &[Nested]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for field in fields {
+        let nested = nested
+            .iter()
+            .find(|(name, _)| *name == field.name)
+            .map(|(_, schema)| schema());
+
+        if field.rule("flatten").is_some() {
+            if let Some(Value::Object(mut nested)) = nested {
+                if let Some(Value::Object(nested_properties)) = nested.remove("properties") {
+                    properties.extend(nested_properties);
+                }
+                if let Some(Value::Array(nested_required)) = nested.remove("required") {
+                    if !field.optional {
+                        required.extend(nested_required);
+                    }
+                }
+            }
+            continue;
+        }
+
+        if !field.optional || field.rule("required").is_some() {
+            required.push(Value::from(field.name));
+        }
+        properties.insert(field.name.into(), field_schema(field, nested));
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".into(), "object".into());
+    schema.insert("properties".into(), properties.into());
+    if !required.is_empty() {
+        schema.insert("required".into(), required.into());
+    }
+    schema.into()
+}
+
+fn field_schema(field: &FieldRuleMeta, nested: Option<Value>) -> Value {
+    let mut schema = match nested {
+        Some(Value::Object(nested)) => nested,
+        _ => Map::new(),
+    };
+    if let Some(ty) = type_name(field) {
+        schema.entry("type").or_insert(ty.into());
+    }
+    for rule in field.rules {
+        apply(&mut schema, rule, field.category);
+    }
+    if !field.inner.is_empty() {
+        let items = schema
+            .entry("items")
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(items) = items {
+            for rule in field.inner {
+                apply(items, rule, None);
+            }
+        }
+    }
+    schema.into()
+}
+
+fn type_name(field: &FieldRuleMeta) -> Option<&'static str> {
+    match field.category? {
+        Category::Strings => Some("string"),
+        Category::Numbers if field.integer => Some("integer"),
+        Category::Numbers => Some("number"),
+        Category::Lists => Some("array"),
+        Category::Maps => Some("object"),
+        Category::Paths => None,
+    }
+}
+
+fn apply(schema: &mut Map<String, Value>, rule: &RuleMeta, category: Option<Category>) {
+    let mut bound = |key: &str, param: &str| {
+        if let Some(value) = rule.param(param).and_then(number) {
+            schema.insert(key.into(), value);
+        }
+    };
+    match rule.name {
+        "length" => match category {
+            Some(Category::Strings) => {
+                bound("minLength", "min");
+                bound("maxLength", "max");
+            }
+            Some(Category::Lists) => {
+                bound("minItems", "min");
+                bound("maxItems", "max");
+            }
+            Some(Category::Maps) => {
+                bound("minProperties", "min");
+                bound("maxProperties", "max");
+            }
+            _ => {}
+        },
+        "range" => {
+            bound("minimum", "min");
+            bound("maximum", "max");
+        }
+        "pattern" => {
+            // Patterns which aren't literals are described by `matcher` instead.
+            if let Some(pattern) = rule.param("pattern") {
+                schema.insert("pattern".into(), pattern.into());
+            }
+        }
+        "email" => {
+            schema.insert("format".into(), "email".into());
+        }
+        "url" => {
+            schema.insert("format".into(), "uri".into());
+        }
+        "hostname" => {
+            schema.insert("format".into(), "hostname".into());
+        }
+        "ip" => match rule.param("kind") {
+            Some("v4") => {
+                schema.insert("format".into(), "ipv4".into());
+            }
+            Some("v6") => {
+                schema.insert("format".into(), "ipv6".into());
+            }
+            _ => {}
+        },
+        _ => {}
+    }
+}
+
+/// Looks up the schema of a nested type, or an empty schema if it doesn't implement [`JsonSchema`],
+/// e.g. for custom implementations of `Validate`.
+///
+/// This relies on method resolution preferring [`NestedSchema`] over [`NoSchema`]:
+/// `(&Probe::<T>::new()).nested_schema()`.
+#[doc(hidden)]
+pub struct Probe<T: ?Sized>(std::marker::PhantomData<T>);
+
+impl<T: ?Sized> Probe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(std::marker::PhantomData)
+    }
+}
+
+#[doc(hidden)]
+pub trait NestedSchema {
+    fn nested_schema(&self) -> Value;
+}
+
+impl<T: JsonSchema + ?Sized> NestedSchema for Probe<T> {
+    fn nested_schema(&self) -> Value {
+        T::json_schema()
+    }
+}
+
+#[doc(hidden)]
+pub trait NoSchema {
+    fn nested_schema(&self) -> Value;
+}
+
+impl<T: ?Sized> NoSchema for &Probe<T> {
+    fn nested_schema(&self) -> Value {
+        Value::Object(Map::new())
+    }
+}
+
+/// Parses a literal bound, which may be an integer or a float.
+fn number(source: &str) -> Option<Value> {
+    let source = source.replace([' ', '_'], "");
+    if let Ok(value) = source.parse::<i64>() {
+        return Some(value.into());
+    }
+    if let Ok(value) = source.parse::<u64>() {
+        return Some(value.into());
+    }
+    source.parse::<f64>().ok().map(|value| json!(value))
+}
+
+impl<T: JsonSchema + ?Sized> JsonSchema for &T {
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T: JsonSchema + ?Sized> JsonSchema for Box<T> {
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn json_schema() -> Value {
+        T::json_schema()
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for [T] {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema() })
+    }
+}
+
+impl<T: JsonSchema, const N: usize> JsonSchema for [T; N] {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema(), "minItems": N, "maxItems": N })
+    }
+}
+
+impl<K, V: JsonSchema, S> JsonSchema for std::collections::HashMap<K, V, S> {
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
+
+impl<K, V: JsonSchema> JsonSchema for std::collections::BTreeMap<K, V> {
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
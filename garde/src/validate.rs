@@ -2,13 +2,32 @@
 
 use std::fmt::Debug;
 
-use crate::error::{Path, PathComponentKind};
+use crate::error::{Error, Path, PathComponentKind, Severity};
 use crate::Report;
 
 /// The core trait of this crate.
 ///
 /// Validation runs the fields through every validation rules,
 /// and aggregates any errors into a [`Report`].
+///
+/// This trait is object-safe, so heterogeneous values may be stored and validated
+/// as `Box<dyn Validate<Context = C>>` (or `&dyn`, `Rc<dyn>`, `Arc<dyn>`), as long as they share a context type:
+///
+/// ```rust
+/// use garde::Validate;
+///
+/// #[derive(garde::Validate)]
+/// struct Name(#[garde(length(min = 1))] String);
+///
+/// #[derive(garde::Validate)]
+/// struct Age(#[garde(range(max = 150))] u8);
+///
+/// let items: Vec<Box<dyn Validate<Context = ()>>> = vec![
+///     Box::new(Name(String::new())),
+///     Box::new(Age(200)),
+/// ];
+/// assert_eq!(items.validate(&()).unwrap_err().iter().count(), 2);
+/// ```
 pub trait Validate {
     /// A user-provided context.
     ///
@@ -23,10 +42,61 @@ pub trait Validate {
     fn validate(&self, ctx: &Self::Context) -> Result<(), Report> {
         let mut report = Report::new();
         self.validate_into(ctx, &mut Path::empty, &mut report);
-        match report.is_empty() {
-            true => Ok(()),
-            false => Err(report),
-        }
+        report.into_result()
+    }
+
+    /// Like [`Validate::validate`], but configured by [`ValidationOptions`].
+    ///
+    /// ```rust
+    /// use garde::{Validate, ValidationOptions};
+    ///
+    /// #[derive(garde::Validate)]
+    /// struct User<'a> {
+    ///     #[garde(length(min = 3))]
+    ///     username: &'a str,
+    ///     #[garde(length(min = 8))]
+    ///     password: &'a str,
+    /// }
+    ///
+    /// let user = User { username: "", password: "" };
+    /// let options = ValidationOptions { fail_fast: true, ..Default::default() };
+    /// let report = user.validate_with_options(&(), &options).unwrap_err();
+    /// assert_eq!(report.iter().count(), 1);
+    /// ```
+    fn validate_with_options(
+        &self,
+        ctx: &Self::Context,
+        options: &ValidationOptions,
+    ) -> Result<(), Report> {
+        let mut report = Report::with_options(options);
+        self.validate_into(ctx, &mut Path::empty, &mut report);
+        report.into_result()
+    }
+
+    /// Validates `Self`, appending any errors to an existing `report`, at paths prefixed by `base`.
+    ///
+    /// This makes it possible to validate many values with a single report, and to reuse its allocation
+    /// via [`Report::clear`], e.g. in a hot loop:
+    ///
+    /// ```rust
+    /// use garde::{Path, Report, Validate};
+    ///
+    /// #[derive(garde::Validate)]
+    /// struct Item<'a> {
+    ///     #[garde(length(min = 1))]
+    ///     name: &'a str,
+    /// }
+    ///
+    /// let items = [Item { name: "a" }, Item { name: "" }, Item { name: "" }];
+    ///
+    /// let mut report = Report::new();
+    /// for (i, item) in items.iter().enumerate() {
+    ///     item.validate_at(&(), &mut report, Path::new("items").join(i));
+    /// }
+    /// assert_eq!(report.to_string(), "items[1].name: length is lower than 1\nitems[2].name: length is lower than 1\n");
+    /// ```
+    fn validate_at(&self, ctx: &Self::Context, report: &mut Report, base: Path) {
+        self.validate_into(ctx, &mut || base.clone(), report);
     }
 
     /// Validates `Self`, aggregating all validation errors into `Report`.
@@ -38,6 +108,97 @@ pub trait Validate {
     );
 }
 
+/// Options which change how [`Validate::validate_with_options`] collects errors.
+///
+/// The defaults match [`Validate::validate`]: every error and warning is collected, without trace notes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValidationOptions {
+    /// Stop validating once the report holds this many errors. Defaults to `None`, which is no limit.
+    ///
+    /// Only entries with [`Severity::Error`] are counted, warnings and notes are kept without using up the limit.
+    /// Validation stops at the next field, so a single field may still be validated in full,
+    /// but any entries beyond the limit are dropped.
+    pub error_limit: Option<usize>,
+    /// Stop validating after the first error, which is the same as an `error_limit` of `1`. Defaults to `false`.
+    pub fail_fast: bool,
+    /// Whether errors with [`Severity::Warning`] are kept in the report. Defaults to `true`.
+    pub collect_warnings: bool,
+    /// Whether to record a note for every `None` skipped by `dive`, see [`Report::with_trace`]. Defaults to `false`.
+    pub trace: bool,
+    /// Whether to attach the `Debug` representation of the offending value to errors, see [`Report::with_value_debug`]. Defaults to `false`.
+    pub value_debug: bool,
+}
+
+impl ValidationOptions {
+    /// The maximum number of errors in the report, combining `error_limit` and `fail_fast`.
+    pub fn limit(&self) -> Option<usize> {
+        match self.fail_fast {
+            true => Some(1),
+            false => self.error_limit,
+        }
+    }
+}
+
+impl Default for ValidationOptions {
+    fn default() -> Self {
+        Self {
+            error_limit: None,
+            fail_fast: false,
+            collect_warnings: true,
+            trace: false,
+            value_debug: false,
+        }
+    }
+}
+
+/// Validates `value` using a default-constructed context.
+///
+/// This is equivalent to calling [`Validate::validate`], but does not require the trait to be in scope.
+///
+/// ```rust
+/// #[derive(garde::Validate)]
+/// struct User<'a> {
+///     #[garde(length(min = 3))]
+///     username: &'a str,
+/// }
+///
+/// assert!(garde::validate(&User { username: "test" }).is_ok());
+/// assert!(garde::validate(&User { username: "" }).is_err());
+/// ```
+pub fn validate<T>(value: &T) -> Result<(), Report>
+where
+    T: ?Sized + Validate,
+    T::Context: Default,
+{
+    value.validate(&T::Context::default())
+}
+
+/// Validates `value` using the provided context.
+///
+/// This is equivalent to calling [`Validate::validate`], but does not require the trait to be in scope.
+///
+/// ```rust
+/// struct Limits {
+///     min: usize,
+/// }
+///
+/// #[derive(garde::Validate)]
+/// #[garde(context(Limits as ctx))]
+/// struct User<'a> {
+///     #[garde(length(min = ctx.min))]
+///     username: &'a str,
+/// }
+///
+/// let limits = Limits { min: 5 };
+/// assert!(garde::validate_with(&User { username: "test" }, &limits).is_err());
+/// ```
+pub fn validate_with<T>(value: &T, ctx: &T::Context) -> Result<(), Report>
+where
+    T: ?Sized + Validate,
+{
+    value.validate(ctx)
+}
+
 /// A struct which wraps a valid instance of some `T`.
 ///
 /// The only way to create an instance of this struct is through the `validate`
@@ -97,9 +258,30 @@ impl<T: Debug> Debug for Unvalidated<T> {
     }
 }
 
-impl<'a, T: ?Sized + Validate> Validate for &'a T {
+impl<T: ?Sized + Validate> Validate for &T {
+    type Context = T::Context;
+
+    fn validate(&self, ctx: &Self::Context) -> Result<(), Report> {
+        <T as Validate>::validate(self, ctx)
+    }
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        <T as Validate>::validate_into(self, ctx, parent, report)
+    }
+}
+
+impl<T: ?Sized + Validate> Validate for &mut T {
     type Context = T::Context;
 
+    fn validate(&self, ctx: &Self::Context) -> Result<(), Report> {
+        <T as Validate>::validate(self, ctx)
+    }
+
     fn validate_into(
         &self,
         ctx: &Self::Context,
@@ -110,7 +292,7 @@ impl<'a, T: ?Sized + Validate> Validate for &'a T {
     }
 }
 
-impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
+impl<T: ?Sized + Validate> Validate for std::boxed::Box<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -123,7 +305,7 @@ impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
     }
 }
 
-impl<T: Validate> Validate for std::boxed::Box<T> {
+impl<T: ?Sized + Validate> Validate for std::rc::Rc<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -136,7 +318,7 @@ impl<T: Validate> Validate for std::boxed::Box<T> {
     }
 }
 
-impl<T: Validate> Validate for std::rc::Rc<T> {
+impl<T: ?Sized + Validate> Validate for std::sync::Arc<T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -149,7 +331,9 @@ impl<T: Validate> Validate for std::rc::Rc<T> {
     }
 }
 
-impl<T: Validate> Validate for std::sync::Arc<T> {
+// Both variants are validated through the borrowed form, so e.g. a `Cow<[T]>`
+// is validated like a `[T]`, whether it is borrowed or owns a `Vec<T>`.
+impl<'a, T: ?Sized + ToOwned + Validate> Validate for std::borrow::Cow<'a, T> {
     type Context = T::Context;
 
     fn validate_into(
@@ -172,6 +356,9 @@ macro_rules! impl_validate_list {
 
             fn validate_into(&self, ctx: &Self::Context, mut parent: &mut dyn FnMut() -> Path, report: &mut Report) {
                 for (index, item) in self.iter().enumerate() {
+                    if report.is_full() {
+                        break;
+                    }
                     let mut path = $crate::util::nested_path!(parent, index);
                     <T as Validate>::validate_into(item, ctx, &mut path, report);
                 }
@@ -180,6 +367,7 @@ macro_rules! impl_validate_list {
     };
 }
 
+// Items of a `HashSet` are indexed in iteration order, which is arbitrary.
 impl_validate_list!(<T, S> std::collections::HashSet<T, S>);
 impl_validate_list!(<T> std::collections::BTreeSet<T>);
 impl_validate_list!(<T> std::collections::BinaryHeap<T>);
@@ -187,6 +375,9 @@ impl_validate_list!(<T> std::collections::LinkedList<T>);
 impl_validate_list!(<T> std::collections::VecDeque<T>);
 impl_validate_list!(<T> std::vec::Vec<T>);
 impl_validate_list!(<T> [T]);
+// Items of an `IndexSet` are indexed in insertion order.
+#[cfg(feature = "indexmap")]
+impl_validate_list!(<T, S> indexmap::IndexSet<T, S>);
 
 impl<T: Validate, const N: usize> Validate for [T; N] {
     type Context = T::Context;
@@ -198,6 +389,9 @@ impl<T: Validate, const N: usize> Validate for [T; N] {
         report: &mut Report,
     ) {
         for (index, item) in self.iter().enumerate() {
+            if report.is_full() {
+                break;
+            }
             let mut path = crate::util::nested_path!(parent, index);
             <T as Validate>::validate_into(item, ctx, &mut path, report);
         }
@@ -268,6 +462,9 @@ where
         report: &mut Report,
     ) {
         for (key, value) in self.iter() {
+            if report.is_full() {
+                break;
+            }
             let mut path = crate::util::nested_path!(parent, key);
             <V as Validate>::validate_into(value, ctx, &mut path, report);
         }
@@ -288,6 +485,34 @@ where
         report: &mut Report,
     ) {
         for (key, value) in self.iter() {
+            if report.is_full() {
+                break;
+            }
+            let mut path = crate::util::nested_path!(parent, key);
+            <V as Validate>::validate_into(value, ctx, &mut path, report);
+        }
+    }
+}
+
+/// Entries are validated in insertion order, so errors are reported in a deterministic order.
+#[cfg(feature = "indexmap")]
+impl<K, V, S> Validate for indexmap::IndexMap<K, V, S>
+where
+    K: Clone + PathComponentKind,
+    V: Validate,
+{
+    type Context = V::Context;
+
+    fn validate_into(
+        &self,
+        ctx: &Self::Context,
+        mut parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        for (key, value) in self.iter() {
+            if report.is_full() {
+                break;
+            }
             let mut path = crate::util::nested_path!(parent, key);
             <V as Validate>::validate_into(value, ctx, &mut path, report);
         }
@@ -303,8 +528,17 @@ impl<T: Validate> Validate for Option<T> {
         parent: &mut dyn FnMut() -> Path,
         report: &mut Report,
     ) {
-        if let Some(value) = self {
-            value.validate_into(ctx, parent, report)
+        match self {
+            Some(value) => value.validate_into(ctx, parent, report),
+            None if report.is_tracing() => report.append(
+                parent(),
+                Error::builder()
+                    .static_message("skipped, value is none")
+                    .code("dive.none")
+                    .severity(Severity::Info)
+                    .build(),
+            ),
+            None => {}
         }
     }
 }
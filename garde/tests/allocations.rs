@@ -0,0 +1,216 @@
+//! Asserts the allocation savings which are measured by `benches/allocations.rs`.
+//!
+//! The counter is thread-local, so the tests may run in parallel.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use garde::{Error, Path, Report, Validate};
+
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn count(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[derive(Debug, garde::Validate)]
+struct Many<'a> {
+    #[garde(length(min = 1))]
+    a: &'a str,
+    #[garde(length(min = 1))]
+    b: &'a str,
+    #[garde(length(min = 1))]
+    c: &'a str,
+    #[garde(length(min = 1))]
+    d: &'a str,
+    #[garde(range(min = 1))]
+    e: i32,
+    #[garde(range(min = 1))]
+    f: i32,
+    #[garde(range(min = 1))]
+    g: i32,
+    #[garde(range(min = 1))]
+    h: i32,
+}
+
+#[test]
+fn capacity_hint_reduces_allocations() {
+    let input = Many {
+        a: "",
+        b: "",
+        c: "",
+        d: "",
+        e: 0,
+        f: 0,
+        g: 0,
+        h: 0,
+    };
+
+    let without_hint = count(|| {
+        let mut report = Report::new();
+        input.validate_into(&(), &mut Path::empty, &mut report);
+        drop(std::hint::black_box(report));
+    });
+    let with_hint = count(|| {
+        let report = input.validate(&());
+        drop(std::hint::black_box(report));
+    });
+
+    // Without a hint, the report's buffer grows from 0 to 4 and then to 8 entries.
+    assert!(
+        with_hint < without_hint,
+        "with hint: {with_hint}, without hint: {without_hint}"
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Flat<'a> {
+    #[garde(ascii)]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Nested<'a> {
+    #[garde(dive)]
+    inner: Flat<'a>,
+    #[garde(dive)]
+    items: Vec<Nested<'a>>,
+}
+
+#[test]
+fn shallow_paths_do_not_allocate() {
+    let flat = Flat { value: "😂" };
+    let flat = count(|| {
+        let report = flat.validate(&());
+        drop(std::hint::black_box(report));
+    });
+    let nested = Nested {
+        inner: Flat { value: "a" },
+        items: vec![Nested {
+            inner: Flat { value: "😂" },
+            items: vec![],
+        }],
+    };
+    let nested = count(|| {
+        let report = nested.validate(&());
+        drop(std::hint::black_box(report));
+    });
+
+    // The flat path is stored inline, so only the report's buffer is allocated.
+    assert_eq!(flat, 1);
+    // `items[0].inner.value` is too deep to be stored inline.
+    assert!(flat < nested, "flat: {flat}, nested: {nested}");
+}
+
+#[test]
+fn checksum_rules_do_not_allocate() {
+    use garde::rules::{checksum, imei, isbn, luhn};
+
+    let allocations = count(|| {
+        assert!(isbn::apply(&"978-3-16-148410-0", ()).is_ok());
+        assert!(isbn::apply(&"0-8044-2957-X", ()).is_ok());
+        assert!(imei::apply(&"49-015420-323751-8", ()).is_ok());
+        assert!(luhn::apply(&"79927398713", ()).is_ok());
+        assert!(checksum::apply(&"79927398713", (checksum::Algorithm::Mod10,)).is_ok());
+        drop(std::hint::black_box(isbn::apply(&"978-3-16-148410-1", ())));
+        drop(std::hint::black_box(imei::apply(&"49-015420-323751-9", ())));
+        drop(std::hint::black_box(luhn::apply(&"7992739871a", ())));
+    });
+    assert_eq!(allocations, 0);
+}
+
+#[derive(Debug, Clone, garde::Validate)]
+struct Static<'a> {
+    #[garde(isbn)]
+    a: &'a str,
+    #[garde(checksum(algo = "mod10"))]
+    b: &'a str,
+    #[garde(not(ipv4))]
+    c: &'a str,
+    #[garde(not(alphanumeric))]
+    d: &'a str,
+    #[garde(required)]
+    e: Option<&'a str>,
+}
+
+#[test]
+fn static_messages_do_not_allocate() {
+    let message = "ISBN must have 10 or 13 digits";
+    assert_eq!(
+        count(|| drop(std::hint::black_box(Error::new_static(message)))),
+        0
+    );
+    assert_eq!(count(|| drop(std::hint::black_box(Error::new(message)))), 1);
+
+    let input = Static {
+        a: "123",
+        b: "1",
+        c: "127.0.0.1",
+        d: "abc",
+        e: None,
+    };
+    let allocations = count(|| {
+        let report = input.validate(&());
+        assert_eq!(report.unwrap_err().iter().count(), 5);
+    });
+    // Only the report's buffer is allocated.
+    assert_eq!(allocations, 1);
+}
+
+#[test]
+fn reused_report_does_not_allocate() {
+    let batch = vec![
+        Static {
+            a: "123",
+            b: "1",
+            c: "127.0.0.1",
+            d: "abc",
+            e: None,
+        };
+        100
+    ];
+    let per_value = count(|| {
+        for value in &batch {
+            drop(std::hint::black_box(value.validate(&())));
+        }
+    });
+
+    let mut report = Report::new();
+    // Warm up, so the report's buffer is already large enough.
+    batch[0].validate_at(&(), &mut report, Path::empty());
+    let reused = count(|| {
+        for value in &batch {
+            report.clear();
+            value.validate_at(&(), &mut report, Path::empty());
+            std::hint::black_box(&report);
+        }
+    });
+
+    assert_eq!(per_value, batch.len());
+    assert_eq!(reused, 0);
+}
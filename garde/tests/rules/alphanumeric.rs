@@ -30,3 +30,60 @@ fn alphanumeric_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Slug<'a> {
+    #[garde(alphanumeric(extra = "-_"))]
+    field: &'a str,
+
+    #[garde(inner(alphanumeric(extra = "-_")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn alphanumeric_extra_valid() {
+    util::check_ok(
+        &[Slug {
+            field: "my-slug_0123",
+            inner: &["my-slug_0123"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn alphanumeric_extra_invalid() {
+    util::check_fail!(
+        &[Slug {
+            field: "my slug!!.",
+            inner: &["my_slug/"]
+        }],
+        &()
+    )
+}
+
+/// A user type which only implements the required method of `Alphanumeric`.
+#[derive(Debug)]
+struct Code(u32);
+
+impl garde::rules::alphanumeric::Alphanumeric for Code {
+    fn validate_alphanumeric(&self) -> bool {
+        self.0 != 0
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Codes {
+    #[garde(alphanumeric(extra = "-"))]
+    field: Code,
+}
+
+#[test]
+fn alphanumeric_default_methods() {
+    util::check_ok(&[Codes { field: Code(1) }], &());
+    let report = garde::Validate::validate(&Codes { field: Code(0) }, &()).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "field: not alphanumeric, found disallowed characters ['\u{fffd}']\n"
+    );
+}
@@ -29,3 +29,103 @@ fn ascii_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Extra<'a> {
+    #[garde(ascii(extra = "é"))]
+    field: &'a str,
+}
+
+#[test]
+fn ascii_extra_valid() {
+    util::check_ok(&[Extra { field: "café" }], &())
+}
+
+#[test]
+fn ascii_extra_invalid() {
+    util::check_fail!(
+        &[Extra {
+            field: "😂 café ü😂"
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Printable<'a> {
+    #[garde(ascii(printable))]
+    field: &'a str,
+    #[garde(not(ascii(printable)))]
+    negated: &'a str,
+}
+
+#[test]
+fn ascii_printable_valid() {
+    util::check_ok(
+        &[Printable {
+            field: " a!0_~",
+            negated: "a\n",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn ascii_printable_invalid() {
+    util::check_fail!(
+        &[
+            Printable {
+                field: "a\0b",
+                negated: "a",
+            },
+            Printable {
+                field: "line\nbreak",
+                negated: "",
+            },
+            Printable {
+                field: "café",
+                negated: "a",
+            },
+        ],
+        &()
+    )
+}
+
+/// A user type which only implements the required method of `Ascii`.
+#[derive(Debug)]
+struct Token(Vec<u8>);
+
+impl garde::rules::ascii::Ascii for Token {
+    fn validate_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Tokens {
+    #[garde(ascii(extra = "é"))]
+    extra: Token,
+    #[garde(ascii(printable))]
+    printable: Token,
+}
+
+#[test]
+fn ascii_default_methods() {
+    util::check_ok(
+        &[Tokens {
+            extra: Token(b"abc".to_vec()),
+            printable: Token(b"abc".to_vec()),
+        }],
+        &(),
+    );
+    let value = Tokens {
+        extra: Token(vec![0xff]),
+        printable: Token(vec![0xff]),
+    };
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    assert_eq!(
+        report.to_string(),
+        "extra: not ascii, found disallowed characters ['\u{fffd}']\n\
+         printable: not printable ascii, found '\u{fffd}' at position 0\n"
+    );
+}
@@ -0,0 +1,129 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(at_least_one(email, phone))]
+struct Contact<'a> {
+    #[garde(email)]
+    email: Option<&'a str>,
+    #[garde(skip)]
+    phone: Option<&'a str>,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(at_least_one(first, last, path = "name"))]
+#[garde(at_least_one(age, email))]
+struct Person<'a> {
+    #[garde(skip)]
+    first: &'a str,
+    #[garde(skip)]
+    last: String,
+    #[garde(skip)]
+    age: Option<u8>,
+    #[garde(skip)]
+    email: Option<String>,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(at_least_one(r#type, kind))]
+struct Raw<'a> {
+    #[garde(inner(length(min = 3)))]
+    r#type: Option<&'a str>,
+    #[garde(skip)]
+    kind: Option<&'a str>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Parent<'a> {
+    #[garde(dive)]
+    contact: Contact<'a>,
+}
+
+#[test]
+fn at_least_one_valid() {
+    util::check_ok(
+        &[
+            Contact {
+                email: Some("test@mail.com"),
+                phone: None,
+            },
+            Contact {
+                email: None,
+                phone: Some("+14155552671"),
+            },
+            Contact {
+                email: Some("test@mail.com"),
+                phone: Some("+14155552671"),
+            },
+        ],
+        &(),
+    );
+    util::check_ok(
+        &[Person {
+            first: "",
+            last: "Doe".into(),
+            age: Some(0),
+            email: None,
+        }],
+        &(),
+    );
+}
+
+#[test]
+fn at_least_one_invalid() {
+    util::check_fail!(
+        &[
+            Contact {
+                email: None,
+                phone: None,
+            },
+            Contact {
+                email: Some(""),
+                phone: Some("  "),
+            },
+            Contact {
+                email: Some("invalid"),
+                phone: None,
+            },
+        ],
+        &()
+    );
+    util::check_fail!(
+        &[Person {
+            first: " ",
+            last: String::new(),
+            age: None,
+            email: Some(String::new()),
+        }],
+        &()
+    );
+}
+
+#[test]
+fn at_least_one_nested_invalid() {
+    util::check_fail!(
+        &[Parent {
+            contact: Contact {
+                email: None,
+                phone: None,
+            }
+        }],
+        &()
+    )
+}
+
+#[test]
+fn at_least_one_raw_ident_invalid() {
+    util::check_fail!(
+        &[
+            Raw {
+                r#type: None,
+                kind: None,
+            },
+            Raw {
+                r#type: Some("a"),
+                kind: None,
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "base64")]
+
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(base64(decoded_len = 32))]
+    key: &'a str,
+    #[garde(base64)]
+    data: Option<&'a str>,
+
+    #[garde(inner(base64(decoded_len = 3)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn base64_valid() {
+    util::check_ok(
+        &[
+            Test {
+                key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=",
+                data: Some("aGVsbG8gd29ybGQ="),
+                inner: &["YWJj"],
+            },
+            Test {
+                key: "q83vASNFZ4mrze8BI0VniavN7wEjRWeJq83vASNFZ4k=",
+                data: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn base64_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHg==",
+                data: Some("not base64!"),
+                inner: &["YWJjZA=="],
+            },
+            Test {
+                key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8",
+                data: Some("aGVsbG8gd29ybGQ"),
+                inner: &["YW Jj"],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn base64_error_codes() {
+    let value = Test {
+        key: "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHg==",
+        data: Some("not base64!"),
+        inner: &[],
+    };
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("key".to_string(), Some("base64.length")),
+            ("data".to_string(), Some("base64.invalid")),
+        ]
+    );
+}
@@ -0,0 +1,108 @@
+use std::borrow::Cow;
+
+use super::util;
+
+#[derive(Debug, serde::Deserialize, garde::Validate)]
+struct Borrowed<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(ascii, prefix("tag:"))]
+    tag: &'a str,
+    #[serde(borrow)]
+    #[garde(length(max = 8))]
+    nickname: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    #[garde(inner(alphanumeric))]
+    aliases: Vec<&'a str>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Nested<'a, 'b: 'a> {
+    #[garde(dive)]
+    inner: &'a Borrowed<'b>,
+    #[garde(length(min = 1))]
+    comment: &'b str,
+}
+
+#[test]
+fn borrowed_from_json_valid() {
+    let json = r#"{"name": "garde", "tag": "tag:rust", "nickname": "g", "aliases": ["a", "b"]}"#;
+    let borrowed: Borrowed<'_> = serde_json::from_str(json).unwrap();
+    // The data is borrowed from `json`, not copied.
+    assert!(json.contains(borrowed.name));
+    util::check_ok(&[borrowed], &())
+}
+
+#[test]
+fn borrowed_from_json_invalid() {
+    let json = r#"{"name": "", "tag": "rust", "nickname": "too long to be a nickname", "aliases": ["😂"]}"#;
+    let borrowed: Borrowed<'_> = serde_json::from_str(json).unwrap();
+    util::check_fail!(&[borrowed], &())
+}
+
+#[test]
+fn borrowed_nested_lifetimes() {
+    let borrowed = Borrowed {
+        name: "",
+        tag: "tag:rust",
+        nickname: None,
+        aliases: vec![],
+    };
+    util::check_fail!(
+        &[Nested {
+            inner: &borrowed,
+            comment: "",
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, Clone, garde::Validate)]
+struct Sub<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Subs<'a> {
+    #[garde(length(max = 2), dive)]
+    items: Cow<'a, [Sub<'a>]>,
+    #[garde(inner(length(min = 1)))]
+    names: Cow<'a, [&'a str]>,
+}
+
+#[test]
+fn borrowed_cow_slice_valid() {
+    let items = [Sub { name: "a" }, Sub { name: "b" }];
+    util::check_ok(
+        &[
+            Subs {
+                items: Cow::Borrowed(&items),
+                names: Cow::Borrowed(&["a"]),
+            },
+            Subs {
+                items: Cow::Owned(items.to_vec()),
+                names: Cow::Owned(vec![]),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn borrowed_cow_slice_invalid() {
+    let items = [Sub { name: "a" }, Sub { name: "" }, Sub { name: "c" }];
+    util::check_fail!(
+        &[
+            Subs {
+                items: Cow::Borrowed(&items),
+                names: Cow::Borrowed(&["a", ""]),
+            },
+            Subs {
+                items: Cow::Owned(vec![Sub { name: "" }]),
+                names: Cow::Owned(vec![""]),
+            },
+        ],
+        &()
+    )
+}
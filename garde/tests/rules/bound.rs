@@ -0,0 +1,74 @@
+use std::fmt::Debug;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(bound = "T: garde::Validate<Context = ()>")]
+struct Page<T> {
+    #[garde(dive, length(max = 2))]
+    items: Vec<T>,
+}
+
+fn is_nonzero<T: Default + PartialEq + Debug>(value: &T, _: &()) -> garde::Result {
+    if *value == T::default() {
+        return Err(garde::Error::new(format!("{value:?} is a default value")));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(bound = "T: Default + PartialEq + Debug, U: garde::Validate<Context = ()>")]
+struct Pair<T, U> {
+    #[garde(custom(is_nonzero))]
+    value: T,
+    #[garde(dive)]
+    nested: U,
+}
+
+#[test]
+fn bound_valid() {
+    util::check_ok(
+        &[Page {
+            items: vec![Inner { field: "a" }, Inner { field: "b" }],
+        }],
+        &(),
+    );
+    util::check_ok(
+        &[Pair {
+            value: 1,
+            nested: Inner { field: "a" },
+        }],
+        &(),
+    );
+}
+
+#[test]
+fn bound_invalid() {
+    util::check_fail!(
+        &[Page {
+            items: vec![
+                Inner { field: "" },
+                Inner { field: "b" },
+                Inner { field: "c" }
+            ],
+        }],
+        &()
+    );
+}
+
+#[test]
+fn bound_multiple_invalid() {
+    util::check_fail!(
+        &[Pair {
+            value: 0,
+            nested: Inner { field: "" },
+        }],
+        &()
+    );
+}
@@ -0,0 +1,49 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(checksum(algo = "mod10"))]
+    mod10: &'a str,
+    #[garde(checksum(algo = "mod11"))]
+    mod11: &'a str,
+
+    #[garde(inner(checksum(algo = "mod10")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn checksum_valid() {
+    util::check_ok(
+        &[
+            Test {
+                mod10: "79927398713",
+                mod11: "080442957X",
+                inner: &["79927398713"],
+            },
+            Test {
+                mod10: "4539571147647251",
+                mod11: "0306406152",
+                inner: &["4539571147647251"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn checksum_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                mod10: "79927398710",
+                mod11: "0306406153",
+                inner: &["79927398710"],
+            },
+            Test {
+                mod10: "7992-7398-713",
+                mod11: "X",
+                inner: &["", "7"],
+            },
+        ],
+        &()
+    )
+}
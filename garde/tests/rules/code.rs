@@ -0,0 +1,94 @@
+use garde::Validate;
+
+fn codes<T: Validate<Context = ()>>(value: &T) -> Vec<(String, Option<String>)> {
+    value
+        .validate(&())
+        .unwrap_err()
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code().map(String::from)))
+        .collect()
+}
+
+#[derive(Debug, garde::Validate)]
+struct Plain<'a> {
+    #[garde(length(min = 1))]
+    email: &'a str,
+    #[garde(range(max = 10))]
+    age: u8,
+}
+
+#[test]
+fn builtin_codes() {
+    let value = Plain { email: "", age: 20 };
+    assert_eq!(
+        codes(&value),
+        [
+            ("email".into(), Some("length.min".into())),
+            ("age".into(), Some("range.max".into())),
+        ]
+    );
+}
+
+fn custom_no_code(_: &str, _: &()) -> garde::Result {
+    Err(garde::Error::new("custom"))
+}
+
+fn custom_with_code(_: &str, _: &()) -> garde::Result {
+    Err(garde::Error::new("custom").with_code("custom.failed"))
+}
+
+fn check_self(_: &User, _: &()) -> garde::Result {
+    Err(garde::Error::new("invalid user").with_code("invalid"))
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(code_prefix = "user")]
+#[garde(custom(check_self))]
+struct User<'a> {
+    #[garde(length(min = 3), contains("@"))]
+    email: &'a str,
+    #[garde(length(min = 3), code("user.name.too_short"))]
+    name: &'a str,
+    #[garde(inner(length(min = 1)))]
+    tags: Vec<&'a str>,
+    #[garde(custom(custom_no_code), custom(custom_with_code))]
+    bio: &'a str,
+}
+
+#[test]
+fn code_prefix() {
+    let value = User {
+        email: "a",
+        name: "b",
+        tags: vec![""],
+        bio: "",
+    };
+    assert_eq!(
+        codes(&value),
+        [
+            ("email".into(), Some("user.email.length.min".into())),
+            ("email".into(), Some("user.email.contains".into())),
+            ("name".into(), Some("user.name.too_short".into())),
+            ("tags[0]".into(), Some("user.tags.length.min".into())),
+            ("bio".into(), None),
+            ("bio".into(), Some("user.bio.custom.failed".into())),
+            ("".into(), Some("user.invalid".into())),
+        ]
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(code_prefix = "pair")]
+struct Pair(#[garde(ascii)] String, #[garde(required)] Option<u8>);
+
+#[test]
+fn code_prefix_tuple() {
+    let value = Pair("😂".into(), None);
+    assert_eq!(
+        codes(&value),
+        [
+            ("[0]".into(), Some("pair.0.ascii".into())),
+            ("[1]".into(), Some("pair.1.required".into())),
+        ]
+    );
+}
@@ -0,0 +1,54 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(custom(check_range))]
+#[garde(custom(|v: &Range, _: &()| if v.min == 42 { Err(garde::Error::new("not the answer")) } else { Ok(()) }))]
+struct Range {
+    #[garde(range(max = 100))]
+    min: u32,
+    #[garde(range(max = 100))]
+    max: u32,
+}
+
+fn check_range(v: &Range, _: &()) -> garde::Result {
+    if v.min > v.max {
+        return Err(garde::Error::new("`min` is greater than `max`"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+struct Parent {
+    #[garde(dive)]
+    range: Range,
+}
+
+#[test]
+fn container_valid() {
+    util::check_ok(
+        &[Range { min: 0, max: 10 }, Range { min: 10, max: 10 }],
+        &(),
+    )
+}
+
+#[test]
+fn container_invalid() {
+    util::check_fail!(
+        &[
+            Range { min: 10, max: 0 },
+            Range { min: 42, max: 0 },
+            Range { min: 200, max: 101 },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn container_nested_invalid() {
+    util::check_fail!(
+        &[Parent {
+            range: Range { min: 10, max: 0 }
+        }],
+        &()
+    )
+}
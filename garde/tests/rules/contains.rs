@@ -42,3 +42,43 @@ fn contains_invalid() {
         &()
     )
 }
+
+const NEEDLES: &[&str] = &["foo", "bar"];
+
+#[derive(Debug, garde::Validate)]
+struct Multi<'a> {
+    #[garde(contains(any = ["foo", "bar"]))]
+    any: &'a str,
+    #[garde(contains(any = NEEDLES))]
+    any_path: &'a str,
+    #[garde(contains(all = ["foo", "foobar"]))]
+    all: &'a str,
+    #[garde(inner(contains(any = ["foo", "bar"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn multi_contains_valid() {
+    util::check_ok(
+        &[Multi {
+            any: "_bar_",
+            any_path: "_bar_",
+            all: "foobar",
+            inner: &["_bar_", "foo"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn multi_contains_invalid() {
+    util::check_fail!(
+        &[Multi {
+            any: "baz",
+            any_path: "baz",
+            all: "foo",
+            inner: &["baz"],
+        }],
+        &()
+    )
+}
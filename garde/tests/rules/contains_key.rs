@@ -0,0 +1,67 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::util;
+
+const KEY: &str = "version";
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(contains_key("version"))]
+    field: HashMap<String, String>,
+
+    #[garde(contains_key(KEY))]
+    field_path: BTreeMap<String, String>,
+
+    #[garde(contains_value("admin"))]
+    role: HashMap<String, String>,
+
+    #[garde(contains_key(&1))]
+    numbers: BTreeMap<u32, u32>,
+
+    #[garde(contains_key("version"))]
+    optional: Option<HashMap<String, String>>,
+}
+
+fn map<M: FromIterator<(String, String)>>(entries: &[(&str, &str)]) -> M {
+    entries
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn contains_key_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: map(&[("version", "1")]),
+                field_path: map(&[("version", "1"), ("name", "garde")]),
+                role: map(&[("alice", "admin")]),
+                numbers: BTreeMap::from([(1, 0)]),
+                optional: None,
+            },
+            Test {
+                field: map(&[("version", "1")]),
+                field_path: map(&[("version", "1")]),
+                role: map(&[("alice", "user"), ("bob", "admin")]),
+                numbers: BTreeMap::from([(0, 1), (1, 0)]),
+                optional: Some(map(&[("version", "2")])),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn contains_key_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: map(&[("name", "garde")]),
+            field_path: map(&[]),
+            role: map(&[("admin", "user")]),
+            numbers: BTreeMap::from([(0, 1)]),
+            optional: Some(map(&[])),
+        }],
+        &()
+    )
+}
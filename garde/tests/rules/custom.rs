@@ -107,3 +107,274 @@ fn multi_custom_invalid() {
         &ctx
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Versioned<'a> {
+    #[garde(length(min = 1))]
+    a_name: &'a str,
+    #[garde(custom(supported_version))]
+    b_version: u32,
+    #[garde(length(min = 1))]
+    c_body: &'a str,
+    #[garde(inner(custom(supported_version)))]
+    d_versions: &'a [u32],
+}
+
+#[derive(Debug, garde::Validate)]
+struct Parent<'a> {
+    #[garde(dive)]
+    a_child: Versioned<'a>,
+    #[garde(length(min = 1))]
+    b_sibling: &'a str,
+}
+
+fn supported_version(value: &u32, _: &()) -> garde::rules::custom::Flow {
+    use garde::rules::custom::Flow;
+    match value {
+        1 => Flow::Ok,
+        2 => Flow::Err(garde::Error::new("deprecated version")),
+        _ => Flow::Abort(Some(garde::Error::new("unsupported version"))),
+    }
+}
+
+#[test]
+fn abort_valid() {
+    util::check_ok(
+        &[Versioned {
+            a_name: "a",
+            b_version: 1,
+            c_body: "a",
+            d_versions: &[1],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn abort_invalid() {
+    util::check_fail!(
+        &[
+            // `b_version` aborts, so `c_body` and `d_versions` are not validated,
+            // but the error for `a_name` is kept
+            Versioned {
+                a_name: "",
+                b_version: 3,
+                c_body: "",
+                d_versions: &[3],
+            },
+            // `Flow::Err` does not abort
+            Versioned {
+                a_name: "",
+                b_version: 2,
+                c_body: "",
+                d_versions: &[2, 3, 2],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn abort_nested_invalid() {
+    util::check_fail!(
+        &[Parent {
+            a_child: Versioned {
+                a_name: "a",
+                b_version: 3,
+                c_body: "",
+                d_versions: &[],
+            },
+            b_sibling: "",
+        }],
+        &()
+    )
+}
+
+fn not_reserved(value: &str, _: &()) -> garde::rules::custom::Flow {
+    use garde::rules::custom::Flow;
+    match value {
+        "ROOT" => Flow::Skip(Some(garde::Error::new("reserved username"))),
+        "" => Flow::Skip(None),
+        _ => Flow::Ok,
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Account<'a> {
+    #[garde(custom(not_reserved), pattern(r"^[a-z]+$"), length(min = 2))]
+    a_username: &'a str,
+    #[garde(length(min = 1))]
+    b_email: &'a str,
+    #[garde(inner(custom(not_reserved), pattern(r"^[a-z]+$")))]
+    c_aliases: &'a [&'a str],
+}
+
+#[test]
+fn skip_valid() {
+    util::check_ok(
+        &[Account {
+            a_username: "root",
+            b_email: "a",
+            c_aliases: &["admin"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn skip_invalid() {
+    util::check_fail!(
+        &[
+            // `a_username` skips its `pattern` and `length` rules,
+            // but `b_email` and each item of `c_aliases` are still validated
+            Account {
+                a_username: "ROOT",
+                b_email: "",
+                c_aliases: &["ROOT", "A", ""],
+            },
+            // skipping without an error
+            Account {
+                a_username: "",
+                b_email: "",
+                c_aliases: &[],
+            },
+            // no skip, the other rules run as normal
+            Account {
+                a_username: "A",
+                b_email: "a",
+                c_aliases: &[],
+            },
+        ],
+        &()
+    )
+}
+
+const MAX_POWER: u32 = 1000;
+
+#[derive(Debug, garde::Validate)]
+struct Power<'a> {
+    #[garde(custom(check_power, args(1000)))]
+    engine: u32,
+    #[garde(custom(check_power, args(MAX_POWER / 100)))]
+    bulb: u32,
+    #[garde(inner(custom(check_power_between, args(1, 5))))]
+    batteries: &'a [u32],
+}
+
+fn check_power(value: &u32, _: &(), max: u32) -> garde::Result {
+    if *value > max {
+        return Err(garde::Error::new(format!("more than {max}W")));
+    }
+    Ok(())
+}
+
+fn check_power_between(value: &u32, _: &(), min: u32, max: u32) -> garde::Result {
+    if !(min..=max).contains(value) {
+        return Err(garde::Error::new(format!("not between {min}W and {max}W")));
+    }
+    Ok(())
+}
+
+#[test]
+fn custom_args_valid() {
+    util::check_ok(
+        &[Power {
+            engine: 1000,
+            bulb: 10,
+            batteries: &[1, 5],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn custom_args_invalid() {
+    util::check_fail!(
+        &[Power {
+            engine: 1001,
+            bulb: 11,
+            batteries: &[0, 3, 6],
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Discount {
+    #[garde(skip)]
+    price: u32,
+    #[garde(custom(|value: &u32, _: &()| {
+        if *value == 0 {
+            return Err(garde::Error::new("must not be zero"));
+        }
+        Ok(())
+    }))]
+    #[garde(custom(|value: &u32, whole: &Self, _: &()| {
+        if *value > whole.price {
+            return Err(garde::Error::new(format!("greater than the price of {}", whole.price)));
+        }
+        Ok(())
+    }))]
+    #[garde(custom(not_above_price, args(self)))]
+    #[garde(custom(not_below_half, with_self))]
+    amount: u32,
+    #[garde(inner(custom(|value: &u32, whole: &Self, _: &(), max: u32| {
+        if value * whole.amount > max {
+            return Err(garde::Error::new(format!("total discount exceeds {max}")));
+        }
+        Ok(())
+    }, args(100))))]
+    repeat: Option<u32>,
+}
+
+fn not_above_price(value: &u32, _: &(), whole: &Discount) -> garde::Result {
+    if *value > whole.price {
+        return Err(garde::Error::new("exceeds the price"));
+    }
+    Ok(())
+}
+
+fn not_below_half(value: &u32, whole: &Discount, _: &()) -> garde::Result {
+    if *value * 2 < whole.price {
+        return Err(garde::Error::new("less than half the price"));
+    }
+    Ok(())
+}
+
+#[test]
+fn custom_with_self_valid() {
+    util::check_ok(
+        &[
+            Discount {
+                price: 10,
+                amount: 10,
+                repeat: Some(10),
+            },
+            Discount {
+                price: 10,
+                amount: 5,
+                repeat: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn custom_with_self_invalid() {
+    util::check_fail!(
+        &[
+            Discount {
+                price: 10,
+                amount: 0,
+                repeat: Some(1),
+            },
+            Discount {
+                price: 10,
+                amount: 11,
+                repeat: Some(11),
+            },
+        ],
+        &()
+    )
+}
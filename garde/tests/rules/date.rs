@@ -0,0 +1,156 @@
+#![cfg(any(feature = "chrono", feature = "time"))]
+
+#[cfg(feature = "chrono")]
+mod with_chrono {
+    use chrono::{DateTime, NaiveDate, Utc};
+
+    use crate::rules::util;
+
+    struct Clock {
+        today: NaiveDate,
+        now: DateTime<Utc>,
+    }
+
+    fn clock() -> Clock {
+        Clock {
+            today: NaiveDate::from_ymd_opt(2024, 6, 15).unwrap(),
+            now: "2024-06-15T12:00:00Z".parse().unwrap(),
+        }
+    }
+
+    fn date(v: &str) -> NaiveDate {
+        v.parse().unwrap()
+    }
+
+    #[derive(Debug, garde::Validate)]
+    #[garde(context(Clock as ctx))]
+    struct Test {
+        #[garde(date(past, now = ctx.today))]
+        birthday: NaiveDate,
+        #[garde(date(future, now = ctx.today))]
+        expires: Option<NaiveDate>,
+        #[garde(date(future, now = ctx.now))]
+        starts_at: DateTime<Utc>,
+    }
+
+    #[test]
+    fn date_chrono_valid() {
+        util::check_ok(
+            &[
+                Test {
+                    birthday: date("2024-06-14"),
+                    expires: Some(date("2024-06-16")),
+                    starts_at: "2024-06-15T12:00:01Z".parse().unwrap(),
+                },
+                Test {
+                    birthday: date("1990-01-01"),
+                    expires: None,
+                    starts_at: "2030-01-01T00:00:00+02:00".parse().unwrap(),
+                },
+            ],
+            &clock(),
+        )
+    }
+
+    #[test]
+    fn date_chrono_invalid() {
+        // The current date is neither in the past nor in the future.
+        util::check_fail!(
+            &[
+                Test {
+                    birthday: date("2024-06-15"),
+                    expires: Some(date("2024-06-15")),
+                    starts_at: "2024-06-15T12:00:00Z".parse().unwrap(),
+                },
+                Test {
+                    birthday: date("2030-01-01"),
+                    expires: Some(date("2000-01-01")),
+                    starts_at: "2024-06-15T13:00:00+02:00".parse().unwrap(),
+                },
+            ],
+            &clock()
+        )
+    }
+
+    #[derive(Debug, garde::Validate)]
+    struct SystemClock {
+        #[garde(date(past))]
+        birthday: NaiveDate,
+        #[garde(date(future))]
+        expires: NaiveDate,
+    }
+
+    #[test]
+    fn date_chrono_system_clock() {
+        use garde::Validate;
+
+        let value = SystemClock {
+            birthday: date("1990-01-01"),
+            expires: date("9999-01-01"),
+        };
+        assert!(value.validate(&()).is_ok());
+        let value = SystemClock {
+            birthday: date("9999-01-01"),
+            expires: date("1990-01-01"),
+        };
+        assert_eq!(value.validate(&()).unwrap_err().iter().count(), 2);
+    }
+}
+
+#[cfg(feature = "time")]
+mod with_time {
+    use time::{Date, Month, OffsetDateTime};
+
+    use crate::rules::util;
+
+    struct Clock {
+        today: Date,
+        now: OffsetDateTime,
+    }
+
+    fn date(day: u8) -> Date {
+        Date::from_calendar_date(2024, Month::June, day).unwrap()
+    }
+
+    fn datetime(day: u8, second: u8) -> OffsetDateTime {
+        date(day).with_hms(12, 0, second).unwrap().assume_utc()
+    }
+
+    fn clock() -> Clock {
+        Clock {
+            today: date(15),
+            now: datetime(15, 0),
+        }
+    }
+
+    #[derive(Debug, garde::Validate)]
+    #[garde(context(Clock as ctx))]
+    struct Test {
+        #[garde(date(past, now = ctx.today))]
+        birthday: Date,
+        #[garde(date(future, now = ctx.now))]
+        expires: Option<OffsetDateTime>,
+    }
+
+    #[test]
+    fn date_time_valid() {
+        util::check_ok(
+            &[Test {
+                birthday: date(14),
+                expires: Some(datetime(15, 1)),
+            }],
+            &clock(),
+        )
+    }
+
+    #[test]
+    fn date_time_invalid() {
+        util::check_fail!(
+            &[Test {
+                birthday: date(15),
+                expires: Some(datetime(15, 0)),
+            }],
+            &clock()
+        )
+    }
+}
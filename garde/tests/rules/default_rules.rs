@@ -0,0 +1,49 @@
+use super::util;
+
+#[allow(dead_code)]
+#[derive(Debug, garde::Validate)]
+#[garde(default_rules(strings(ascii, length(max = 8)), numbers(range(max = 10))))]
+struct Test<'a> {
+    #[garde(alphanumeric)]
+    name: &'a str,
+    /// No attribute is needed, the defaults are enough.
+    city: String,
+    /// `length` replaces the default `length`, `ascii` is still applied.
+    #[garde(length(min = 2))]
+    long: &'a str,
+    #[garde(skip_defaults, length(min = 1))]
+    unicode: &'a str,
+    #[garde(skip)]
+    skipped: &'a str,
+    optional: Option<u32>,
+}
+
+#[test]
+fn default_rules_valid() {
+    util::check_ok(
+        &[Test {
+            name: "abc",
+            city: "Prague".into(),
+            long: "a very long value",
+            unicode: "ünïcödé everywhere",
+            skipped: "ü, 123456789",
+            optional: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn default_rules_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: "abcdefghij",
+            city: "Zürich".into(),
+            long: "ü",
+            unicode: "",
+            skipped: "",
+            optional: Some(11),
+        }],
+        &()
+    )
+}
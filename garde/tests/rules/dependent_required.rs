@@ -0,0 +1,91 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(dependent_required(if = "self.has_company", then = company_name))]
+#[garde(dependent_required(if = self.country == "US", then = state))]
+struct Test {
+    #[garde(skip)]
+    has_company: bool,
+    #[garde(length(max = 10))]
+    company_name: Option<String>,
+    #[garde(skip)]
+    country: &'static str,
+    #[garde(skip)]
+    state: String,
+}
+
+#[test]
+fn dependent_required_valid() {
+    util::check_ok(
+        &[
+            Test {
+                has_company: true,
+                company_name: Some("Acme".into()),
+                country: "US",
+                state: "CA".into(),
+            },
+            Test {
+                has_company: false,
+                company_name: None,
+                country: "FR",
+                state: "".into(),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn dependent_required_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                has_company: true,
+                company_name: None,
+                country: "US",
+                state: " ".into(),
+            },
+            Test {
+                has_company: true,
+                company_name: Some("".into()),
+                country: "FR",
+                state: "".into(),
+            }
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(bool as required))]
+#[garde(dependent_required(if = *required, then = value))]
+struct WithContext {
+    #[garde(skip)]
+    value: Option<u32>,
+}
+
+#[test]
+fn dependent_required_context() {
+    util::check_ok(&[WithContext { value: None }], &false);
+    util::check_fail!(&[WithContext { value: None }], &true);
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(dependent_required(if = self.r#type.is_some(), then = r#ref))]
+struct Raw<'a> {
+    #[garde(skip)]
+    r#type: Option<&'a str>,
+    #[garde(skip)]
+    r#ref: Option<&'a str>,
+}
+
+#[test]
+fn dependent_required_raw_ident_invalid() {
+    util::check_fail!(
+        &[Raw {
+            r#type: Some("link"),
+            r#ref: None,
+        }],
+        &()
+    );
+}
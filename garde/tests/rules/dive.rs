@@ -68,3 +68,115 @@ fn email_invalid() {
         &()
     )
 }
+
+#[test]
+fn by_ref_valid() {
+    let mut inner = Inner { field: "asdf" };
+    util::check_ok(&[&inner], &());
+    util::check_ok(&[&mut inner], &());
+}
+
+#[test]
+fn by_ref_invalid() {
+    let inner = Inner { field: "" };
+    util::check_fail!(&[&inner], &())
+}
+
+#[derive(Debug, garde::Validate)]
+struct Optional<'a> {
+    /// `None` is skipped, `Some` is validated
+    #[garde(dive)]
+    dive: Option<Inner<'a>>,
+    /// `None` is an error, `Some` is validated
+    #[garde(dive(required))]
+    dive_required: Option<Inner<'a>>,
+    /// `None` is an error, `Some` is not validated
+    #[garde(required)]
+    required: Option<Inner<'a>>,
+}
+
+#[test]
+fn optional_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[
+            Optional {
+                dive: Some(inner),
+                dive_required: Some(inner),
+                required: Some(inner),
+            },
+            Optional {
+                dive: None,
+                dive_required: Some(inner),
+                required: Some(Inner { field: "" }),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn optional_invalid() {
+    let inner = Inner { field: "" };
+    util::check_fail!(
+        &[
+            Optional {
+                dive: None,
+                dive_required: None,
+                required: None,
+            },
+            Optional {
+                dive: Some(inner),
+                dive_required: Some(inner),
+                required: Some(inner),
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Traced<'a> {
+    #[garde(dive)]
+    optional: Optional<'a>,
+    #[garde(dive)]
+    items: Vec<Option<Inner<'a>>>,
+}
+
+#[test]
+fn trace_skipped_none() {
+    use garde::error::Severity;
+    use garde::{Path, Report, Validate};
+
+    let inner = Inner { field: "asdf" };
+    let value = Traced {
+        optional: Optional {
+            dive: None,
+            dive_required: Some(inner),
+            required: Some(inner),
+        },
+        items: vec![Some(inner), None],
+    };
+
+    let mut report = Report::new();
+    value.validate_into(&(), &mut Path::empty, &mut report);
+    assert!(report.is_empty());
+
+    let mut report = Report::new().with_trace();
+    value.validate_into(&(), &mut Path::empty, &mut report);
+    let notes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code(), error.severity()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        notes,
+        [
+            (
+                "optional.dive".to_string(),
+                Some("dive.none"),
+                Severity::Info
+            ),
+            ("items[1]".to_string(), Some("dive.none"), Severity::Info),
+        ]
+    );
+}
@@ -33,3 +33,19 @@ fn dive_with_rules_invalid() {
         &(),
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Bounded<'a> {
+    #[garde(length(max = 1), dive)]
+    field: Vec<Inner<'a>>,
+}
+
+#[test]
+fn dive_with_rules_container_first() {
+    util::check_fail!(
+        &[Bounded {
+            field: vec![Inner { field: "" }, Inner { field: "" }]
+        }],
+        &(),
+    )
+}
@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(e164)]
+    field: &'a str,
+    #[garde(inner(e164))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn e164_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "+14155552671",
+                inner: &["+420123456789"],
+            },
+            Test {
+                field: "+12",
+                inner: &["+999999999999999"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn e164_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "14155552671",
+                inner: &["+0123456789"],
+            },
+            Test {
+                field: "+1",
+                inner: &["+1234567890123456"],
+            },
+            Test {
+                field: "+1 415 555 2671",
+                inner: &["+1-415-555-2671"],
+            },
+            Test {
+                field: "",
+                inner: &["+"],
+            },
+            Test {
+                field: "+١٢٣",
+                inner: &["+12a"],
+            },
+        ],
+        &()
+    )
+}
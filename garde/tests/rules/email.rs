@@ -28,3 +28,96 @@ fn email_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct MaxLen<'a> {
+    #[garde(email)]
+    default: &'a str,
+    #[garde(email(max_len = 16), inner(email(max_len = 16)))]
+    short: Option<&'a str>,
+}
+
+#[test]
+fn email_max_len_valid() {
+    util::check_ok(
+        &[MaxLen {
+            default: "email@here.com",
+            short: Some("email@here.com"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn email_max_len_invalid() {
+    util::check_fail!(
+        &[MaxLen {
+            default: "email@here.com",
+            short: Some("longer.email@here.com"),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn email_oversized_input_fails_before_parsing() {
+    let huge = format!("{}@here.com", "a".repeat(10_000_000));
+    let value = MaxLen {
+        default: &huge,
+        short: None,
+    };
+    let start = std::time::Instant::now();
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    let (path, error) = report.iter().next().unwrap();
+    assert_eq!(path.to_string(), "default");
+    assert_eq!(error.code(), Some("email.length"));
+    assert_eq!(error.message(), "email is longer than 320 bytes");
+}
+
+/// An email address which was already parsed, so it only has to be checked against `max_len`.
+#[derive(Debug)]
+struct Address {
+    user: &'static str,
+    domain: &'static str,
+}
+
+impl garde::rules::email::Email for Address {
+    type Error = std::convert::Infallible;
+
+    fn validate_email(&self) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn email_len(&self) -> Option<usize> {
+        Some(self.user.len() + 1 + self.domain.len())
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Parsed {
+    #[garde(email(max_len = 16))]
+    address: Address,
+}
+
+#[test]
+fn email_parsed_type() {
+    util::check_ok(
+        &[Parsed {
+            address: Address {
+                user: "user",
+                domain: "example.com",
+            },
+        }],
+        &(),
+    );
+    util::check_fail!(
+        &[Parsed {
+            address: Address {
+                user: "user",
+                domain: "subdomain.example.com",
+            },
+        }],
+        &()
+    );
+}
@@ -0,0 +1,76 @@
+use super::util;
+
+#[derive(garde::Discriminants)]
+#[repr(i32)]
+#[allow(dead_code)]
+enum Color {
+    Red = 1,
+    Green,
+    // There is a gap between `Green` and `Blue`.
+    Blue = 5,
+    Black = -1,
+}
+
+#[derive(garde::Discriminants)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(enum_value(Color))]
+    color: i32,
+    #[garde(enum_value(Level))]
+    level: Option<u8>,
+    #[garde(inner(enum_value(Color)))]
+    inner: &'a [i32],
+}
+
+#[test]
+fn enum_value_valid() {
+    util::check_ok(
+        &[
+            Test {
+                color: 1,
+                level: None,
+                inner: &[2, 5, -1],
+            },
+            Test {
+                color: 5,
+                level: Some(1),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn enum_value_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                color: 3,
+                level: Some(2),
+                inner: &[0, 4],
+            },
+            Test {
+                color: 6,
+                level: Some(255),
+                inner: &[-2],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn enum_value_discriminants() {
+    use garde::rules::enum_value::Discriminants;
+
+    assert_eq!(Color::DISCRIMINANTS, [1, 2, 5, -1]);
+    assert_eq!(Level::DISCRIMINANTS, [0, 1]);
+}
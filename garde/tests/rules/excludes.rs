@@ -0,0 +1,69 @@
+use super::util;
+
+const RESERVED: &[&str] = &["admin", "root"];
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(excludes(["admin", "root"]))]
+    field: &'a str,
+
+    #[garde(excludes(RESERVED, ignore_case))]
+    field_ignore_case: &'a str,
+
+    #[garde(excludes(RESERVED))]
+    list: Vec<&'a str>,
+
+    #[garde(excludes(RESERVED, ignore_case))]
+    list_ignore_case: &'a [String],
+
+    #[garde(inner(excludes(RESERVED)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn excludes_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "alice",
+                field_ignore_case: "alice",
+                list: vec![],
+                list_ignore_case: &[],
+                inner: &[],
+            },
+            Test {
+                // Case-sensitive by default.
+                field: "ADMIN",
+                field_ignore_case: "bob",
+                // Items are compared as a whole.
+                list: vec!["administrator", "rooted"],
+                list_ignore_case: &["user".to_string()],
+                inner: &["ROOT"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn excludes_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "superadmin",
+                field_ignore_case: "I am Root",
+                list: vec!["user", "root"],
+                list_ignore_case: &["Guest".to_string(), "ADMIN".to_string()],
+                inner: &["root"],
+            },
+            Test {
+                field: "root",
+                field_ignore_case: "ADMINISTRATOR",
+                list: vec!["root", "admin"],
+                list_ignore_case: &["Root".to_string()],
+                inner: &["alice", "xadminx"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,67 @@
+use super::util;
+
+const IMAGES: &[&str] = &[".jpg", ".png"];
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(extension(["jpg", "png"]))]
+    field: &'a str,
+
+    #[garde(extension(IMAGES))]
+    field_path: &'a str,
+
+    #[garde(extension(["tar.gz"]))]
+    archive: &'a str,
+
+    #[garde(inner(extension(["jpg", "png"])))]
+    inner: &'a [&'a str],
+
+    #[garde(not(extension(["exe"])))]
+    not: &'a str,
+}
+
+#[test]
+fn extension_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "photo.png",
+                field_path: "photo.jpg",
+                archive: "release.tar.gz",
+                inner: &["a.jpg", "b.png"],
+                not: "setup.msi",
+            },
+            Test {
+                field: "photo.PNG",
+                field_path: "https://example.com/photo.JpG",
+                archive: "release.TAR.GZ",
+                inner: &[],
+                not: "exe",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn extension_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "photo.gif",
+                field_path: "photo.png.txt",
+                archive: "release.gz",
+                inner: &["a.jpeg"],
+                not: "setup.EXE",
+            },
+            Test {
+                field: ".png",
+                field_path: "photopng",
+                archive: "",
+                inner: &["😂"],
+                not: "a.exe",
+            },
+        ],
+        &()
+    )
+}
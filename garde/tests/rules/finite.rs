@@ -0,0 +1,57 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(finite)]
+    field: f64,
+
+    #[garde(finite, range(min = -90.0, max = 90.0))]
+    latitude: f32,
+
+    #[garde(finite)]
+    optional: Option<f64>,
+
+    #[garde(inner(finite))]
+    inner: &'a [f64],
+}
+
+#[test]
+fn finite_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: 0.0,
+                latitude: 52.52,
+                optional: None,
+                inner: &[],
+            },
+            Test {
+                field: -1.5e300,
+                latitude: -90.0,
+                optional: Some(f64::MAX),
+                inner: &[f64::MIN_POSITIVE, 1.0],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn finite_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: f64::NAN,
+                latitude: f32::NAN,
+                optional: Some(f64::NAN),
+                inner: &[1.0, f64::NAN],
+            },
+            Test {
+                field: f64::INFINITY,
+                latitude: f32::NEG_INFINITY,
+                optional: Some(f64::NEG_INFINITY),
+                inner: &[f64::INFINITY],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,67 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    city: &'a str,
+    #[garde(length(min = 5))]
+    zip: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Flattened<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(flatten)]
+    address: Address<'a>,
+    #[garde(flatten)]
+    billing: Option<Address<'a>>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Outer<'a> {
+    #[garde(dive)]
+    inner: Flattened<'a>,
+}
+
+#[test]
+fn flatten_valid() {
+    util::check_ok(
+        &[Flattened {
+            name: "a",
+            address: Address {
+                city: "Prague",
+                zip: "11000",
+            },
+            billing: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn flatten_invalid() {
+    util::check_fail!(
+        &[Flattened {
+            name: "",
+            address: Address { city: "", zip: "1" },
+            // Errors of both flattened fields appear at the same paths.
+            billing: Some(Address { city: "", zip: "1" }),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn flatten_nested_invalid() {
+    util::check_fail!(
+        &[Outer {
+            inner: Flattened {
+                name: "a",
+                address: Address { city: "", zip: "1" },
+                billing: None,
+            },
+        }],
+        &()
+    )
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(fs_path(absolute))]
+    absolute: PathBuf,
+    #[garde(fs_path(relative))]
+    relative: &'a Path,
+    #[garde(fs_path(extension = ["toml", "yaml"]))]
+    extension: &'a Path,
+    #[garde(fs_path(absolute, extension = ["toml"]))]
+    both: Option<PathBuf>,
+    #[garde(inner(fs_path(relative)))]
+    inner: &'a [&'a Path],
+}
+
+#[test]
+fn fs_path_valid() {
+    util::check_ok(
+        &[
+            Test {
+                absolute: PathBuf::from("/etc/app"),
+                relative: Path::new("config/app.toml"),
+                extension: Path::new("app.yaml"),
+                both: Some(PathBuf::from("/etc/app.toml")),
+                inner: &[Path::new("a"), Path::new("./b")],
+            },
+            Test {
+                absolute: PathBuf::from("/"),
+                relative: Path::new(""),
+                extension: Path::new("/etc/app.toml"),
+                both: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn fs_path_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                absolute: PathBuf::from("etc/app"),
+                relative: Path::new("/config/app.toml"),
+                extension: Path::new("app.json"),
+                both: Some(PathBuf::from("/etc/app.yaml")),
+                inner: &[Path::new("/a")],
+            },
+            Test {
+                absolute: PathBuf::from("/etc/app"),
+                relative: Path::new("app"),
+                extension: Path::new("toml"),
+                both: Some(PathBuf::from("etc/app.toml")),
+                inner: &[],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,49 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(hex(decoded_len = 4))]
+    id: &'a str,
+    #[garde(hex)]
+    data: &'a str,
+
+    #[garde(inner(hex(decoded_len = 1)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn hex_valid() {
+    util::check_ok(
+        &[
+            Test {
+                id: "deadBEEF",
+                data: "",
+                inner: &["00", "ff"],
+            },
+            Test {
+                id: "01234567",
+                data: "89abcdef",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn hex_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                id: "deadbeef00",
+                data: "abc",
+                inner: &["0"],
+            },
+            Test {
+                id: "0xdeadbe",
+                data: "zz",
+                inner: &["0g"],
+            },
+        ],
+        &()
+    )
+}
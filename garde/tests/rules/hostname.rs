@@ -0,0 +1,52 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(hostname)]
+    field: &'a str,
+
+    #[garde(inner(hostname))]
+    inner: &'a [&'a str],
+}
+
+const LABEL_63: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+#[test]
+fn hostname_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "localhost",
+                inner: &["example.com", "API.Example.COM"],
+            },
+            Test {
+                field: "my-service.default.svc.cluster.local",
+                inner: &["1.2.3.4", "0a", LABEL_63],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn hostname_invalid() {
+    let label_64 = format!("{LABEL_63}a");
+    let too_long = [LABEL_63; 4].join(".");
+    util::check_fail!(
+        &[
+            Test {
+                field: "",
+                inner: &[&too_long]
+            },
+            Test {
+                field: "-example.com",
+                inner: &["example-.com", "exa_mple.com", "bücher.de"]
+            },
+            Test {
+                field: "example..com",
+                inner: &["example.com.", &label_64, "example com"]
+            }
+        ],
+        &()
+    )
+}
@@ -0,0 +1,43 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(imei)]
+    field: &'a str,
+
+    #[garde(inner(imei))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn imei_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "490154203237518",
+                inner: &["49-015420-323751-8"],
+            },
+            Test {
+                field: "356938035643809",
+                inner: &["356938035643809"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn imei_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "490154203237519",
+                inner: &["49015420323751"],
+            },
+            Test {
+                field: "49015420323751a",
+                inner: &["4901542032375180"],
+            },
+        ],
+        &()
+    )
+}
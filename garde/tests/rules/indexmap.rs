@@ -0,0 +1,71 @@
+#![cfg(feature = "indexmap")]
+
+use indexmap::{IndexMap, IndexSet};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(max = 3), dive)]
+    map: IndexMap<&'a str, Inner<'a>>,
+    #[garde(length(min = 1), inner(length(min = 1)), keys(length(max = 3)))]
+    strings: IndexMap<&'a str, &'a str>,
+    #[garde(length(max = 2), inner(length(min = 1)))]
+    set: IndexSet<&'a str>,
+}
+
+#[test]
+fn indexmap_valid() {
+    util::check_ok(
+        &[Test {
+            map: IndexMap::from([("a", Inner { field: "x" })]),
+            strings: IndexMap::from([("abc", "x")]),
+            set: IndexSet::from(["a", "b"]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn indexmap_invalid() {
+    util::check_fail!(
+        &[Test {
+            map: IndexMap::from([
+                ("z", Inner { field: "" }),
+                ("a", Inner { field: "" }),
+                ("m", Inner { field: "" }),
+                ("b", Inner { field: "" }),
+            ]),
+            strings: IndexMap::from([("long", ""), ("x", "")]),
+            set: IndexSet::from(["b", "", "a"]),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn indexmap_errors_follow_insertion_order() {
+    let value = Test {
+        map: IndexMap::from([
+            ("z", Inner { field: "" }),
+            ("a", Inner { field: "" }),
+            ("m", Inner { field: "" }),
+        ]),
+        strings: IndexMap::from([("k", "v")]),
+        set: IndexSet::new(),
+    };
+    for _ in 0..10 {
+        let report = garde::Validate::validate(&value, &()).unwrap_err();
+        let paths = report
+            .iter()
+            .map(|(path, _)| path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["map.z.field", "map.a.field", "map.m.field"]);
+    }
+}
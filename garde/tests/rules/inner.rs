@@ -119,3 +119,40 @@ fn alphanumeric_none_valid() {
         &(),
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Item {
+    #[garde(range(min = 1))]
+    value: u8,
+}
+
+#[derive(Debug, garde::Validate)]
+#[allow(dead_code)]
+struct InnerSkip {
+    #[garde(length(min = 1), inner(skip))]
+    items: Vec<Item>,
+    #[garde(inner(skip))]
+    only_skip: Vec<Item>,
+    #[garde(inner(inner(skip)))]
+    nested: Vec<Vec<Item>>,
+}
+
+#[test]
+fn inner_skip_validates_only_the_container() {
+    util::check_ok(
+        &[InnerSkip {
+            items: vec![Item { value: 0 }],
+            only_skip: vec![Item { value: 0 }],
+            nested: vec![vec![Item { value: 0 }]],
+        }],
+        &(),
+    );
+    util::check_fail!(
+        &[InnerSkip {
+            items: vec![],
+            only_skip: vec![Item { value: 0 }],
+            nested: vec![vec![Item { value: 0 }]],
+        }],
+        &(),
+    )
+}
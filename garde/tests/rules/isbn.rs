@@ -0,0 +1,63 @@
+use super::util;
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(isbn)]
+    field: &'a str,
+
+    #[garde(inner(isbn))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn isbn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "978-3-16-148410-0",
+                inner: &["9783161484100"],
+            },
+            Test {
+                field: "0-306-40615-2",
+                inner: &["0 8044 2957 X"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn isbn_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "978-3-16-148410-1",
+                inner: &["9783161484101"],
+            },
+            Test {
+                field: "978-3-16-14841",
+                inner: &["978-3-16-148410-X"],
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn isbn_13_error_codes() {
+    let value = Test {
+        field: "9783161484101",
+        inner: &["97831614841"],
+    };
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("field".to_string(), Some("isbn.checksum")),
+            ("inner[0]".to_string(), Some("isbn.length")),
+        ]
+    );
+}
@@ -0,0 +1,50 @@
+use std::collections::{BTreeMap, HashMap};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Value<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(keys(length(max = 8)), dive)]
+    dive: BTreeMap<&'a str, Value<'a>>,
+    #[garde(keys(length(max = 8)), inner(length(min = 1)))]
+    inner: BTreeMap<&'a str, &'a str>,
+    #[garde(keys(ascii))]
+    hash_map: HashMap<&'a str, &'a str>,
+    #[garde(inner(keys(length(max = 8))))]
+    nested: &'a [BTreeMap<&'a str, &'a str>],
+}
+
+#[test]
+fn keys_valid() {
+    util::check_ok(
+        &[Test {
+            dive: BTreeMap::from([("a", Value { field: "a" })]),
+            inner: BTreeMap::from([("a", "a")]),
+            hash_map: HashMap::from([("a", "a")]),
+            nested: &[BTreeMap::from([("a", "a")])],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn keys_invalid() {
+    util::check_fail!(
+        &[Test {
+            dive: BTreeMap::from([
+                ("too_long_key", Value { field: "a" }),
+                ("a", Value { field: "" }),
+            ]),
+            inner: BTreeMap::from([("too_long_key", "")]),
+            hash_map: HashMap::from([("😂", "a")]),
+            nested: &[BTreeMap::from([("too_long_key", "a")])],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,73 @@
+use garde::Validate;
+
+fn labels<T: Validate<Context = ()>>(value: &T) -> Vec<(String, Option<String>)> {
+    value
+        .validate(&())
+        .unwrap_err()
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.label().map(String::from)))
+        .collect()
+}
+
+fn custom_error(_: &str, _: &()) -> garde::Result {
+    Err(garde::Error::new("custom"))
+}
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(code_prefix = "user")]
+struct User<'a> {
+    #[garde(email, label = "Email address")]
+    email: &'a str,
+    #[garde(length(min = 3), custom(custom_error), label("Display name"))]
+    name: &'a str,
+    #[garde(inner(length(min = 1)), label = "Tags")]
+    tags: Vec<&'a str>,
+    #[garde(dive, label = "Nested")]
+    inner: Inner<'a>,
+    #[garde(length(min = 1))]
+    bio: &'a str,
+}
+
+#[test]
+fn label_is_attached_to_field_errors() {
+    let value = User {
+        email: "not an email",
+        name: "a",
+        tags: vec![""],
+        inner: Inner { value: "" },
+        bio: "",
+    };
+    assert_eq!(
+        labels(&value),
+        [
+            ("email".into(), Some("Email address".into())),
+            ("name".into(), Some("Display name".into())),
+            ("name".into(), Some("Display name".into())),
+            ("tags[0]".into(), Some("Tags".into())),
+            ("inner.value".into(), None),
+            ("bio".into(), None),
+        ]
+    );
+}
+
+#[test]
+fn label_does_not_replace_code() {
+    let value = User {
+        email: "not an email",
+        name: "abc",
+        tags: vec![],
+        inner: Inner { value: "a" },
+        bio: "a",
+    };
+    let report = value.validate(&()).unwrap_err();
+    let errors: Vec<_> = report.iter().collect();
+    assert_eq!(errors[0].0.to_string(), "email");
+    assert_eq!(errors[0].1.code(), Some("user.email.email"));
+    assert_eq!(errors[0].1.label(), Some("Email address"));
+}
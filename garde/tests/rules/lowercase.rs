@@ -0,0 +1,44 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(lowercase)]
+    field: &'a str,
+
+    #[garde(inner(lowercase))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn lowercase_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "username_01",
+                inner: &["username_01"],
+            },
+            Test {
+                field: "ßtraße ǆ ς",
+                inner: &[""],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn lowercase_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "UserName",
+                inner: &["UserName"]
+            },
+            Test {
+                field: "Straße",
+                inner: &["Straße"]
+            }
+        ],
+        &()
+    )
+}
@@ -0,0 +1,44 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(luhn)]
+    field: &'a str,
+
+    #[garde(inner(luhn))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn luhn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "79927398713",
+                inner: &["490154203237518", "18", "4111111111111111"],
+            },
+            Test {
+                field: "0000000000",
+                inner: &["059"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn luhn_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "79927398710",
+                inner: &["490154203237519", "4111111111111112"],
+            },
+            Test {
+                field: "7",
+                inner: &["7992-7398-713", ""],
+            },
+        ],
+        &()
+    )
+}
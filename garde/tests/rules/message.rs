@@ -0,0 +1,115 @@
+use garde::Validate;
+
+fn errors<T: Validate>(value: &T, ctx: &T::Context) -> Vec<(String, String, Option<String>)> {
+    value
+        .validate(ctx)
+        .unwrap_err()
+        .iter()
+        .map(|(path, error)| {
+            (
+                path.to_string(),
+                error.message().to_string(),
+                error.code().map(String::from),
+            )
+        })
+        .collect()
+}
+
+struct Limits {
+    reserved: &'static str,
+}
+
+fn reserved_message(value: &str, ctx: &Limits) -> String {
+    format!("`{value}` is reserved, `{}` may not be used", ctx.reserved)
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits as ctx))]
+struct Username<'a> {
+    #[garde(not(contains("admin")))]
+    default: &'a str,
+    #[garde(not(contains("admin")), message = "choose another name")]
+    message: &'a str,
+    #[garde(not(pattern("^root")), code("username.reserved"))]
+    code: &'a str,
+    #[garde(not(contains(ctx.reserved)), message(reserved_message))]
+    func: &'a str,
+    #[garde(inner(not(contains(" "))), message = "no spaces")]
+    tags: &'a [&'a str],
+}
+
+#[test]
+fn negated_default_code() {
+    let value = Username {
+        default: "admin1",
+        message: "user",
+        code: "user",
+        func: "user",
+        tags: &[],
+    };
+    assert_eq!(
+        errors(&value, &Limits { reserved: "system" }),
+        [(
+            "default".into(),
+            "must not contain \"admin\"".into(),
+            Some("not.contains".into())
+        )]
+    );
+}
+
+#[test]
+fn negated_message_and_code_override() {
+    let value = Username {
+        default: "user",
+        message: "the_admin",
+        code: "root2",
+        func: "system",
+        tags: &["a b", "c"],
+    };
+    assert_eq!(
+        errors(&value, &Limits { reserved: "system" }),
+        [
+            (
+                "message".into(),
+                "choose another name".into(),
+                Some("not.contains".into())
+            ),
+            (
+                "code".into(),
+                "must not match pattern /^root/".into(),
+                Some("username.reserved".into())
+            ),
+            (
+                "func".into(),
+                "`system` is reserved, `system` may not be used".into(),
+                Some("not.contains".into())
+            ),
+            (
+                "tags[0]".into(),
+                "no spaces".into(),
+                Some("not.contains".into())
+            ),
+        ]
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Plain<'a> {
+    #[garde(length(min = 3), ascii, message = "invalid name")]
+    name: &'a str,
+}
+
+#[test]
+fn message_replaces_builtin_messages() {
+    assert_eq!(
+        errors(&Plain { name: "é" }, &()),
+        [
+            (
+                "name".into(),
+                "invalid name".into(),
+                Some("length.min".into())
+            ),
+            ("name".into(), "invalid name".into(), Some("ascii".into())),
+        ]
+    );
+}
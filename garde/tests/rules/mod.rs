@@ -1,25 +1,81 @@
 mod allow_unvalidated;
 mod alphanumeric;
 mod ascii;
+mod at_least_one;
+mod base64;
+mod borrowed;
+mod bound;
 mod byte_length;
+mod checksum;
+mod code;
+mod container;
 mod contains;
+mod contains_key;
 mod credit_card;
 mod custom;
+mod date;
+mod default_rules;
+mod dependent_required;
 mod dive;
 mod dive_with_rules;
+mod e164;
 mod email;
+mod enum_value;
+mod excludes;
+mod extension;
+mod finite;
+mod flatten;
+mod fs_path;
+mod hex;
+mod hostname;
+mod imei;
+mod indexmap;
 mod inner;
 mod ip;
+mod isbn;
+mod keys;
+mod label;
 mod length;
+mod lowercase;
+mod luhn;
+mod message;
 mod multi_rule;
+mod mutually_exclusive;
+mod no_control_chars;
+mod not;
+mod not_blank;
+mod numeric;
+mod one_of;
 mod option;
+mod option_matrix;
+mod optional;
+mod options;
+mod ordering;
+mod os_str;
 mod pattern;
+mod pattern_fancy;
 mod phone_number;
 mod prefix;
 mod range;
+mod recursive;
+mod reflection;
+mod schema;
 mod select;
+mod semver;
+mod set;
 mod skip;
+mod starts_with_uppercase;
 mod suffix;
+mod titlecase;
+mod trait_object;
+mod transparent;
+mod try_from;
+mod uppercase;
 mod url;
+mod validate_at;
+mod value_debug;
+mod variant;
+mod when;
+mod words;
 
 mod util;
@@ -0,0 +1,90 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+#[garde(mutually_exclusive(percentage, fixed, code))]
+struct Discount<'a> {
+    #[garde(inner(range(max = 100)))]
+    percentage: Option<u8>,
+    #[garde(skip)]
+    fixed: Option<u32>,
+    #[garde(skip)]
+    code: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(mutually_exclusive(email, phone, path = "contact"))]
+#[garde(at_least_one(email, phone, path = "contact"))]
+struct Contact {
+    #[garde(skip)]
+    email: Option<String>,
+    #[garde(skip)]
+    phone: Option<String>,
+}
+
+#[test]
+fn mutually_exclusive_valid() {
+    util::check_ok(
+        &[
+            Discount {
+                percentage: None,
+                fixed: None,
+                code: "",
+            },
+            Discount {
+                percentage: Some(10),
+                fixed: None,
+                code: " ",
+            },
+            Discount {
+                percentage: None,
+                fixed: None,
+                code: "SUMMER",
+            },
+        ],
+        &(),
+    );
+    util::check_ok(
+        &[Contact {
+            email: Some(String::new()),
+            phone: Some("+14155552671".into()),
+        }],
+        &(),
+    );
+}
+
+#[test]
+fn mutually_exclusive_invalid() {
+    util::check_fail!(
+        &[
+            Discount {
+                percentage: Some(10),
+                fixed: Some(5),
+                code: "",
+            },
+            Discount {
+                percentage: Some(200),
+                fixed: None,
+                code: "SUMMER",
+            },
+            Discount {
+                percentage: Some(10),
+                fixed: Some(5),
+                code: "SUMMER",
+            },
+        ],
+        &()
+    );
+    util::check_fail!(
+        &[
+            Contact {
+                email: Some("test@mail.com".into()),
+                phone: Some("+14155552671".into()),
+            },
+            Contact {
+                email: None,
+                phone: None,
+            },
+        ],
+        &()
+    );
+}
@@ -0,0 +1,49 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(no_control_chars)]
+    field: &'a str,
+    #[garde(no_control_chars(allow = ["\n", "\t"]))]
+    allow: &'a str,
+    #[garde(inner(no_control_chars))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn no_control_chars_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "café 😂",
+                allow: "line\n\tbreak",
+                inner: &["a b"],
+            },
+            Test {
+                field: "",
+                allow: "",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn no_control_chars_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "null\0byte",
+                allow: "line\n\rbreak",
+                inner: &["a", "\u{7f}"],
+            },
+            Test {
+                field: "line\nbreak",
+                allow: "\0",
+                inner: &["ü\u{1b}[0m"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,117 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::util;
+
+static RESERVED: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(root|admin)$").unwrap());
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not(pattern(r"^admin")))]
+    pattern: &'a str,
+    #[garde(not(contains(any = ["@", " "])))]
+    contains: &'a str,
+    #[garde(prefix("x"), not(suffix("x")))]
+    suffix: &'a str,
+    #[garde(not(email))]
+    email: Option<&'a str>,
+    #[garde(inner(not(uppercase), not(pattern(RESERVED))))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn not_valid() {
+    util::check_ok(
+        &[
+            Test {
+                pattern: "user",
+                contains: "user",
+                suffix: "xy",
+                email: Some("user"),
+                inner: &["user"],
+            },
+            Test {
+                pattern: "not_admin",
+                contains: "",
+                suffix: "xa",
+                email: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn not_invalid() {
+    util::check_fail!(
+        &[Test {
+            pattern: "admin_user",
+            contains: "user name",
+            suffix: "xx",
+            email: Some("user@example.com"),
+            inner: &["USER", "user", "root"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn not_code() {
+    use garde::Validate;
+
+    let value = Test {
+        pattern: "admin",
+        contains: "@",
+        suffix: "x",
+        email: None,
+        inner: &[],
+    };
+    let report = value.validate(&()).unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("pattern".to_string(), "not.pattern"),
+            ("contains".to_string(), "not.contains"),
+            ("suffix".to_string(), "not.suffix"),
+        ]
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Username<'a> {
+    #[garde(not(one_of(["root", "admin"])))]
+    name: &'a str,
+}
+
+#[test]
+fn not_one_of_valid() {
+    util::check_ok(
+        &[Username { name: "alice" }, Username { name: "Admin" }],
+        &(),
+    )
+}
+
+#[test]
+fn not_one_of_invalid() {
+    util::check_fail!(
+        &[Username { name: "root" }, Username { name: "admin" }],
+        &()
+    )
+}
+
+#[test]
+fn not_one_of_code() {
+    use garde::Validate;
+
+    let report = Username { name: "root" }.validate(&()).unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(codes, [("name".to_string(), "not.one_of")]);
+}
@@ -0,0 +1,32 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_blank)]
+    field: &'a str,
+
+    #[garde(inner(not_blank))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn not_blank_valid() {
+    util::check_ok(
+        &[Test {
+            field: "a",
+            inner: &[" a ", "\u{3000}b"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn not_blank_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "",
+            inner: &[" \t\n", "\u{3000}"]
+        }],
+        &()
+    )
+}
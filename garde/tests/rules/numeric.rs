@@ -0,0 +1,117 @@
+use super::util;
+
+struct Limits {
+    max: i32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits as ctx))]
+struct Test<'a> {
+    #[garde(numeric(int, min = -10, max = ctx.max))]
+    int: &'a str,
+    #[garde(numeric(float, min = 0, max = 1.5))]
+    float: &'a str,
+    #[garde(numeric)]
+    any: &'a str,
+    #[garde(numeric(int, min = 1))]
+    optional: Option<&'a str>,
+    #[garde(inner(numeric(int, max = 10)))]
+    inner: &'a [&'a str],
+}
+
+const LIMITS: Limits = Limits { max: 100 };
+
+#[test]
+fn numeric_valid() {
+    util::check_ok(
+        &[
+            Test {
+                int: "-10",
+                float: "0",
+                any: "1e3",
+                optional: None,
+                inner: &["10"],
+            },
+            Test {
+                int: "100",
+                float: "1.5",
+                any: "-0.25",
+                optional: Some("1"),
+                inner: &["-5", "+7"],
+            },
+        ],
+        &LIMITS,
+    )
+}
+
+#[test]
+fn numeric_not_a_number() {
+    util::check_fail!(
+        &[
+            Test {
+                int: "1.5",
+                float: "abc",
+                any: "NaN",
+                optional: Some(""),
+                inner: &[" 1"],
+            },
+            Test {
+                int: "ten",
+                float: "inf",
+                any: "1,5",
+                optional: Some("0x10"),
+                inner: &["1_000"],
+            },
+        ],
+        &LIMITS,
+    )
+}
+
+#[test]
+fn numeric_out_of_range() {
+    util::check_fail!(
+        &[
+            Test {
+                int: "-11",
+                float: "-0.1",
+                any: "0",
+                optional: Some("0"),
+                inner: &["11"],
+            },
+            Test {
+                int: "101",
+                float: "1.51",
+                any: "0",
+                optional: Some("-1"),
+                inner: &["10", "100"],
+            },
+        ],
+        &LIMITS,
+    )
+}
+
+#[test]
+fn numeric_codes() {
+    use garde::Validate;
+
+    let value = Test {
+        int: "x",
+        float: "2",
+        any: "0",
+        optional: Some("0"),
+        inner: &[],
+    };
+    let report = value.validate(&LIMITS).unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("int".to_string(), "numeric.invalid"),
+            ("float".to_string(), "numeric.max"),
+            ("optional".to_string(), "numeric.min"),
+        ]
+    );
+}
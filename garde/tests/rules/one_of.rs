@@ -0,0 +1,45 @@
+use super::util;
+
+const ROLES: &[&str] = &["admin", "user"];
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of(["red", "green", "blue"]))]
+    field: &'a str,
+    #[garde(one_of(ROLES))]
+    role: Option<String>,
+    #[garde(inner(one_of(ROLES)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn one_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "red",
+                role: Some("admin".into()),
+                inner: &["user", "admin"],
+            },
+            Test {
+                field: "blue",
+                role: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn one_of_invalid() {
+    util::check_fail!(
+        &[Test {
+            // Compared exactly.
+            field: "Red",
+            role: Some("root".into()),
+            inner: &["user", ""],
+        }],
+        &()
+    )
+}
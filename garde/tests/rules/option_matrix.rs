@@ -0,0 +1,113 @@
+//! Pins how each way of validating an `Option` treats `None`, a valid `Some`, and an invalid `Some`.
+
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Child {
+    #[garde(length(min = 2))]
+    value: String,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Rule {
+    #[garde(length(min = 2))]
+    v: Option<String>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct InnerRule {
+    #[garde(inner(length(min = 2)))]
+    v: Option<String>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Required {
+    #[garde(required)]
+    v: Option<String>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct RequiredRule {
+    #[garde(required, length(min = 2))]
+    v: Option<String>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Dive {
+    #[garde(dive)]
+    v: Option<Child>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct DiveRequired {
+    #[garde(dive(required))]
+    v: Option<Child>,
+}
+
+const VALID: [&str; 0] = [];
+
+fn errors<T: Validate<Context = ()>>(value: &T) -> Vec<String> {
+    match value.validate(&()) {
+        Ok(()) => Vec::new(),
+        Err(report) => report
+            .iter()
+            .map(|(path, error)| format!("{path}: {}", error.code().unwrap_or_default()))
+            .collect(),
+    }
+}
+
+fn string(value: &str) -> Option<String> {
+    Some(value.into())
+}
+
+fn child(value: &str) -> Option<Child> {
+    Some(Child {
+        value: value.into(),
+    })
+}
+
+#[test]
+fn option_matrix_rule() {
+    assert_eq!(errors(&Rule { v: None }), VALID);
+    assert_eq!(errors(&Rule { v: string("ab") }), VALID);
+    assert_eq!(errors(&Rule { v: string("a") }), ["v: length.min"]);
+}
+
+#[test]
+fn option_matrix_inner() {
+    assert_eq!(errors(&InnerRule { v: None }), VALID);
+    assert_eq!(errors(&InnerRule { v: string("ab") }), VALID);
+    assert_eq!(errors(&InnerRule { v: string("a") }), ["v: length.min"]);
+}
+
+#[test]
+fn option_matrix_required() {
+    assert_eq!(errors(&Required { v: None }), ["v: required"]);
+    assert_eq!(errors(&Required { v: string("ab") }), VALID);
+    // `required` alone only checks for presence.
+    assert_eq!(errors(&Required { v: string("a") }), VALID);
+}
+
+#[test]
+fn option_matrix_required_rule() {
+    assert_eq!(errors(&RequiredRule { v: None }), ["v: required"]);
+    assert_eq!(errors(&RequiredRule { v: string("ab") }), VALID);
+    assert_eq!(errors(&RequiredRule { v: string("a") }), ["v: length.min"]);
+}
+
+#[test]
+fn option_matrix_dive() {
+    assert_eq!(errors(&Dive { v: None }), VALID);
+    assert_eq!(errors(&Dive { v: child("ab") }), VALID);
+    assert_eq!(errors(&Dive { v: child("a") }), ["v.value: length.min"]);
+}
+
+#[test]
+fn option_matrix_dive_required() {
+    assert_eq!(errors(&DiveRequired { v: None }), ["v: required"]);
+    assert_eq!(errors(&DiveRequired { v: child("ab") }), VALID);
+    assert_eq!(
+        errors(&DiveRequired { v: child("a") }),
+        ["v.value: length.min"]
+    );
+}
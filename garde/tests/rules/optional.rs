@@ -0,0 +1,65 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Sub<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(optional(length(min = 1), dive))]
+    subs: Option<Vec<Sub<'a>>>,
+
+    #[garde(optional(inner(length(min = 1))))]
+    tags: Option<Vec<&'a str>>,
+
+    // `inner` on an `Option` applies to the `Vec`, so this is equivalent to the above.
+    #[garde(inner(inner(length(min = 1))))]
+    tags_inner: Option<Vec<&'a str>>,
+
+    #[garde(required, optional(length(max = 2)))]
+    required: Option<Vec<&'a str>>,
+}
+
+#[test]
+fn optional_valid() {
+    util::check_ok(
+        &[
+            Test {
+                subs: None,
+                tags: None,
+                tags_inner: None,
+                required: Some(vec![]),
+            },
+            Test {
+                subs: Some(vec![Sub { name: "a" }, Sub { name: "b" }]),
+                tags: Some(vec!["a"]),
+                tags_inner: Some(vec!["a"]),
+                required: Some(vec!["a", "b"]),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn optional_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                subs: Some(vec![]),
+                tags: Some(vec![""]),
+                tags_inner: Some(vec![""]),
+                required: None,
+            },
+            Test {
+                subs: Some(vec![Sub { name: "a" }, Sub { name: "" }]),
+                tags: Some(vec!["a", ""]),
+                tags_inner: Some(vec!["a", ""]),
+                required: Some(vec!["a", "b", "c"]),
+            },
+        ],
+        &()
+    )
+}
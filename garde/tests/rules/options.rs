@@ -0,0 +1,257 @@
+use std::cell::Cell;
+
+use garde::error::Severity;
+use garde::{Report, Validate, ValidationOptions};
+
+#[derive(Debug, Clone, garde::Validate)]
+#[garde(context(Cell<usize>))]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Cell<usize> as ctx))]
+struct Test<'a> {
+    #[garde(custom(counted), length(min = 3))]
+    a: &'a str,
+    #[garde(custom(counted), length(min = 3))]
+    b: &'a str,
+    #[garde(custom(|value: &str, _: &Cell<usize>| {
+        if value.len() > 5 {
+            return Err(garde::Error::builder().message("long").severity(Severity::Warning).build());
+        }
+        Ok(())
+    }))]
+    warned: &'a str,
+    #[garde(custom(counted), dive)]
+    items: Vec<Item<'a>>,
+    #[garde(dive)]
+    optional: Option<Item<'a>>,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Cell<usize>))]
+struct Traced<'a> {
+    #[garde(dive)]
+    first: Option<Item<'a>>,
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+fn counted<T: ?Sized>(_: &T, ctx: &Cell<usize>) -> garde::Result {
+    ctx.set(ctx.get() + 1);
+    Ok(())
+}
+
+fn invalid() -> Test<'static> {
+    Test {
+        a: "",
+        b: "",
+        warned: "too long",
+        items: vec![Item { name: "" }, Item { name: "" }],
+        optional: None,
+    }
+}
+
+fn messages(report: &Report) -> Vec<String> {
+    report
+        .iter()
+        .map(|(path, error)| format!("{path}: {}", error.message()))
+        .collect()
+}
+
+#[test]
+fn options_default_matches_validate() {
+    let options = ValidationOptions::default();
+    assert_eq!(options.limit(), None);
+    assert!(options.collect_warnings && !options.fail_fast && !options.trace);
+
+    let report = invalid()
+        .validate_with_options(&Cell::new(0), &options)
+        .unwrap_err();
+    assert_eq!(report, invalid().validate(&Cell::new(0)).unwrap_err());
+    assert_eq!(
+        messages(&report),
+        [
+            "a: length is lower than 3",
+            "b: length is lower than 3",
+            "warned: long",
+            "items[0].name: length is lower than 1",
+            "items[1].name: length is lower than 1",
+        ]
+    );
+}
+
+#[test]
+fn options_fail_fast() {
+    let ctx = Cell::new(0);
+    let options = ValidationOptions {
+        fail_fast: true,
+        ..Default::default()
+    };
+    let report = invalid().validate_with_options(&ctx, &options).unwrap_err();
+    assert_eq!(messages(&report), ["a: length is lower than 3"]);
+    // The remaining fields were not validated at all.
+    assert_eq!(ctx.get(), 1);
+
+    let valid = Test {
+        a: "abc",
+        b: "abc",
+        warned: "",
+        items: vec![],
+        optional: None,
+    };
+    assert!(valid.validate_with_options(&ctx, &options).is_ok());
+}
+
+#[test]
+fn options_error_limit() {
+    let ctx = Cell::new(0);
+    let options = ValidationOptions {
+        error_limit: Some(4),
+        ..Default::default()
+    };
+    let report = invalid().validate_with_options(&ctx, &options).unwrap_err();
+    assert_eq!(
+        messages(&report),
+        [
+            "a: length is lower than 3",
+            "b: length is lower than 3",
+            "warned: long",
+            "items[0].name: length is lower than 1",
+            "items[1].name: length is lower than 1",
+        ]
+    );
+    // The warning does not count towards the limit.
+    assert!(report.is_full());
+    assert_eq!(ctx.get(), 3);
+
+    let items = vec![Item { name: "" }; 100];
+    let report = items
+        .validate_with_options(
+            &Cell::new(0),
+            &ValidationOptions {
+                error_limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+    assert_eq!(
+        messages(&report),
+        [
+            "[0].name: length is lower than 1",
+            "[1].name: length is lower than 1"
+        ]
+    );
+}
+
+#[test]
+fn options_collect_warnings() {
+    let options = ValidationOptions {
+        collect_warnings: false,
+        ..Default::default()
+    };
+    let report = invalid()
+        .validate_with_options(&Cell::new(0), &options)
+        .unwrap_err();
+    assert!(report.iter().all(|(_, error)| error.severity().is_error()));
+    assert_eq!(report.iter().count(), 4);
+
+    let only_warned = Test {
+        a: "abc",
+        b: "abc",
+        warned: "too long",
+        items: vec![],
+        optional: None,
+    };
+    assert!(only_warned
+        .validate_with_options(&Cell::new(0), &options)
+        .is_ok());
+}
+
+#[test]
+fn options_fail_fast_collects_warnings() {
+    let ctx = Cell::new(0);
+    let options = ValidationOptions {
+        fail_fast: true,
+        collect_warnings: true,
+        ..Default::default()
+    };
+    let value = Test {
+        a: "abc",
+        b: "abc",
+        warned: "too long",
+        items: vec![Item { name: "" }, Item { name: "" }],
+        optional: None,
+    };
+    let report = value.validate_with_options(&ctx, &options).unwrap_err();
+    // The warning comes first, but validation only stops at the first error.
+    assert_eq!(
+        messages(&report),
+        ["warned: long", "items[0].name: length is lower than 1"]
+    );
+    assert_eq!(ctx.get(), 3);
+}
+
+#[test]
+fn options_trace() {
+    let options = ValidationOptions {
+        trace: true,
+        collect_warnings: false,
+        ..Default::default()
+    };
+    let report = invalid()
+        .validate_with_options(&Cell::new(0), &options)
+        .unwrap_err();
+    assert_eq!(
+        messages(&report),
+        [
+            "a: length is lower than 3",
+            "b: length is lower than 3",
+            "items[0].name: length is lower than 1",
+            "items[1].name: length is lower than 1",
+            "optional: skipped, value is none",
+        ]
+    );
+    let (_, note) = report.iter().last().unwrap();
+    assert_eq!(note.severity(), Severity::Info);
+    assert_eq!(note.code(), Some("dive.none"));
+}
+
+#[test]
+fn options_trace_valid_is_ok() {
+    let options = ValidationOptions {
+        trace: true,
+        ..Default::default()
+    };
+    let value = Traced {
+        first: None,
+        name: "a",
+    };
+    assert!(value.validate_with_options(&Cell::new(0), &options).is_ok());
+}
+
+#[test]
+fn options_trace_fail_fast() {
+    let options = ValidationOptions {
+        trace: true,
+        fail_fast: true,
+        ..Default::default()
+    };
+    let value = Traced {
+        first: None,
+        name: "",
+    };
+    let report = value
+        .validate_with_options(&Cell::new(0), &options)
+        .unwrap_err();
+    // The note does not use up the limit of a single error.
+    assert_eq!(
+        messages(&report),
+        [
+            "first: skipped, value is none",
+            "name: length is lower than 1",
+        ]
+    );
+}
@@ -0,0 +1,99 @@
+use garde::Validate;
+
+fn errors<T: Validate<Context = ()>>(value: &T) -> Vec<(String, String)> {
+    value
+        .validate(&())
+        .unwrap_err()
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.message().to_string()))
+        .collect()
+}
+
+fn fail(_: &str, _: &()) -> garde::Result {
+    Err(garde::Error::new("custom"))
+}
+
+#[derive(Debug, garde::Validate)]
+struct Fields<'a> {
+    #[garde(length(min = 1))]
+    zeta: &'a str,
+    #[garde(length(min = 1))]
+    alpha: &'a str,
+    #[garde(length(min = 1))]
+    mu: &'a str,
+}
+
+#[test]
+fn fields_in_declaration_order() {
+    let value = Fields {
+        zeta: "",
+        alpha: "",
+        mu: "",
+    };
+    let paths = errors(&value)
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["zeta", "alpha", "mu"]);
+}
+
+#[derive(Debug, garde::Validate)]
+struct Rules<'a> {
+    #[garde(pattern(r"^[a-z]+$"), custom(fail), length(min = 10), ascii)]
+    value: &'a str,
+}
+
+#[test]
+fn rules_in_attribute_order() {
+    let value = Rules { value: "Ü" };
+    let messages = errors(&value)
+        .into_iter()
+        .map(|(_, m)| m)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        [
+            "does not match pattern /^[a-z]+$/",
+            "custom",
+            "length is lower than 10",
+            "not ascii",
+        ]
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Dive<'a> {
+    #[garde(length(max = 1), dive)]
+    items: Vec<Item<'a>>,
+    #[garde(inner(length(min = 1)))]
+    tags: [&'a str; 2],
+}
+
+#[test]
+fn dive_in_iteration_order() {
+    let value = Dive {
+        items: vec![Item { name: "" }, Item { name: "" }, Item { name: "" }],
+        tags: ["", ""],
+    };
+    let paths = errors(&value)
+        .into_iter()
+        .map(|(path, _)| path)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        [
+            "items",
+            "items[0].name",
+            "items[1].name",
+            "items[2].name",
+            "tags[0]",
+            "tags[1]",
+        ]
+    );
+}
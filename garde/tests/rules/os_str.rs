@@ -0,0 +1,72 @@
+use std::ffi::{OsStr, OsString};
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_blank, length(max = 5), byte_length(max = 10))]
+    owned: OsString,
+
+    #[garde(not_blank, length(min = 1))]
+    borrowed: &'a OsStr,
+}
+
+#[test]
+fn os_str_valid() {
+    util::check_ok(
+        &[Test {
+            owned: OsString::from("äääää"),
+            borrowed: OsStr::new("a"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn os_str_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                owned: OsString::from("  "),
+                borrowed: OsStr::new(""),
+            },
+            Test {
+                owned: OsString::from("ääääää"),
+                borrowed: OsStr::new("a"),
+            }
+        ],
+        &()
+    )
+}
+
+#[cfg(unix)]
+#[test]
+fn os_str_non_utf8() {
+    use std::os::unix::ffi::OsStrExt;
+
+    use garde::Validate;
+
+    // `0xFF` is never valid UTF-8, and is counted as a single replacement character by `length`.
+    let value = Test {
+        owned: OsStr::from_bytes(b"\xFF\xFFabc").to_owned(),
+        borrowed: OsStr::from_bytes(b"\xFF"),
+    };
+    assert!(value.validate(&()).is_ok());
+
+    let value = Test {
+        owned: OsStr::from_bytes(b"\xFF\xFFabcdefghi").to_owned(),
+        borrowed: OsStr::from_bytes(b"\xFF"),
+    };
+    let report = value.validate(&()).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, error)| format!("{path}: {error}"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            "owned: length is greater than 5",
+            "owned: byte length is greater than 10"
+        ]
+    );
+}
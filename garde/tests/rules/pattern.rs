@@ -69,3 +69,73 @@ fn pattern_invalid() {
         &()
     )
 }
+
+static DATE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?P<year>\d{4})?-(?P<month>\d{2})$").unwrap());
+
+#[derive(Debug, garde::Validate)]
+struct Groups<'a> {
+    #[garde(pattern(r"^(?P<year>\d{4})?-(?P<month>\d{2})$", require = ["year"]))]
+    field: &'a str,
+
+    #[garde(pattern(DATE_RE, require = ["year", "month"]))]
+    field_path: &'a str,
+
+    #[garde(pattern(r"^(?P<year>\d{4})?-(?P<month>\d{2})$", require = ["year"]))]
+    optional: Option<&'a str>,
+}
+
+#[test]
+fn pattern_groups_valid() {
+    util::check_ok(
+        &[
+            Groups {
+                field: "2024-01",
+                field_path: "2024-01",
+                optional: None,
+            },
+            Groups {
+                field: "1999-12",
+                field_path: "1999-12",
+                optional: Some("1999-12"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn pattern_groups_invalid() {
+    util::check_fail!(
+        &[
+            // The optional `year` group did not take part in the match.
+            Groups {
+                field: "-01",
+                field_path: "-01",
+                optional: Some("-01"),
+            },
+            // No match at all.
+            Groups {
+                field: "2024/01",
+                field_path: "2024/01",
+                optional: Some("2024/01"),
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn pattern_groups_code() {
+    let report = garde::Validate::validate(
+        &Groups {
+            field: "-01",
+            field_path: "2024-01",
+            optional: None,
+        },
+        &(),
+    )
+    .unwrap_err();
+    let codes: Vec<_> = report.iter().map(|(_, e)| e.code()).collect();
+    assert_eq!(codes, [Some("pattern.group")]);
+}
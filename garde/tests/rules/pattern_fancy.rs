@@ -0,0 +1,33 @@
+#![cfg(feature = "fancy-regex")]
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(pattern(r"^(?!admin$)[a-z]+$"))]
+    username: &'a str,
+    #[garde(pattern(r"^(\w)\1$"))]
+    repeated: &'a str,
+}
+
+#[test]
+fn pattern_fancy_valid() {
+    util::check_ok(
+        &[Test {
+            username: "alice",
+            repeated: "aa",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn pattern_fancy_invalid() {
+    util::check_fail!(
+        &[Test {
+            username: "admin",
+            repeated: "ab",
+        }],
+        &()
+    )
+}
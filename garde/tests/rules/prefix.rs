@@ -43,3 +43,43 @@ fn prefix_invalid() {
         &()
     )
 }
+
+const NEEDLES: &[&str] = &["foo", "bar"];
+
+#[derive(Debug, garde::Validate)]
+struct Multi<'a> {
+    #[garde(prefix(any = ["foo", "bar"]))]
+    any: &'a str,
+    #[garde(prefix(any = NEEDLES))]
+    any_path: &'a str,
+    #[garde(prefix(all = ["foo", "foobar"]))]
+    all: &'a str,
+    #[garde(inner(prefix(any = ["foo", "bar"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn multi_prefix_valid() {
+    util::check_ok(
+        &[Multi {
+            any: "bar_",
+            any_path: "bar_",
+            all: "foobar",
+            inner: &["bar_", "foo"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn multi_prefix_invalid() {
+    util::check_fail!(
+        &[Multi {
+            any: "baz",
+            any_path: "baz",
+            all: "foo",
+            inner: &["baz"],
+        }],
+        &()
+    )
+}
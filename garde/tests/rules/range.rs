@@ -44,3 +44,309 @@ fn range_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Durations {
+    #[garde(range(min = std::time::Duration::from_secs(1), max = std::time::Duration::from_secs(60)))]
+    field: std::time::Duration,
+    #[garde(range(min = std::time::Duration::from_millis(500)))]
+    optional: Option<std::time::Duration>,
+}
+
+#[test]
+fn duration_range_valid() {
+    util::check_ok(
+        &[
+            Durations {
+                field: std::time::Duration::from_secs(1),
+                optional: None,
+            },
+            Durations {
+                field: std::time::Duration::from_secs(60),
+                optional: Some(std::time::Duration::from_millis(500)),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn duration_range_invalid() {
+    util::check_fail!(
+        &[
+            Durations {
+                field: std::time::Duration::from_millis(999),
+                optional: Some(std::time::Duration::from_millis(499)),
+            },
+            Durations {
+                field: std::time::Duration::from_nanos(60_000_000_001),
+                optional: None,
+            },
+        ],
+        &()
+    )
+}
+
+#[cfg(feature = "chrono")]
+#[derive(Debug, garde::Validate)]
+struct ChronoDates {
+    #[garde(range(min = chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()))]
+    field: chrono::NaiveDate,
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_date_range() {
+    let valid = ChronoDates {
+        field: chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+    };
+    util::check_ok(&[valid], &());
+
+    let invalid = ChronoDates {
+        field: chrono::NaiveDate::from_ymd_opt(1999, 12, 31).unwrap(),
+    };
+    let report = garde::Validate::validate(&invalid, &()).unwrap_err();
+    assert_eq!(report.to_string(), "field: lower than 2000-01-01\n");
+}
+
+#[cfg(feature = "time")]
+#[derive(Debug, garde::Validate)]
+struct TimeDates {
+    #[garde(range(max = time::Date::from_calendar_date(2000, time::Month::January, 1).unwrap()))]
+    field: time::Date,
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_date_range() {
+    let valid = TimeDates {
+        field: time::Date::from_calendar_date(2000, time::Month::January, 1).unwrap(),
+    };
+    util::check_ok(&[valid], &());
+
+    let invalid = TimeDates {
+        field: time::Date::from_calendar_date(2000, time::Month::January, 2).unwrap(),
+    };
+    let report = garde::Validate::validate(&invalid, &()).unwrap_err();
+    assert_eq!(report.to_string(), "field: greater than 2000-01-01\n");
+}
+
+#[derive(Debug, garde::Validate)]
+struct SingleBound {
+    #[garde(range(min = 0))]
+    min_only: i32,
+    #[garde(range(max = 100))]
+    max_only: i32,
+    #[garde(range(min = 0.))]
+    float_min_only: f64,
+    #[garde(range(max = 100.))]
+    float_max_only: f64,
+}
+
+#[test]
+fn single_bound_valid() {
+    util::check_ok(
+        &[
+            SingleBound {
+                min_only: i32::MAX,
+                max_only: i32::MIN,
+                float_min_only: f64::INFINITY,
+                float_max_only: f64::NEG_INFINITY,
+            },
+            SingleBound {
+                min_only: 0,
+                max_only: 100,
+                float_min_only: 0.,
+                float_max_only: 100.,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn single_bound_invalid() {
+    util::check_fail!(
+        &[
+            SingleBound {
+                min_only: -1,
+                max_only: 101,
+                float_min_only: -0.5,
+                float_max_only: 100.5,
+            },
+            SingleBound {
+                min_only: 0,
+                max_only: 0,
+                float_min_only: f64::NAN,
+                float_max_only: f64::NAN,
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Combined {
+    #[garde(range(min = 10, max = 100, combined_message))]
+    field: u64,
+    #[garde(range(min = 0., max = 1., combined_message))]
+    float_field: f64,
+}
+
+#[test]
+fn combined_message_valid() {
+    util::check_ok(
+        &[Combined {
+            field: 10,
+            float_field: 1.,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn combined_message_invalid() {
+    util::check_fail!(
+        &[
+            Combined {
+                field: 9,
+                float_field: f64::NAN,
+            },
+            Combined {
+                field: 101,
+                float_field: 2.,
+            }
+        ],
+        &()
+    )
+}
+
+#[test]
+fn combined_message_single_error() {
+    let value = Combined {
+        field: 101,
+        float_field: 0.5,
+    };
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [(
+            "field".to_string(),
+            "not between 10 and 100".to_string(),
+            Some("range.max")
+        )]
+    );
+}
+
+const NEGATIVE: i64 = -5;
+const LARGE: u128 = u64::MAX as u128 + 1;
+const SMALL_MAX: u8 = 200;
+
+#[derive(Debug, garde::Validate)]
+struct MixedIntegers {
+    #[garde(range(min = NEGATIVE, max = 10))]
+    negative_min: u64,
+    #[garde(range(max = NEGATIVE))]
+    negative_max: u64,
+    #[garde(range(max = LARGE))]
+    large_max: u64,
+    #[garde(range(min = -300, max = SMALL_MAX, combined_message))]
+    widened: i16,
+}
+
+#[test]
+fn mixed_integer_bounds() {
+    let value = MixedIntegers {
+        negative_min: 0,
+        negative_max: 0,
+        large_max: u64::MAX,
+        widened: 201,
+    };
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    let errors = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (
+                "negative_max".to_string(),
+                "greater than -5".to_string(),
+                Some("range.max")
+            ),
+            (
+                "widened".to_string(),
+                "not between -300 and 200".to_string(),
+                Some("range.max")
+            ),
+        ]
+    );
+
+    let value = MixedIntegers {
+        negative_min: 11,
+        negative_max: 0,
+        large_max: 0,
+        widened: -301,
+    };
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("negative_min".to_string(), Some("range.max")),
+            ("negative_max".to_string(), Some("range.max")),
+            ("widened".to_string(), Some("range.min")),
+        ]
+    );
+}
+
+/// A user type implementing `Bounds`, whose size only implements `Display`.
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Percent(u8);
+
+impl garde::rules::range::Bounds for Percent {
+    type Size = u8;
+
+    const MIN: Self::Size = 0;
+    const MAX: Self::Size = 100;
+
+    fn validate_bounds(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), garde::rules::range::OutOfBounds> {
+        self.0.validate_bounds(lower_bound, upper_bound)
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Discount {
+    #[garde(range(max = 50))]
+    percent: Percent,
+}
+
+#[test]
+fn custom_bounds() {
+    util::check_ok(
+        &[Discount {
+            percent: Percent(50),
+        }],
+        &(),
+    );
+    let report = garde::Validate::validate(
+        &Discount {
+            percent: Percent(51),
+        },
+        &(),
+    )
+    .unwrap_err();
+    assert_eq!(report.to_string(), "percent: greater than 50\n");
+}
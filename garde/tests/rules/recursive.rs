@@ -0,0 +1,60 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Node {
+    #[garde(length(min = 1))]
+    name: String,
+    #[garde(dive)]
+    children: Vec<Node>,
+    #[garde(dive)]
+    parent: Option<Box<Self>>,
+}
+
+fn leaf(name: &str) -> Node {
+    Node {
+        name: name.into(),
+        children: Vec::new(),
+        parent: None,
+    }
+}
+
+#[test]
+fn recursive_valid() {
+    util::check_ok(
+        &[Node {
+            name: "root".into(),
+            children: vec![
+                leaf("a"),
+                Node {
+                    children: vec![leaf("c")],
+                    parent: Some(Box::new(leaf("root"))),
+                    ..leaf("b")
+                },
+            ],
+            parent: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn recursive_invalid() {
+    util::check_fail!(
+        &[Node {
+            name: "root".into(),
+            children: vec![
+                leaf("a"),
+                Node {
+                    children: vec![leaf("c"), leaf("")],
+                    parent: Some(Box::new(Node {
+                        parent: Some(Box::new(leaf(""))),
+                        ..leaf("root")
+                    })),
+                    ..leaf("b")
+                },
+            ],
+            parent: None,
+        }],
+        &()
+    )
+}
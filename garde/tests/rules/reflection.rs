@@ -0,0 +1,110 @@
+#![cfg(feature = "reflection")]
+
+use garde::reflection::{Category, FieldRuleMeta, Reflect, RuleMeta};
+
+struct Limits {
+    max: usize,
+}
+
+#[derive(garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    city: &'a str,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Limits as ctx))]
+#[allow(dead_code)]
+struct User<'a> {
+    #[garde(required, length(min = 3, max = ctx.max), not(contains("admin")))]
+    name: Option<&'a str>,
+    #[garde(range(min = 18))]
+    age: u8,
+    #[garde(length(max = 4), inner(pattern("^[a-z]+$")))]
+    tags: Vec<&'a str>,
+    #[garde(skip)]
+    cache: u64,
+    #[garde(skip)]
+    address: Address<'a>,
+}
+
+#[test]
+fn reflection_rules() {
+    assert_eq!(
+        User::rules(),
+        [
+            FieldRuleMeta {
+                name: "name",
+                category: Some(Category::Strings),
+                optional: true,
+                integer: false,
+                rules: &[
+                    RuleMeta {
+                        name: "required",
+                        params: &[],
+                    },
+                    RuleMeta {
+                        name: "length",
+                        params: &[("min", "3"), ("max", "ctx.max")],
+                    },
+                    RuleMeta {
+                        name: "not.contains",
+                        params: &[("value", "\"admin\"")],
+                    },
+                ],
+                inner: &[],
+            },
+            FieldRuleMeta {
+                name: "age",
+                category: Some(Category::Numbers),
+                optional: false,
+                integer: true,
+                rules: &[RuleMeta {
+                    name: "range",
+                    params: &[("min", "18")],
+                }],
+                inner: &[],
+            },
+            FieldRuleMeta {
+                name: "tags",
+                category: Some(Category::Lists),
+                optional: false,
+                integer: false,
+                rules: &[RuleMeta {
+                    name: "length",
+                    params: &[("max", "4")],
+                }],
+                inner: &[RuleMeta {
+                    name: "pattern",
+                    params: &[("pattern", "^[a-z]+$")],
+                }],
+            },
+        ]
+    );
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+enum Shape<'a> {
+    Circle(#[garde(range(min = 0.0))] f64),
+    Named {
+        #[garde(dive)]
+        address: Address<'a>,
+    },
+}
+
+#[test]
+fn reflection_lookup() {
+    let name = &User::rules()[0];
+    assert_eq!(name.rule("length").unwrap().param("min"), Some("3"));
+    assert_eq!(name.rule("length").unwrap().param("other"), None);
+    assert!(name.rule("email").is_none());
+
+    let shapes = Shape::rules();
+    assert_eq!(shapes.len(), 2);
+    assert_eq!(shapes[0].name, "Circle.0");
+    assert_eq!(shapes[0].rules[0].param("min"), Some("0.0"));
+    assert_eq!(shapes[1].name, "Named.address");
+    assert_eq!(shapes[1].category, None);
+    assert_eq!(shapes[1].rules[0].name, "dive");
+}
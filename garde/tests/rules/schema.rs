@@ -0,0 +1,122 @@
+#![cfg(feature = "schema")]
+
+use garde::schema::JsonSchema;
+use serde_json::json;
+
+const MAX_TAGS: usize = 4;
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    city: &'a str,
+    #[garde(pattern(r"^\d{5}$"))]
+    zip: &'a str,
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+struct Contact<'a> {
+    #[garde(email)]
+    email: &'a str,
+    #[garde(url)]
+    website: Option<&'a str>,
+    #[garde(hostname)]
+    server: Option<&'a str>,
+}
+
+/// Implements `Validate` by hand, so it has no schema.
+struct Opaque;
+
+impl garde::Validate for Opaque {
+    type Context = ();
+
+    fn validate_into(
+        &self,
+        _: &Self::Context,
+        _: &mut dyn FnMut() -> garde::Path,
+        _: &mut garde::Report,
+    ) {
+    }
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+struct User<'a> {
+    #[garde(length(min = 3, max = 20), not(contains("admin")))]
+    name: &'a str,
+    #[garde(required, range(min = 18, max = 150))]
+    age: Option<u8>,
+    #[garde(range(min = -1.5))]
+    balance: f64,
+    #[garde(length(max = MAX_TAGS), inner(length(min = 1), pattern("^[a-z]+$")))]
+    tags: Vec<&'a str>,
+    #[garde(skip)]
+    cache: u64,
+    #[garde(dive)]
+    address: Address<'a>,
+    #[garde(dive)]
+    previous: Vec<Address<'a>>,
+    #[garde(flatten)]
+    contact: Contact<'a>,
+    #[garde(dive)]
+    opaque: Opaque,
+}
+
+#[test]
+fn schema_golden() {
+    let address = json!({
+        "type": "object",
+        "properties": {
+            "city": { "type": "string", "minLength": 1 },
+            "zip": { "type": "string", "pattern": r"^\d{5}$" },
+        },
+        "required": ["city", "zip"],
+    });
+    assert_eq!(
+        User::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 3, "maxLength": 20 },
+                "age": { "type": "integer", "minimum": 18, "maximum": 150 },
+                "balance": { "type": "number", "minimum": -1.5 },
+                "tags": { "type": "array", "items": { "pattern": "^[a-z]+$" } },
+                "address": address,
+                "previous": { "type": "array", "items": address },
+                "email": { "type": "string", "format": "email" },
+                "website": { "type": "string", "format": "uri" },
+                "server": { "type": "string", "format": "hostname" },
+                "opaque": {},
+            },
+            "required": ["name", "age", "balance", "tags", "address", "previous", "email", "opaque"],
+        })
+    );
+}
+
+#[derive(garde::Validate)]
+#[allow(dead_code)]
+struct Node {
+    #[garde(length(min = 1))]
+    name: String,
+    #[garde(dive)]
+    children: Vec<Node>,
+    #[garde(dive)]
+    parent: Option<Box<Self>>,
+}
+
+#[test]
+fn schema_recursive() {
+    assert_eq!(
+        Node::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "name": { "type": "string", "minLength": 1 },
+                "children": { "type": "array", "items": {} },
+                "parent": {},
+            },
+            "required": ["name", "children"],
+        })
+    );
+}
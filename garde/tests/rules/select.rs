@@ -35,3 +35,31 @@ fn select_macro() {
         assert_eq!(errors, ["not ascii", "byte length is lower than 10"])
     }
 }
+
+#[test]
+fn select_runtime() {
+    let v = UserRole {
+        name: "😂".into(),
+        identifiers: vec![UserIdentifier { id: 10 }, UserIdentifier { id: 20 }],
+    };
+
+    let report = v.validate(&()).unwrap_err();
+    {
+        let selector = garde::Selector::new().key("identifiers").index(0);
+        let errors: Vec<String> = report.select(&selector).map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["greater than 4"]);
+    }
+    {
+        let selector = garde::Selector::new().key("name");
+        let errors: Vec<String> = report.select(&selector).map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["not ascii", "byte length is lower than 10"])
+    }
+    {
+        let selector = garde::Selector::new()
+            .key("identifiers")
+            .any_index()
+            .key("id");
+        let errors: Vec<String> = report.select(&selector).map(|e| e.to_string()).collect();
+        assert_eq!(errors, ["greater than 4", "greater than 4"])
+    }
+}
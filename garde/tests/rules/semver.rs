@@ -0,0 +1,58 @@
+#![cfg(feature = "semver")]
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(semver)]
+    field: &'a str,
+
+    #[garde(semver(min = "1.0.0", max = "2.0.0"))]
+    bounded: &'a str,
+
+    #[garde(inner(semver(min = "1.70.0")))]
+    typed: Vec<semver::Version>,
+}
+
+#[test]
+fn semver_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "0.0.1",
+                bounded: "1.0.0",
+                typed: vec![semver::Version::new(1, 70, 0)],
+            },
+            Test {
+                field: "1.0.0-alpha.1+build.5",
+                bounded: "2.0.0",
+                typed: vec![semver::Version::new(2, 0, 0)],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn semver_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "1.2",
+                bounded: "0.9.9",
+                typed: vec![semver::Version::new(1, 69, 9)],
+            },
+            Test {
+                field: "^1.2.3",
+                bounded: "2.0.1",
+                typed: vec![semver::Version::parse("1.70.0-rc.1").unwrap()],
+            },
+            Test {
+                field: "01.2.3",
+                bounded: "1.0.0-rc.1",
+                typed: vec![],
+            },
+        ],
+        &()
+    )
+}
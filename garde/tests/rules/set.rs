@@ -0,0 +1,88 @@
+use std::collections::{BTreeSet, HashSet};
+
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test {
+    #[garde(length(min = 1), inner(length(min = 2)))]
+    hash_set: HashSet<String>,
+    #[garde(length(max = 2), inner(alphanumeric))]
+    btree_set: BTreeSet<&'static str>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, garde::Validate)]
+struct Item {
+    #[garde(length(min = 1))]
+    name: String,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Dive {
+    #[garde(dive)]
+    hash_set: HashSet<Item>,
+    #[garde(dive)]
+    btree_set: BTreeSet<Item>,
+}
+
+#[test]
+fn set_valid() {
+    util::check_ok(
+        &[Test {
+            hash_set: HashSet::from(["ab".into(), "cd".into()]),
+            btree_set: BTreeSet::from(["a", "b"]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn set_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                hash_set: HashSet::new(),
+                btree_set: BTreeSet::from(["a", "b", "c"]),
+            },
+            Test {
+                hash_set: HashSet::from(["a".into()]),
+                btree_set: BTreeSet::from(["a", "-"]),
+            }
+        ],
+        &()
+    )
+}
+
+#[test]
+fn hash_set_invalid_element() {
+    let value = Test {
+        hash_set: HashSet::from(["ab".into(), "c".into(), "de".into()]),
+        btree_set: BTreeSet::new(),
+    };
+    let report = value.validate(&()).unwrap_err();
+    let errors = report.iter().collect::<Vec<_>>();
+    assert_eq!(errors.len(), 1);
+    let (path, error) = errors[0];
+    // The index of an item in a `HashSet` depends on the iteration order.
+    let path = path.to_string();
+    assert!(["hash_set[0]", "hash_set[1]", "hash_set[2]"].contains(&path.as_str()));
+    assert_eq!(error.message(), "length is lower than 2");
+}
+
+#[test]
+fn set_dive_invalid() {
+    let value = Dive {
+        hash_set: HashSet::from([Item { name: "".into() }, Item { name: "a".into() }]),
+        btree_set: BTreeSet::from([Item { name: "a".into() }, Item { name: "".into() }]),
+    };
+    let report = value.validate(&()).unwrap_err();
+    let paths = report
+        .iter()
+        .map(|(path, _)| path.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(paths.len(), 2);
+    assert!(paths[0] == "hash_set[0].name" || paths[0] == "hash_set[1].name");
+    // `BTreeSet` iterates in order, so `""` comes first.
+    assert_eq!(paths[1], "btree_set[0].name");
+}
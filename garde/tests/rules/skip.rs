@@ -27,6 +27,7 @@ fn skip_valid() {
     util::check_ok(&[Enum::Struct { field: 50 }, Enum::Tuple(50)], &());
 }
 
+#[allow(dead_code)]
 #[derive(garde::Validate)]
 struct Foo {
     #[garde(skip)]
@@ -0,0 +1,44 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(starts_with_uppercase)]
+    field: &'a str,
+
+    #[garde(inner(starts_with_uppercase))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn starts_with_uppercase_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "Alice",
+                inner: &["Alice"],
+            },
+            Test {
+                field: "Élodie",
+                inner: &["ǅemal", "O'Neil"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn starts_with_uppercase_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "alice",
+                inner: &["alice"]
+            },
+            Test {
+                field: "",
+                inner: &["1st", "élodie"]
+            }
+        ],
+        &()
+    )
+}
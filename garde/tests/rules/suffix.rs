@@ -43,3 +43,43 @@ fn suffix_invalid() {
         &()
     )
 }
+
+const NEEDLES: &[&str] = &["foo", "bar"];
+
+#[derive(Debug, garde::Validate)]
+struct Multi<'a> {
+    #[garde(suffix(any = ["foo", "bar"]))]
+    any: &'a str,
+    #[garde(suffix(any = NEEDLES))]
+    any_path: &'a str,
+    #[garde(suffix(all = ["bar", "foobar"]))]
+    all: &'a str,
+    #[garde(inner(suffix(any = ["foo", "bar"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn multi_suffix_valid() {
+    util::check_ok(
+        &[Multi {
+            any: "_bar",
+            any_path: "_bar",
+            all: "foobar",
+            inner: &["_bar", "foo"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn multi_suffix_invalid() {
+    util::check_fail!(
+        &[Multi {
+            any: "baz",
+            any_path: "baz",
+            all: "foo",
+            inner: &["baz"],
+        }],
+        &()
+    )
+}
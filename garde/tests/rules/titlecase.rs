@@ -0,0 +1,44 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(titlecase)]
+    field: &'a str,
+
+    #[garde(inner(titlecase))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn titlecase_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "Alice",
+                inner: &["Ada Lovelace"],
+            },
+            Test {
+                field: "Jean-Luc McDonald 3rd",
+                inner: &["Émile  Zola", ""],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn titlecase_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "alice",
+                inner: &["alice"]
+            },
+            Test {
+                field: "Ada lovelace",
+                inner: &["Vincent van Gogh"]
+            }
+        ],
+        &()
+    )
+}
@@ -0,0 +1,51 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Name<'a>(#[garde(length(min = 3))] &'a str);
+
+#[derive(Debug, garde::Validate)]
+struct Port(#[garde(range(min = 1024))] u16);
+
+#[derive(garde::Validate)]
+struct Registry<'a> {
+    #[garde(dive)]
+    plugins: Vec<Box<dyn Validate<Context = ()> + 'a>>,
+    #[garde(dive)]
+    shared: Rc<dyn Validate<Context = ()> + 'a>,
+    #[garde(dive)]
+    sync: Arc<dyn Validate<Context = ()> + 'a>,
+}
+
+#[test]
+fn trait_object_valid() {
+    let plugins: Vec<Box<dyn Validate<Context = ()>>> =
+        vec![Box::new(Name("test")), Box::new(Port(8080))];
+    assert!(plugins.validate(&()).is_ok());
+
+    let registry = Registry {
+        plugins,
+        shared: Rc::new(Name("test")),
+        sync: Arc::new(Port(8080)),
+    };
+    assert!(registry.validate(&()).is_ok());
+}
+
+#[test]
+fn trait_object_invalid() {
+    let plugins: Vec<Box<dyn Validate<Context = ()>>> = vec![
+        Box::new(Name("a")),
+        Box::new(Port(80)),
+        Box::new(Name("test")),
+    ];
+    insta::assert_snapshot!(plugins.validate(&()).unwrap_err().to_string());
+
+    let registry = Registry {
+        plugins,
+        shared: Rc::new(Name("a")),
+        sync: Arc::new(Port(80)),
+    };
+    insta::assert_snapshot!(registry.validate(&()).unwrap_err().to_string());
+}
@@ -0,0 +1,59 @@
+use super::util;
+
+struct Limits {
+    max: usize,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits as ctx))]
+struct Length(#[garde(range(max = ctx.max))] usize);
+
+/// Delegates to `Length`, including its context.
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Meters(Length);
+
+/// Validates its field at the path of the container.
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+#[garde(context(Limits))]
+struct Name<'a> {
+    #[garde(length(min = 1))]
+    value: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+struct Route<'a> {
+    #[garde(dive)]
+    distance: Meters,
+    #[garde(dive)]
+    name: Name<'a>,
+}
+
+#[test]
+fn transparent_valid() {
+    util::check_ok(
+        &[Route {
+            distance: Meters(Length(10)),
+            name: Name { value: "a" },
+        }],
+        &Limits { max: 10 },
+    )
+}
+
+#[test]
+fn transparent_invalid() {
+    util::check_fail!(
+        &[Route {
+            distance: Meters(Length(11)),
+            name: Name { value: "" },
+        }],
+        &Limits { max: 10 }
+    )
+}
+
+#[test]
+fn transparent_top_level_invalid() {
+    util::check_fail!(&[Meters(Length(11))], &Limits { max: 10 })
+}
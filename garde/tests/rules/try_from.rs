@@ -0,0 +1,28 @@
+#[derive(Debug, garde::Validate)]
+#[garde(try_from)]
+struct Username<'a>(#[garde(length(min = 3))] &'a str);
+
+#[derive(Debug, garde::Validate)]
+#[garde(try_from)]
+struct Email<'a> {
+    #[garde(length(min = 3), contains("@"))]
+    value: &'a str,
+}
+
+#[test]
+fn try_from_valid() {
+    let username = Username::try_from("username").unwrap();
+    assert_eq!(username.0, "username");
+
+    let email: Email = "test@mail.com".try_into().unwrap();
+    assert_eq!(email.value, "test@mail.com");
+}
+
+#[test]
+fn try_from_invalid() {
+    let report = Username::try_from("me").unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+
+    let report = Email::try_from("me").unwrap_err();
+    insta::assert_snapshot!(report.to_string());
+}
@@ -0,0 +1,44 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(uppercase)]
+    field: &'a str,
+
+    #[garde(inner(uppercase))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn uppercase_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "USERNAME_01",
+                inner: &["USERNAME_01"],
+            },
+            Test {
+                field: "STRASSE Ǆ Σ",
+                inner: &[""],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn uppercase_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "UserName",
+                inner: &["UserName"]
+            },
+            Test {
+                field: "STRAßE",
+                inner: &["STRAßE"]
+            }
+        ],
+        &()
+    )
+}
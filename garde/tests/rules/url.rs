@@ -107,3 +107,208 @@ fn url_valid_wrapper() {
     };
     println!("{:?}", value.validate(&()).unwrap_err());
 }
+
+#[derive(Debug, Validate)]
+struct Schemes<'a> {
+    #[garde(url(schemes = ["http", "https"]))]
+    field: &'a str,
+    #[garde(url(schemes = ["https"]))]
+    typed: url::Url,
+    #[garde(url(schemes = ["https"]))]
+    optional: Option<url::Url>,
+    #[garde(inner(url(schemes = ["ftp"])))]
+    inner: &'a [&'a str],
+}
+
+fn parse(url: &str) -> url::Url {
+    url::Url::parse(url).unwrap()
+}
+
+#[test]
+fn url_schemes_valid() {
+    util::check_ok(
+        &[
+            Schemes {
+                field: "http://example.com",
+                typed: parse("https://example.com"),
+                optional: None,
+                inner: &[],
+            },
+            Schemes {
+                field: "HTTPS://example.com",
+                typed: parse("https://example.com/a?b=c"),
+                optional: Some(parse("https://example.com")),
+                inner: &["ftp://example.com"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn url_schemes_invalid() {
+    util::check_fail!(
+        &[
+            Schemes {
+                field: "ftp://example.com",
+                typed: parse("http://example.com"),
+                optional: Some(parse("mailto:test@example.com")),
+                inner: &["http://example.com", "ftp://example.com"],
+            },
+            Schemes {
+                field: "not a url",
+                typed: parse("https://example.com"),
+                optional: None,
+                inner: &["ftp:/ /"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[derive(Debug, Validate)]
+struct Typed {
+    #[garde(url)]
+    field: url::Url,
+}
+
+#[test]
+fn url_typed_valid() {
+    util::check_ok(
+        &[Typed {
+            field: parse("file:///etc/hosts"),
+        }],
+        &(),
+    )
+}
+
+#[derive(Debug, Validate)]
+struct Hosts<'a> {
+    #[garde(url(host = ["cdn.example.com", "static.example.com"]))]
+    host: &'a str,
+    #[garde(url(host_suffix = [".example.com"]))]
+    suffix: &'a str,
+    #[garde(url(schemes = ["https"], host = ["example.com"], host_suffix = [".example.com"]))]
+    combined: url::Url,
+}
+
+#[test]
+fn url_hosts_valid() {
+    util::check_ok(
+        &[
+            Hosts {
+                host: "https://cdn.example.com/image.png",
+                suffix: "http://a.b.example.com",
+                combined: parse("https://example.com"),
+            },
+            Hosts {
+                host: "https://STATIC.example.com",
+                suffix: "https://www.Example.com:8080",
+                combined: parse("https://cdn.example.com"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn url_hosts_invalid() {
+    util::check_fail!(
+        &[
+            Hosts {
+                host: "https://evil.com/cdn.example.com",
+                suffix: "https://example.com",
+                combined: parse("http://example.com"),
+            },
+            Hosts {
+                host: "https://cdn.example.com.evil.com",
+                suffix: "https://evilexample.com",
+                combined: parse("https://127.0.0.1"),
+            },
+            Hosts {
+                host: "mailto:admin@cdn.example.com",
+                suffix: "not a url",
+                combined: parse("https://example.com.evil.com"),
+            },
+        ],
+        &(),
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct MaxLen<'a> {
+    #[garde(url)]
+    default: &'a str,
+    #[garde(url(max_len = 24))]
+    short: &'a str,
+    #[garde(url(schemes = ["https"], max_len = 24))]
+    combined: Option<&'a str>,
+}
+
+#[test]
+fn url_max_len_valid() {
+    util::check_ok(
+        &[MaxLen {
+            default: "https://example.com",
+            short: "https://example.com/a",
+            combined: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn url_max_len_invalid() {
+    util::check_fail!(
+        &[MaxLen {
+            default: "https://example.com",
+            short: "https://example.com/too/long",
+            combined: Some("https://example.com/too/long"),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn url_oversized_input_fails_before_parsing() {
+    let huge = format!("https://example.com/{}", "a".repeat(10_000_000));
+    let value = MaxLen {
+        default: &huge,
+        short: "https://example.com",
+        combined: None,
+    };
+    let start = std::time::Instant::now();
+    let report = garde::Validate::validate(&value, &()).unwrap_err();
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    let (path, error) = report.iter().next().unwrap();
+    assert_eq!(path.to_string(), "default");
+    assert_eq!(error.code(), Some("url.length"));
+    assert_eq!(error.message(), "url is longer than 2048 bytes");
+}
+
+/// Implements only the required method of `Url`, which keeps working with `#[garde(url)]`.
+#[derive(Debug)]
+struct Link(bool);
+
+impl garde::rules::url::Url for Link {
+    type Error = &'static str;
+
+    fn validate_url(&self) -> Result<(), Self::Error> {
+        match self.0 {
+            true => Ok(()),
+            false => Err("broken link"),
+        }
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Custom {
+    #[garde(url)]
+    link: Link,
+}
+
+#[test]
+fn url_custom_impl() {
+    util::check_ok(&[Custom { link: Link(true) }], &());
+    util::check_fail!(&[Custom { link: Link(false) }], &());
+}
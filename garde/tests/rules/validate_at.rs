@@ -0,0 +1,75 @@
+use garde::{Path, Report, Validate};
+
+#[derive(Debug, garde::Validate)]
+struct Item<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(dive)]
+    children: Vec<Item<'a>>,
+}
+
+#[test]
+fn validate_at_prefixes_paths() {
+    let items = [
+        Item {
+            name: "",
+            children: vec![],
+        },
+        Item {
+            name: "a",
+            children: vec![Item {
+                name: "",
+                children: vec![],
+            }],
+        },
+    ];
+
+    let mut report = Report::new();
+    for (i, item) in items.iter().enumerate() {
+        item.validate_at(&(), &mut report, Path::new("batch").join(i));
+    }
+    assert_eq!(
+        report.to_string(),
+        "batch[0].name: length is lower than 1\nbatch[1].children[0].name: length is lower than 1\n"
+    );
+}
+
+#[test]
+fn validate_at_empty_path_matches_validate() {
+    let item = Item {
+        name: "",
+        children: vec![Item {
+            name: "",
+            children: vec![],
+        }],
+    };
+
+    let mut report = Report::new();
+    item.validate_at(&(), &mut report, Path::empty());
+    assert_eq!(report, item.validate(&()).unwrap_err());
+}
+
+#[test]
+fn validate_at_reuses_report() {
+    let items = [
+        Item {
+            name: "",
+            children: vec![],
+        },
+        Item {
+            name: "a",
+            children: vec![],
+        },
+    ];
+
+    let mut report = Report::new();
+    let mut failed = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        report.clear();
+        item.validate_at(&(), &mut report, Path::empty());
+        if !report.is_empty() {
+            failed.push((i, report.to_string()));
+        }
+    }
+    assert_eq!(failed, [(0, "name: length is lower than 1\n".to_string())]);
+}
@@ -0,0 +1,96 @@
+use garde::{Error, Path, Report, Validate, ValidationOptions};
+
+struct NoDebug(String);
+
+impl garde::rules::AsStr for NoDebug {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(length(min = 3))]
+    name: String,
+    #[garde(range(min = 18))]
+    age: u8,
+    #[garde(inner(ascii))]
+    tags: Vec<String>,
+    #[garde(sensitive, length(min = 8), not(contains("pass")))]
+    password: String,
+    #[garde(sensitive, inner(length(min = 4)))]
+    pins: Vec<String>,
+    #[garde(ascii)]
+    no_debug: NoDebug,
+    #[garde(custom(fail))]
+    custom: String,
+    #[garde(length(max = 4))]
+    long: String,
+}
+
+fn fail(_: &str, _: &()) -> garde::Result {
+    Err(Error::new("always fails"))
+}
+
+fn invalid() -> Test {
+    Test {
+        name: "ab".into(),
+        age: 17,
+        tags: vec!["ok".into(), "😂".into()],
+        password: "pass".into(),
+        pins: vec!["123".into()],
+        no_debug: NoDebug("😂".into()),
+        custom: "value".into(),
+        long: "x".repeat(100),
+    }
+}
+
+fn values(report: &Report) -> Vec<(String, Option<&str>)> {
+    report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.value_debug()))
+        .collect()
+}
+
+#[test]
+fn value_debug_captured() {
+    let mut report = Report::new().with_value_debug();
+    invalid().validate_into(&(), &mut Path::empty, &mut report);
+
+    let truncated = format!("\"{}…", "x".repeat(Error::VALUE_DEBUG_MAX_LEN - 1));
+    assert_eq!(
+        values(&report),
+        [
+            ("name".to_string(), Some(r#""ab""#)),
+            ("age".to_string(), Some("17")),
+            ("tags[1]".to_string(), Some(r#""😂""#)),
+            ("password".to_string(), None),
+            ("password".to_string(), None),
+            ("pins[0]".to_string(), None),
+            ("no_debug".to_string(), None),
+            ("custom".to_string(), None),
+            ("long".to_string(), Some(truncated.as_str())),
+        ]
+    );
+}
+
+#[test]
+fn value_debug_off_by_default() {
+    let report = invalid().validate(&()).unwrap_err();
+    assert!(report
+        .iter()
+        .all(|(_, error)| error.value_debug().is_none()));
+}
+
+#[test]
+fn value_debug_via_options() {
+    let options = ValidationOptions {
+        value_debug: true,
+        ..Default::default()
+    };
+    let report = invalid().validate_with_options(&(), &options).unwrap_err();
+
+    let mut expected = Report::new().with_value_debug();
+    invalid().validate_into(&(), &mut Path::empty, &mut expected);
+    assert_eq!(values(&report), values(&expected));
+}
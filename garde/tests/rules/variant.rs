@@ -0,0 +1,67 @@
+use super::util;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Status {
+    Active,
+    Suspended { reason: &'static str },
+    Deleted(u64),
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Wrapper<'a, T> {
+    Some(&'a T),
+    None,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(variant(Active))]
+    status: Status,
+    #[garde(variant(Suspended))]
+    by_ref: &'a Status,
+    #[garde(variant(Active))]
+    optional: Option<Status>,
+    #[garde(variant(Some))]
+    generic: Wrapper<'a, u32>,
+    #[garde(inner(variant(Status::Active)))]
+    inner: Vec<Status>,
+}
+
+#[test]
+fn variant_valid() {
+    util::check_ok(
+        &[
+            Test {
+                status: Status::Active,
+                by_ref: &Status::Suspended { reason: "spam" },
+                optional: Some(Status::Active),
+                generic: Wrapper::Some(&0),
+                inner: vec![Status::Active],
+            },
+            Test {
+                status: Status::Active,
+                by_ref: &Status::Suspended { reason: "" },
+                optional: None,
+                generic: Wrapper::Some(&1),
+                inner: vec![],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn variant_invalid() {
+    util::check_fail!(
+        &[Test {
+            status: Status::Suspended { reason: "spam" },
+            by_ref: &Status::Active,
+            optional: Some(Status::Deleted(1)),
+            generic: Wrapper::None,
+            inner: vec![Status::Active, Status::Deleted(2)],
+        }],
+        &()
+    )
+}
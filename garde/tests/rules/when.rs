@@ -0,0 +1,129 @@
+use garde::Validate;
+
+use super::util;
+
+struct Config {
+    require_phone: bool,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Config as ctx))]
+struct Address<'a> {
+    #[garde(length(min = 2, max = 2))]
+    country: &'a str,
+    #[garde(when(self.country == "US"), required, length(min = 2))]
+    state: Option<&'a str>,
+    #[garde(skip_if(self.country == "US" || self.state.is_none()), length(max = 0))]
+    region: &'a str,
+    #[garde(when(ctx.require_phone), skip_if(self.country.is_empty()), required)]
+    phone: Option<&'a str>,
+}
+
+#[test]
+fn when_valid() {
+    util::check_ok(
+        &[
+            Address {
+                country: "US",
+                state: Some("CA"),
+                region: "West",
+                phone: Some("555"),
+            },
+            Address {
+                country: "DE",
+                state: None,
+                region: "anything",
+                phone: Some("555"),
+            },
+        ],
+        &Config {
+            require_phone: true,
+        },
+    );
+    util::check_ok(
+        &[Address {
+            country: "FR",
+            state: None,
+            region: "",
+            phone: None,
+        }],
+        &Config {
+            require_phone: false,
+        },
+    )
+}
+
+#[test]
+fn when_invalid() {
+    util::check_fail!(
+        &[
+            Address {
+                country: "US",
+                state: None,
+                region: "",
+                phone: None,
+            },
+            Address {
+                country: "DE",
+                state: Some("B"),
+                region: "West",
+                phone: None,
+            },
+        ],
+        &Config {
+            require_phone: true,
+        }
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Post<'a> {
+    #[garde(skip)]
+    premium: bool,
+    #[garde(
+        when(self.premium, code = "premium.limit", label = "Premium body"),
+        length(max = 10)
+    )]
+    body: &'a str,
+    #[garde(length(max = 5), code = "title.limit")]
+    title: &'a str,
+}
+
+#[test]
+fn when_code_and_label() {
+    let value = Post {
+        premium: true,
+        body: "more than ten characters",
+        title: "too long",
+    };
+    let errors = value
+        .validate(&())
+        .unwrap_err()
+        .iter()
+        .map(|(path, error)| {
+            (
+                path.to_string(),
+                error.code().map(String::from),
+                error.label().map(String::from),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        [
+            (
+                "body".into(),
+                Some("premium.limit".into()),
+                Some("Premium body".into())
+            ),
+            ("title".into(), Some("title.limit".into()), None),
+        ]
+    );
+
+    let value = Post {
+        premium: false,
+        body: "more than ten characters",
+        title: "",
+    };
+    assert!(value.validate(&()).is_ok());
+}
@@ -0,0 +1,60 @@
+use super::util;
+
+const MAX: usize = 4;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(words(min = 5))]
+    field: &'a str,
+
+    #[garde(words(min = 1, max = MAX))]
+    field_const: &'a str,
+
+    #[garde(words(min = 2, max = 3, separator = ","))]
+    field_separator: &'a str,
+
+    #[garde(inner(words(max = 2)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn words_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "one two three four five",
+                field_const: "one",
+                field_separator: "a b, c d",
+                inner: &["", "one two"],
+            },
+            Test {
+                field: " one\ttwo\nthree\u{3000}four  five six ",
+                field_const: "one two three four",
+                field_separator: "a,, b , c,",
+                inner: &["one  ", "  two"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn words_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "only three words",
+                field_const: "   ",
+                field_separator: "a b c d",
+                inner: &["one two three"],
+            },
+            Test {
+                field: "",
+                field_const: "one two three four five",
+                field_separator: "a,b,c,d",
+                inner: &["one two", "a b c d e"],
+            },
+        ],
+        &()
+    )
+}
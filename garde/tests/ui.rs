@@ -6,20 +6,43 @@ const COMPILE_FAIL_TESTS: &str =
     concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ui/compile-fail/**/*.rs");
 const COMPILE_PASS_TESTS: &str =
     concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ui/compile-pass/**/*.rs");
+const ALL_ENGINES_TESTS: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ui/all-engines/**/*.rs");
 
 fn matches(path: &Path, pat: &[String]) -> bool {
     pat.iter()
         .any(|pat| path.as_os_str().to_string_lossy().contains(pat))
 }
 
+/// Tests whose expected output lists the implementations of `Matcher` or `AsStr`,
+/// which include the `Regex` types of the enabled regex engines.
+///
+/// The output in `compile-fail` is for the `regex` engine on its own,
+/// and the one in `all-engines` for all of `regex`, `regex-lite` and `fancy-regex`.
+const REGEX_ENGINE_TESTS: &[&str] = &["pattern_mismatched_types", "url_wrong_type"];
+
+const ONLY_REGEX: bool = cfg!(all(
+    feature = "regex",
+    not(any(feature = "regex-lite", feature = "fancy-regex"))
+));
+const ALL_ENGINES: bool = cfg!(all(
+    feature = "regex",
+    feature = "regex-lite",
+    feature = "fancy-regex"
+));
+
 fn get_filter() -> Vec<String> {
-    std::env::var("EXCLUDE_UI_TESTS")
+    let mut filter = std::env::var("EXCLUDE_UI_TESTS")
         .unwrap_or_default()
         .split(',')
         .map(|v| v.trim())
         .filter(|v| !v.is_empty())
         .map(String::from)
-        .collect::<Vec<_>>()
+        .collect::<Vec<_>>();
+    if !ONLY_REGEX {
+        filter.extend(REGEX_ENGINE_TESTS.iter().map(|v| v.to_string()));
+    }
+    filter
 }
 
 fn get_tests<'a>(path_pattern: &str, filter: &'a [String]) -> impl Iterator<Item = PathBuf> + 'a {
@@ -35,4 +58,11 @@ fn ui() {
     let t = trybuild::TestCases::new();
     get_tests(COMPILE_FAIL_TESTS, &filter).for_each(|test| t.compile_fail(test));
     get_tests(COMPILE_PASS_TESTS, &filter).for_each(|test| t.pass(test));
+    if ALL_ENGINES {
+        get_tests(ALL_ENGINES_TESTS, &[]).for_each(|test| t.compile_fail(test));
+    } else if !ONLY_REGEX {
+        eprintln!(
+            "skipping {REGEX_ENGINE_TESTS:?}, their output is only recorded for `regex` on its own or all engines"
+        );
+    }
 }
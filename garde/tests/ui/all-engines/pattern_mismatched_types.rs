@@ -0,0 +1,9 @@
+static STR: &str = r"a|b";
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(pattern(STR))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(url)]
+    field: u32,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(alphanumeric(allow = "-"))]
+    field: &'a str,
+}
+
+fn main() {}
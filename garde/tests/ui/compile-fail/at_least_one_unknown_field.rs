@@ -0,0 +1,8 @@
+#[derive(garde::Validate)]
+#[garde(at_least_one(email, phone))]
+struct Test {
+    #[garde(skip)]
+    email: Option<String>,
+}
+
+fn main() {}
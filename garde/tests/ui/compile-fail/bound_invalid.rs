@@ -0,0 +1,8 @@
+#[derive(garde::Validate)]
+#[garde(bound = "T garde::Validate")]
+struct Test<T> {
+    #[garde(dive)]
+    field: T,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(checksum(algo = "mod97"))]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(custom(check, params(1)))]
+    field: u32,
+}
+
+fn check(_: &u32, _: &(), _: u32) -> garde::Result {
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(custom(|_: &u32, _: &()| Ok(()), with_self))]
+    field: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(garde::Validate)]
+#[garde(default_rules(text(ascii)))]
+struct Test {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+fn main() {}
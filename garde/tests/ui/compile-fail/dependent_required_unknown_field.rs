@@ -0,0 +1,10 @@
+#[derive(garde::Validate)]
+#[garde(dependent_required(if = self.has_company, then = company))]
+struct Test {
+    #[garde(skip)]
+    has_company: bool,
+    #[garde(skip)]
+    company_name: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#[derive(garde::Discriminants)]
+enum Color {
+    Red,
+    Green,
+}
+
+#[derive(garde::Discriminants)]
+#[repr(u8)]
+enum Shape {
+    Circle(u8),
+    Square,
+}
+
+fn main() {}
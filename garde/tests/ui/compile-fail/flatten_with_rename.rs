@@ -0,0 +1,15 @@
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(length(min = 1))]
+    value: String,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(flatten, rename("other"))]
+    inner: Inner,
+    #[garde(flatten, length(min = 1))]
+    list: Vec<Inner>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(fs_path())]
+    field: std::path::PathBuf,
+}
+
+fn main() {}
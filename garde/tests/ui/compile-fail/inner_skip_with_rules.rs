@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(inner(skip, length(min = 1)))]
+    field: Vec<String>,
+}
+
+fn main() {}
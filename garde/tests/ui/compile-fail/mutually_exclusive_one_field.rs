@@ -0,0 +1,8 @@
+#[derive(garde::Validate)]
+#[garde(mutually_exclusive(email))]
+struct Test {
+    #[garde(skip)]
+    email: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Modifiers {
+    #[garde(label = "Name")]
+    name: String,
+    #[garde(when(self.name.is_empty()))]
+    nickname: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(not(dive))]
+    field: Inner,
+    #[garde(not(length(min = 1)))]
+    other: String,
+}
+
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+fn main() {}
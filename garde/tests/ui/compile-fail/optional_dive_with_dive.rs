@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Sub {
+    #[garde(skip)]
+    v: u32,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(dive, optional(dive))]
+    field: Option<Sub>,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(pattern(r"^(?P<year>\d{4})-(?P<month>\d{2})$", require = ["year", "day"]))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(prefix(any = []))]
+    a: &'a str,
+    #[garde(suffix(all = []))]
+    b: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(min = 10, combined_message))]
+    field: u64,
+    #[garde(length(min = 1, max = 10, combined_message))]
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(min = -1, max = 10))]
+    field: u64,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range())]
+    field: u64,
+}
+
+fn main() {}
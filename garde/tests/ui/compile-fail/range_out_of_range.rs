@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(max = 256))]
+    field: Option<u8>,
+}
+
+fn main() {}
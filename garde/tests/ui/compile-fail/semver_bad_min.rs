@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(semver(min = "1.0"))]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(length(min = 100, max = 10))]
+    inverted: String,
+    #[garde(byte_length())]
+    no_bounds: String,
+    #[garde(fs_path())]
+    no_args: PathBuf,
+    #[garde(range(min = 1), ascii, range(max = 10))]
+    duplicate: u64,
+}
+
+fn main() {}
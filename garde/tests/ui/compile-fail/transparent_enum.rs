@@ -0,0 +1,7 @@
+#[derive(garde::Validate)]
+#[garde(transparent)]
+enum Test {
+    A(#[garde(skip)] u32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(garde::Validate)]
+#[garde(try_from)]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    a: &'a str,
+    #[garde(length(min = 1))]
+    b: &'a str,
+}
+
+fn main() {}
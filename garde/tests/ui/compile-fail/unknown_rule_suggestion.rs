@@ -0,0 +1,21 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(lenght(min = 1))]
+    string: &'a str,
+    #[garde(rnage(min = 1))]
+    number: Option<u32>,
+    #[garde(inner(alphanumric))]
+    list: Vec<String>,
+    #[garde(dive, kyes(length(max = 32)))]
+    map: std::collections::HashMap<String, Inner>,
+    #[garde(unknown_rule)]
+    other: Inner,
+}
+
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(skip)]
+    v: (),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+enum Status {
+    Active,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(inner(variant(Active)))]
+    inner: Vec<Status>,
+}
+
+fn main() {}
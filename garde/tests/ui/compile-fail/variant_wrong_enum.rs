@@ -0,0 +1,11 @@
+enum Status {
+    Active,
+}
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(variant(Deleted))]
+    status: Status,
+}
+
+fn main() {}
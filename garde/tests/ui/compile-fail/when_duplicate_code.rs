@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(skip)]
+    premium: bool,
+    #[garde(when(self.premium, code = "premium.limit"), length(max = 10), code("limit"))]
+    body: String,
+}
+
+fn main() {}
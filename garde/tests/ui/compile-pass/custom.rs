@@ -61,3 +61,15 @@ struct Baz {
 }
 
 fn main() {}
+
+#[derive(garde::Validate)]
+#[garde(custom(custom_validate_container))]
+#[garde(custom(|_, _| Ok(())))]
+struct Container<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+fn custom_validate_container(_: &Container, _: &()) -> Result<(), garde::Error> {
+    Ok(())
+}
@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+#[garde(try_from)]
+struct Username(#[garde(length(min = 3))] String);
+
+#[derive(garde::Validate)]
+#[garde(try_from)]
+struct Email<'a> {
+    #[garde(length(min = 3))]
+    value: &'a str,
+}
+
+#[derive(Default)]
+struct Limits {
+    min: usize,
+}
+
+// should also work with a context which implements `Default`
+#[derive(garde::Validate)]
+#[garde(try_from)]
+#[garde(context(Limits as ctx))]
+struct Name(#[garde(length(min = ctx.min))] String);
+
+fn main() {
+    let _: Result<Username, garde::Report> = Username::try_from(String::from("username"));
+    let _: Result<Email, garde::Report> = "test@mail.com".try_into();
+    let _: Result<Name, garde::Report> = Name::try_from(String::from("name"));
+}
@@ -1,8 +1,9 @@
 use std::collections::BTreeSet;
 
-use proc_macro2::Span;
+use proc_macro2::{Ident, Span};
 use syn::parse_quote;
 use syn::spanned::Spanned;
+use syn::Expr;
 
 use crate::model;
 use crate::util::{default_ctx_name, MaybeFoldError};
@@ -10,7 +11,7 @@ use crate::util::{default_ctx_name, MaybeFoldError};
 pub fn check(input: model::Input) -> syn::Result<model::Validate> {
     let model::Input {
         ident,
-        generics,
+        mut generics,
         attrs,
         kind,
     } = input;
@@ -30,6 +31,31 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
     };
 
     let options = get_options(&attrs);
+    let mut container_rules = Vec::new();
+    for (span, attr) in &attrs {
+        match attr {
+            model::Attr::Custom(expr) => {
+                container_rules.push(model::ContainerRule::Custom((**expr).clone()))
+            }
+            model::Attr::FieldGroup(group) => {
+                if let Err(e) = check_field_group(&kind, *span, group) {
+                    error.maybe_fold(e);
+                }
+                container_rules.push(model::ContainerRule::FieldGroup(group.clone()));
+            }
+            model::Attr::DependentRequired(rule) => {
+                if let Err(e) = check_dependent_required(&kind, *span, rule) {
+                    error.maybe_fold(e);
+                }
+                container_rules.push(model::ContainerRule::DependentRequired((**rule).clone()));
+            }
+            model::Attr::Bound(predicates) => generics
+                .make_where_clause()
+                .predicates
+                .extend(predicates.iter().cloned()),
+            _ => {}
+        }
+    }
 
     let kind = match kind {
         model::InputKind::Struct(variant) => {
@@ -58,24 +84,145 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         }
     };
 
+    if let Some(span) = options.try_from {
+        if let Err(e) = check_single_field(&kind, span, "try_from") {
+            error.maybe_fold(e);
+        }
+    }
+
+    if let Some(span) = options.transparent {
+        if let Err(e) = check_single_field(&kind, span, "transparent") {
+            error.maybe_fold(e);
+        }
+    }
+
     if let Some(error) = error {
         return Err(error);
     }
 
+    let context = match (options.transparent, has_context(&attrs)) {
+        (Some(_), false) => transparent_context(&kind).unwrap_or(context),
+        _ => context,
+    };
+
     Ok(model::Validate {
         ident,
         generics,
         context,
         kind,
+        container_rules,
         options,
     })
 }
 
+fn check_single_field(kind: &model::ValidateKind, span: Span, name: &str) -> syn::Result<()> {
+    match kind {
+        model::ValidateKind::Struct(model::ValidateVariant::Struct(fields))
+            if fields.len() == 1 =>
+        {
+            Ok(())
+        }
+        model::ValidateKind::Struct(model::ValidateVariant::Tuple(fields)) if fields.len() == 1 => {
+            Ok(())
+        }
+        _ => Err(syn::Error::new(
+            span,
+            format!("`{name}` is only supported on structs with exactly one field"),
+        )),
+    }
+}
+
+fn check_field_group(
+    kind: &model::InputKind,
+    span: Span,
+    group: &model::FieldGroup,
+) -> syn::Result<()> {
+    let name = group.kind.name();
+    let model::InputKind::Struct(model::Variant::Struct(fields)) = kind else {
+        return Err(syn::Error::new(
+            span,
+            format!("`{name}` is only supported on structs with named fields"),
+        ));
+    };
+    let min = group.kind.min_fields();
+    if group.fields.len() < min {
+        let fields = if min == 1 { "field" } else { "fields" };
+        return Err(syn::Error::new(
+            span,
+            format!("`{name}` requires at least {min} {fields}"),
+        ));
+    }
+
+    let mut error = None;
+    for name in &group.fields {
+        if !fields.iter().any(|(ident, _)| ident == name) {
+            error.maybe_fold(syn::Error::new(
+                name.span(),
+                format!("unknown field `{name}`"),
+            ));
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+fn check_dependent_required(
+    kind: &model::InputKind,
+    span: Span,
+    rule: &model::DependentRequired,
+) -> syn::Result<()> {
+    let model::InputKind::Struct(model::Variant::Struct(fields)) = kind else {
+        return Err(syn::Error::new(
+            span,
+            "`dependent_required` is only supported on structs with named fields",
+        ));
+    };
+    let name = &rule.field;
+    if !fields.iter().any(|(ident, _)| ident == name) {
+        return Err(syn::Error::new(
+            name.span(),
+            format!("unknown field `{name}`"),
+        ));
+    }
+    Ok(())
+}
+
+fn has_context(attrs: &[(Span, model::Attr)]) -> bool {
+    attrs
+        .iter()
+        .any(|(_, attr)| matches!(attr, model::Attr::Context(..)))
+}
+
+/// A transparent wrapper which dives into its field uses the field's context,
+/// unless a `context` is given explicitly.
+fn transparent_context(kind: &model::ValidateKind) -> Option<(syn::Type, syn::Ident)> {
+    let field = match kind {
+        model::ValidateKind::Struct(model::ValidateVariant::Struct(fields)) => &fields[0].1,
+        model::ValidateKind::Struct(model::ValidateVariant::Tuple(fields)) => &fields[0],
+        model::ValidateKind::Enum(_) => return None,
+    };
+    field.dive?;
+    let ty = &field.ty;
+    Some((
+        parse_quote!(<#ty as ::garde::Validate>::Context),
+        default_ctx_name(),
+    ))
+}
+
 fn check_attrs(attrs: &[(Span, model::Attr)]) -> syn::Result<()> {
     let mut error = None;
 
     let mut set = BTreeSet::new();
     for (span, attr) in attrs {
+        if let model::Attr::Custom(..)
+        | model::Attr::FieldGroup(..)
+        | model::Attr::DependentRequired(..) = attr
+        {
+            continue;
+        }
         let d = attr.discriminant();
         if set.contains(&d) {
             error.maybe_fold(syn::Error::new(
@@ -118,12 +265,24 @@ fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<(syn::Type, syn::Id
 fn get_options(attrs: &[(Span, model::Attr)]) -> model::Options {
     let mut options = model::Options {
         allow_unvalidated: false,
+        try_from: None,
+        transparent: None,
+        code_prefix: None,
     };
 
-    for (_, attr) in attrs {
+    for (span, attr) in attrs {
         match attr {
             model::Attr::Context(..) => {}
             model::Attr::AllowUnvalidated => options.allow_unvalidated = true,
+            model::Attr::TryFrom => options.try_from = Some(*span),
+            model::Attr::Transparent => options.transparent = Some(*span),
+            model::Attr::Custom(..) => {}
+            model::Attr::FieldGroup(..) => {}
+            model::Attr::DependentRequired(..) => {}
+            model::Attr::CodePrefix(prefix) => options.code_prefix = Some(prefix.clone()),
+            model::Attr::Bound(..) => {}
+            // Default rules are applied to the fields while parsing.
+            model::Attr::DefaultRules(..) => {}
         }
     }
 
@@ -140,27 +299,29 @@ fn check_variant(
         model::Variant::Struct(map) => {
             let mut fields = Vec::new();
             for (ident, field) in map {
-                let field = match check_field(field, options) {
+                let mut field = match check_field(field, options) {
                     Ok(v) => v,
                     Err(e) => {
                         error.maybe_fold(e);
                         continue;
                     }
                 };
+                field.error_code = get_error_code(&field, &ident, options);
                 fields.push((ident, field))
             }
             model::ValidateVariant::Struct(fields)
         }
         model::Variant::Tuple(list) => {
             let mut fields = Vec::new();
-            for field in list {
-                let field = match check_field(field, options) {
+            for (index, field) in list.into_iter().enumerate() {
+                let mut field = match check_field(field, options) {
                     Ok(v) => v,
                     Err(e) => {
                         error.maybe_fold(e);
                         continue;
                     }
                 };
+                field.error_code = get_error_code(&field, &index, options);
                 fields.push(field);
             }
             model::ValidateVariant::Tuple(fields)
@@ -174,6 +335,35 @@ fn check_variant(
     Ok(variant)
 }
 
+/// A field-level `code` always wins over the container-level `code_prefix`,
+/// because it is already the exact code the user wants to see.
+fn get_error_code(
+    field: &model::ValidateField,
+    key: &dyn std::fmt::Display,
+    options: &model::Options,
+) -> Option<model::ErrorCode> {
+    match (&field.code, &options.code_prefix) {
+        (Some(code), _) => Some(model::ErrorCode::Override(code.clone())),
+        (None, Some(prefix)) => Some(model::ErrorCode::Prefix(format!("{prefix}.{key}"))),
+        (None, None) => None,
+    }
+}
+
+/// Whether `rules` consist of a single `skip`, possibly nested in `inner`, e.g. `inner(inner(skip))`.
+fn is_only_skip(rules: &[model::RawRule]) -> bool {
+    match rules {
+        [model::RawRule {
+            kind: model::RawRuleKind::Skip,
+            ..
+        }] => true,
+        [model::RawRule {
+            kind: model::RawRuleKind::Inner(inner),
+            ..
+        }] => is_only_skip(&inner.contents),
+        _ => false,
+    }
+}
+
 fn check_field(field: model::Field, options: &model::Options) -> syn::Result<model::ValidateField> {
     let mut error = None;
 
@@ -188,12 +378,24 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         alias: None,
         message: None,
         code: None,
+        error_code: None,
+        label: None,
+        when: None,
+        skip_if: None,
         dive: None,
+        flatten: None,
+        sensitive: None,
         rule_set: model::RuleSet::empty(),
     };
 
+    // `inner(skip)` on its own explicitly leaves the field unvalidated.
+    let only_skip = is_only_skip(&raw_rules);
+
     if raw_rules.is_empty() {
-        if options.allow_unvalidated {
+        if let Some(span) = options.transparent {
+            // A transparent wrapper delegates to its field by default.
+            field.dive = Some(span);
+        } else if options.allow_unvalidated {
             field.skip = Some(Span::call_site());
         } else {
             error.maybe_fold(syn::Error::new(
@@ -211,6 +413,37 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     };
 
+    if let Some(span) = field.flatten {
+        let conflict = if field.dive.is_some() {
+            Some("`flatten` may not be combined with `dive`")
+        } else if field.alias.is_some() {
+            Some("`flatten` may not be combined with `rename`, because the field does not appear in the path")
+        } else if !field.rule_set.is_empty() {
+            Some("`flatten` may not be combined with other rules")
+        } else {
+            None
+        };
+        match conflict {
+            Some(message) => error.maybe_fold(syn::Error::new(span, message)),
+            None => field.dive = Some(span),
+        }
+    }
+
+    if let Some(span) = options.transparent {
+        field.flatten = Some(span);
+    }
+
+    if field.skip.is_none() && field.is_empty() && error.is_none() {
+        if only_skip || options.allow_unvalidated {
+            field.skip = Some(Span::call_site());
+        } else {
+            error.maybe_fold(syn::Error::new(
+                field.ty.span(),
+                "field has no validation, use `#[garde(skip)]` if this is intentional",
+            ));
+        }
+    }
+
     if let Some(span) = field.skip {
         if !field.is_empty() {
             error.maybe_fold(syn::Error::new(
@@ -227,6 +460,14 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
                 "`dive` may not be combined with `inner`",
             ))
         }
+        if let Some(optional) = &field.rule_set.optional {
+            if optional.dive.is_some() {
+                error.maybe_fold(syn::Error::new(
+                    span,
+                    "`dive` may not be combined with `optional(dive)`",
+                ))
+            }
+        }
     }
 
     if let Some(error) = error {
@@ -282,7 +523,7 @@ fn check_rule(
         ($rule_set:ident, $rule:ident($($inner:expr)?), $span:expr) => {{
             let rule = model::ValidateRule::$rule$(($inner))?;
             let name = rule.name();
-            if !$rule_set.rules.insert(rule) {
+            if !$rule_set.insert(model::FieldRule::Rule(rule)) {
                 return Err(syn::Error::new($span, format!("duplicate rule `{name}`")));
             }
         }};
@@ -292,59 +533,311 @@ fn check_rule(
     use model::RawRuleKind::*;
     match raw_rule.kind {
         Skip => apply!(is_inner, field, skip, span, span),
+        SkipDefaults if is_inner => {
+            return Err(syn::Error::new(
+                span,
+                "rule `skip_defaults` may not be used in `inner`",
+            ))
+        }
+        // Default rules are removed while parsing, so this is a no-op.
+        SkipDefaults => {}
         Rename(alias) => apply!(is_inner, field, alias, alias.value, span),
         Message(message) => apply!(is_inner, field, message, message, span),
         Code(code) => apply!(is_inner, field, code, code.value, span),
-        Dive => apply!(is_inner, field, dive, span, span),
-        Custom(custom) => rule_set.custom_rules.push(custom),
+        Label(label) => apply!(is_inner, field, label, label.value, span),
+        When(when) => {
+            apply!(is_inner, field, when, when.condition, span);
+            // A field has at most one `when`, so its code and label apply to all of the field's errors.
+            if let Some(code) = when.code {
+                apply!(is_inner, field, code, code.value, code.span);
+            }
+            if let Some(label) = when.label {
+                apply!(is_inner, field, label, label.value, label.span);
+            }
+        }
+        SkipIf(expr) => apply!(is_inner, field, skip_if, expr, span),
+        Flatten => apply!(is_inner, field, flatten, span, span),
+        Sensitive => apply!(is_inner, field, sensitive, span, span),
+        Dive(options) => {
+            apply!(is_inner, field, dive, span, span);
+            if let Some(span) = options.and_then(|options| options.required) {
+                apply!(rule_set, Required(), span);
+            }
+        }
+        Custom(custom) => {
+            rule_set.insert(model::FieldRule::Custom(custom));
+        }
         Required => apply!(rule_set, Required(), span),
-        Ascii => apply!(rule_set, Ascii(), span),
-        Alphanumeric => apply!(rule_set, Alphanumeric(), span),
-        Email => apply!(rule_set, Email(), span),
-        Url => apply!(rule_set, Url(), span),
+        Ascii(Some(model::AsciiOptions::Printable)) => apply!(rule_set, AsciiPrintable(), span),
+        Ascii(Some(model::AsciiOptions::Extra(v))) => apply!(rule_set, Ascii(Some(v.expr)), span),
+        Ascii(None) => apply!(rule_set, Ascii(None), span),
+        Alphanumeric(v) => apply!(rule_set, Alphanumeric(v.map(|v| v.expr)), span),
+        Lowercase => apply!(rule_set, Lowercase(), span),
+        Uppercase => apply!(rule_set, Uppercase(), span),
+        StartsWithUppercase => apply!(rule_set, StartsWithUppercase(), span),
+        Titlecase => apply!(rule_set, Titlecase(), span),
+        NotBlank => apply!(rule_set, NotBlank(), span),
+        Email(v) => apply!(rule_set, Email(v), span),
+        Url(v) => apply!(rule_set, Url(v), span),
         Ip => apply!(rule_set, Ip(), span),
         IpV4 => apply!(rule_set, IpV4(), span),
         IpV6 => apply!(rule_set, IpV6(), span),
         CreditCard => apply!(rule_set, CreditCard(), span),
         PhoneNumber => apply!(rule_set, PhoneNumber(), span),
-        Length(v) => apply!(rule_set, Length(check_range_generic(v)?), span),
-        ByteLength(v) => apply!(rule_set, ByteLength(check_range_generic(v)?), span),
-        Range(v) => apply!(rule_set, Range(check_range_not_ord(v)?), span),
-        Contains(v) => apply!(rule_set, Contains(v), span),
-        Prefix(v) => apply!(rule_set, Prefix(v), span),
-        Suffix(v) => apply!(rule_set, Suffix(v), span),
-        Pattern(v) => apply!(rule_set, Pattern(check_regex(v)?), span),
-        Inner(v) => {
-            if rule_set.inner.is_none() {
-                rule_set.inner = Some(Box::new(model::RuleSet::empty()));
-            }
-
-            let mut error = None;
-            for raw_rule in v.contents {
-                if let Err(e) = check_rule(field, raw_rule, rule_set.inner.as_mut().unwrap(), true)
-                {
-                    error.maybe_fold(e);
+        Checksum(v) => apply!(rule_set, Checksum(v.algo), span),
+        Isbn => apply!(rule_set, Isbn(), span),
+        Imei => apply!(rule_set, Imei(), span),
+        E164 => apply!(rule_set, E164(), span),
+        Hostname => apply!(rule_set, Hostname(), span),
+        Luhn => apply!(rule_set, Luhn(), span),
+        Semver(v) => {
+            if let Some(options) = &v {
+                for bound in options.min.iter().chain(&options.max) {
+                    if let Err(e) = check_semver_literal(&bound.value) {
+                        return Err(syn::Error::new(
+                            bound.span,
+                            format!("invalid version `{}`: {e}", bound.value),
+                        ));
+                    }
                 }
             }
-            if let Some(error) = error {
-                return Err(error);
+            apply!(rule_set, Semver(v), span)
+        }
+        NoControlChars(v) => apply!(rule_set, NoControlChars(v.map(|v| v.expr)), span),
+        Base64(v) => apply!(rule_set, Base64(v.unwrap_or_default().decoded_len), span),
+        Hex(v) => apply!(rule_set, Hex(v.unwrap_or_default().decoded_len), span),
+        Length(v) => apply!(rule_set, Length(check_range_generic(v, span)?), span),
+        ByteLength(v) => apply!(rule_set, ByteLength(check_range_generic(v, span)?), span),
+        Words(v) => apply!(
+            rule_set,
+            Words((check_range_generic(v.range, span)?, v.separator)),
+            span
+        ),
+        Range(v) => {
+            let combined_message = v.combined_message;
+            let range = check_range_not_ord(v, span)?;
+            if let Some(span) = combined_message {
+                if !matches!(range, model::ValidateRange::Between(..)) {
+                    return Err(syn::Error::new(
+                        span,
+                        "`combined_message` requires both `min` and `max`",
+                    ));
+                }
             }
+            // Within `inner`, the type of the items isn't known.
+            let (range, integer) = match integer_type(&field.ty) {
+                Some(ty) if !is_inner => check_integer_bounds(range, ty)?,
+                _ => (range, None),
+            };
+            apply!(
+                rule_set,
+                Range((range, combined_message.is_some(), integer)),
+                span
+            )
+        }
+        Finite => apply!(rule_set, Finite(), span),
+        Numeric(v) => apply!(rule_set, Numeric(v.unwrap_or_default()), span),
+        Contains(v) => apply!(rule_set, Contains(check_needle(v)?), span),
+        ContainsKey(v) => apply!(rule_set, ContainsKey(v), span),
+        ContainsValue(v) => apply!(rule_set, ContainsValue(v), span),
+        Prefix(v) => apply!(rule_set, Prefix(check_needle(v)?), span),
+        Suffix(v) => apply!(rule_set, Suffix(check_needle(v)?), span),
+        Extension(v) => apply!(rule_set, Extension(v), span),
+        OneOf(v) => apply!(rule_set, OneOf(v), span),
+        Excludes(v) => apply!(rule_set, Excludes(v), span),
+        Pattern(v) => {
+            let require = check_pattern_groups(&v)?;
+            apply!(rule_set, Pattern((check_regex(v.pattern)?, require)), span)
         }
+        FsPath(v) => apply!(rule_set, FsPath(check_fs_path(v, span)?), span),
+        Date(v) => apply!(rule_set, Date(v), span),
+        Variant(v) => apply!(
+            rule_set,
+            Variant(check_enum_variant(&field.ty, v, is_inner)?),
+            span
+        ),
+        EnumValue(v) => apply!(rule_set, EnumValue(v), span),
+        Inner(v) => check_nested_rules(field, v, &mut rule_set.inner)?,
+        Keys(v) => check_nested_rules(field, v, &mut rule_set.keys)?,
+        Optional(v) => check_optional_rules(field, v, &mut rule_set.optional)?,
+        Not(v) => check_not(field, *v, rule_set, is_inner)?,
     };
 
     Ok(())
 }
 
-trait CheckRange: Sized {
-    fn check_range(self) -> syn::Result<model::ValidateRange<Self>>;
+fn check_not(
+    field: &mut model::ValidateField,
+    raw_rule: model::RawRule,
+    rule_set: &mut model::RuleSet,
+    is_inner: bool,
+) -> syn::Result<()> {
+    use model::RawRuleKind::*;
+    let span = raw_rule.span;
+    let name = match &raw_rule.kind {
+        Ascii(_) | Alphanumeric(_) | Lowercase | Uppercase | Email(_) | Url(_) | Ip | IpV4
+        | IpV6 | E164 | Hostname | Contains(_) | Prefix(_) | Suffix(_) | Extension(_)
+        | OneOf(_) => None,
+        Pattern(v) => v.require.as_ref().map(|_| "pattern(.., require = [..])"),
+        Skip => Some("skip"),
+        SkipDefaults => Some("skip_defaults"),
+        Rename(_) => Some("rename"),
+        Message(_) => Some("message"),
+        Code(_) => Some("code"),
+        Label(_) => Some("label"),
+        When(_) => Some("when"),
+        SkipIf(_) => Some("skip_if"),
+        Dive(_) => Some("dive"),
+        Flatten => Some("flatten"),
+        Sensitive => Some("sensitive"),
+        Required => Some("required"),
+        StartsWithUppercase => Some("starts_with_uppercase"),
+        Titlecase => Some("titlecase"),
+        NotBlank => Some("not_blank"),
+        Semver(_) => Some("semver"),
+        CreditCard => Some("credit_card"),
+        PhoneNumber => Some("phone_number"),
+        Checksum(_) => Some("checksum"),
+        Isbn => Some("isbn"),
+        Imei => Some("imei"),
+        Luhn => Some("luhn"),
+        NoControlChars(_) => Some("no_control_chars"),
+        Base64(_) => Some("base64"),
+        Hex(_) => Some("hex"),
+        Length(_) => Some("length"),
+        ByteLength(_) => Some("byte_length"),
+        Words(_) => Some("words"),
+        Range(_) => Some("range"),
+        Finite => Some("finite"),
+        Numeric(_) => Some("numeric"),
+        FsPath(_) => Some("fs_path"),
+        Date(_) => Some("date"),
+        Variant(_) => Some("variant"),
+        EnumValue(_) => Some("enum_value"),
+        Custom(_) => Some("custom"),
+        Inner(_) => Some("inner"),
+        Optional(_) => Some("optional"),
+        Keys(_) => Some("keys"),
+        ContainsKey(_) => Some("contains_key"),
+        ContainsValue(_) => Some("contains_value"),
+        Excludes(_) => Some("excludes"),
+        Not(_) => Some("not"),
+    };
+    if let Some(name) = name {
+        return Err(syn::Error::new(
+            span,
+            format!("rule `{name}` may not be used in `not`"),
+        ));
+    }
+
+    let mut negated = model::RuleSet::empty();
+    check_rule(field, raw_rule, &mut negated, is_inner)?;
+    for rule in negated.rules {
+        let model::FieldRule::Rule(rule) = rule else {
+            unreachable!("only built-in rules are allowed in `not`");
+        };
+        let name = rule.name();
+        if !rule_set.insert(model::FieldRule::Not(rule)) {
+            return Err(syn::Error::new(
+                span,
+                format!("duplicate rule `not({name})`"),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn check_nested_rules(
+    field: &mut model::ValidateField,
+    raw_rules: model::List<model::RawRule>,
+    rule_set: &mut Option<Box<model::RuleSet>>,
+) -> syn::Result<()> {
+    let rule_set = rule_set.get_or_insert_with(|| Box::new(model::RuleSet::empty()));
+
+    // `inner(skip)` explicitly leaves the items unvalidated, so it has no rules.
+    if let Some(skip) = raw_rules
+        .contents
+        .iter()
+        .find(|rule| matches!(rule.kind, model::RawRuleKind::Skip))
+    {
+        if raw_rules.contents.len() > 1 || !rule_set.is_empty() {
+            return Err(syn::Error::new(
+                skip.span,
+                "`skip` may not be combined with other rules",
+            ));
+        }
+        return Ok(());
+    }
+
+    let mut error = None;
+    for raw_rule in raw_rules.contents {
+        if let Err(e) = check_rule(field, raw_rule, rule_set, true) {
+            error.maybe_fold(e);
+        }
+    }
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Like [`check_nested_rules`], but also accepts `dive`, which validates the value of the `Option`.
+fn check_optional_rules(
+    field: &mut model::ValidateField,
+    raw_rules: model::List<model::RawRule>,
+    rule_set: &mut Option<Box<model::RuleSet>>,
+) -> syn::Result<()> {
+    let rule_set = rule_set.get_or_insert_with(|| Box::new(model::RuleSet::empty()));
+
+    let mut error = None;
+    for raw_rule in raw_rules.contents {
+        let result = match raw_rule.kind {
+            model::RawRuleKind::Dive(options) => {
+                if let Some(span) = options.and_then(|options| options.required) {
+                    Err(syn::Error::new(
+                        span,
+                        "`required` may not be used in `optional`, put it on the field instead",
+                    ))
+                } else if rule_set.dive.is_some() {
+                    Err(syn::Error::new(raw_rule.span, "duplicate rule `dive`"))
+                } else {
+                    rule_set.dive = Some(raw_rule.span);
+                    Ok(())
+                }
+            }
+            _ => check_rule(field, raw_rule, rule_set, true),
+        };
+        if let Err(e) = result {
+            error.maybe_fold(e);
+        }
+    }
+    if let (Some(span), Some(_)) = (rule_set.dive, &rule_set.inner) {
+        error.maybe_fold(syn::Error::new(
+            span,
+            "`dive` may not be combined with `inner`",
+        ));
+    }
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
 }
 
 fn check_range_generic<L, R>(
     range: model::Range<model::Either<L, R>>,
+    rule_span: Span,
 ) -> syn::Result<model::ValidateRange<model::Either<L, R>>>
 where
     L: PartialOrd,
 {
+    if let Some(span) = range.combined_message {
+        return Err(syn::Error::new(
+            span,
+            "`combined_message` is only supported by `range`",
+        ));
+    }
+
     macro_rules! map_validate_range {
         ($value:expr, $wrapper:expr) => {{
             match $value {
@@ -359,90 +852,465 @@ where
         }};
     }
 
-    let range = match (range.span, range.min, range.max) {
-        (span, Some(model::Either::Left(min)), Some(model::Either::Left(max))) => {
+    let min_span = range.min_span;
+    let range = match (range.min, range.max) {
+        (Some(model::Either::Left(min)), Some(model::Either::Left(max))) => {
             map_validate_range!(
-                check_range(model::Range {
-                    span,
-                    min: Some(min),
-                    max: Some(max)
-                })?,
+                check_range(
+                    model::Range {
+                        min: Some(min),
+                        max: Some(max),
+                        min_span,
+                        combined_message: None,
+                    },
+                    rule_span
+                )?,
                 model::Either::Left
             )
         }
-        (span, Some(model::Either::Left(min)), None) => {
+        (Some(model::Either::Left(min)), None) => {
             map_validate_range!(
-                check_range(model::Range {
-                    span,
-                    min: Some(min),
-                    max: None,
-                })?,
+                check_range(
+                    model::Range {
+                        min: Some(min),
+                        max: None,
+                        min_span,
+                        combined_message: None,
+                    },
+                    rule_span
+                )?,
                 model::Either::Left
             )
         }
-        (span, None, Some(model::Either::Left(max))) => {
+        (None, Some(model::Either::Left(max))) => {
             map_validate_range!(
-                check_range(model::Range {
-                    span,
-                    min: None,
-                    max: Some(max),
-                })?,
+                check_range(
+                    model::Range {
+                        min: None,
+                        max: Some(max),
+                        min_span,
+                        combined_message: None,
+                    },
+                    rule_span
+                )?,
                 model::Either::Left
             )
         }
-        (span, min, max) => check_range_not_ord(model::Range { span, min, max })?,
+        (min, max) => check_range_not_ord(
+            model::Range {
+                min,
+                max,
+                min_span,
+                combined_message: None,
+            },
+            rule_span,
+        )?,
     };
 
     Ok(range)
 }
 
-fn check_range<T>(range: model::Range<T>) -> syn::Result<model::ValidateRange<T>>
+/// `rule_span` is the span of the rule's name, which is used if neither bound is given.
+fn check_range<T>(range: model::Range<T>, rule_span: Span) -> syn::Result<model::ValidateRange<T>>
 where
     T: PartialOrd,
 {
     match (range.min, range.max) {
         (Some(min), Some(max)) if min <= max => Ok(model::ValidateRange::Between(min, max)),
         (Some(_), Some(_)) => Err(syn::Error::new(
-            range.span,
+            range.min_span.unwrap_or(rule_span),
             "`min` must be lower than or equal to `max`",
         )),
         (Some(min), None) => Ok(model::ValidateRange::GreaterThan(min)),
         (None, Some(max)) => Ok(model::ValidateRange::LowerThan(max)),
         (None, None) => Err(syn::Error::new(
-            range.span,
+            rule_span,
             "range must have at least one of `min`, `max`",
         )),
     }
 }
 
-fn check_range_not_ord<T>(range: model::Range<T>) -> syn::Result<model::ValidateRange<T>> {
+fn check_range_not_ord<T>(
+    range: model::Range<T>,
+    rule_span: Span,
+) -> syn::Result<model::ValidateRange<T>> {
     match (range.min, range.max) {
         (Some(min), Some(max)) => Ok(model::ValidateRange::Between(min, max)),
         (Some(min), None) => Ok(model::ValidateRange::GreaterThan(min)),
         (None, Some(max)) => Ok(model::ValidateRange::LowerThan(max)),
         (None, None) => Err(syn::Error::new(
-            range.span,
+            rule_span,
             "range must have at least one of `min`, `max`",
         )),
     }
 }
 
+/// The primitive integer types, with the smallest and largest value of each.
+///
+/// `isize` and `usize` use their 64-bit range, a smaller target is still caught by the compiler.
+const INTEGER_TYPES: &[(&str, i128, u128)] = &[
+    ("u8", 0, u8::MAX as u128),
+    ("u16", 0, u16::MAX as u128),
+    ("u32", 0, u32::MAX as u128),
+    ("u64", 0, u64::MAX as u128),
+    ("u128", 0, u128::MAX),
+    ("usize", 0, u64::MAX as u128),
+    ("i8", i8::MIN as i128, i8::MAX as u128),
+    ("i16", i16::MIN as i128, i16::MAX as u128),
+    ("i32", i32::MIN as i128, i32::MAX as u128),
+    ("i64", i64::MIN as i128, i64::MAX as u128),
+    ("i128", i128::MIN, i128::MAX as u128),
+    ("isize", i64::MIN as i128, i64::MAX as u128),
+];
+
+struct IntegerType {
+    ident: Ident,
+    min: i128,
+    max: u128,
+}
+
+/// Finds the primitive integer in a field type, such as `u64` or `Option<u64>`.
+fn integer_type(ty: &syn::Type) -> Option<IntegerType> {
+    let ty = named_type(ty)?;
+    let ident = ty.path.get_ident()?;
+    INTEGER_TYPES
+        .iter()
+        .find(|(name, _, _)| ident == name)
+        .map(|&(_, min, max)| IntegerType {
+            ident: ident.clone(),
+            min,
+            max,
+        })
+}
+
+/// An integer literal, optionally negated, such as `10` or `-1`.
+fn integer_literal(expr: &Expr) -> Option<(bool, &syn::LitInt)> {
+    match expr {
+        Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => Some((false, lit)),
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => match integer_literal(expr)? {
+            (false, lit) => Some((true, lit)),
+            (true, _) => None,
+        },
+        Expr::Paren(v) => integer_literal(&v.expr),
+        Expr::Group(v) => integer_literal(&v.expr),
+        _ => None,
+    }
+}
+
+/// Checks that a version literal follows the [SemVer grammar](https://semver.org/#backusnaur-form-grammar-for-valid-semver-versions),
+/// so that parsing it at runtime can't fail.
+fn check_semver_literal(value: &str) -> Result<(), &'static str> {
+    fn is_numeric(s: &str) -> bool {
+        !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+    }
+    fn identifiers(
+        s: &str,
+        what: &'static str,
+        numeric_leading_zero: bool,
+    ) -> Result<(), &'static str> {
+        for id in s.split('.') {
+            if id.is_empty() || !id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-') {
+                return Err(what);
+            }
+            if numeric_leading_zero && is_numeric(id) && id.len() > 1 && id.starts_with('0') {
+                return Err("numeric identifiers may not have leading zeros");
+            }
+        }
+        Ok(())
+    }
+
+    let (rest, build) = match value.split_once('+') {
+        Some((rest, build)) => (rest, Some(build)),
+        None => (value, None),
+    };
+    let (core, pre) = match rest.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (rest, None),
+    };
+    let parts = core.split('.').collect::<Vec<_>>();
+    if parts.len() != 3 || !parts.iter().all(|part| is_numeric(part)) {
+        return Err("expected `<major>.<minor>.<patch>`");
+    }
+    if parts
+        .iter()
+        .any(|part| part.len() > 1 && part.starts_with('0'))
+    {
+        return Err("numeric identifiers may not have leading zeros");
+    }
+    if parts.iter().any(|part| part.parse::<u64>().is_err()) {
+        return Err("version numbers must fit into a `u64`");
+    }
+    if let Some(pre) = pre {
+        identifiers(pre, "invalid pre-release", true)?;
+    }
+    if let Some(build) = build {
+        identifiers(build, "invalid build metadata", false)?;
+    }
+    Ok(())
+}
+
+/// Checks the bounds of a `range` on an integer field.
+///
+/// Literal bounds must be representable in the field's type.
+/// If any of the bounds is a constant or a field, which has a type of its own,
+/// the bounds are compared via `range::apply_integer`, and the literals are given the field's type,
+/// so that they aren't inferred as `i32`.
+fn check_integer_bounds(
+    range: model::ValidateRange<Expr>,
+    ty: IntegerType,
+) -> syn::Result<(model::ValidateRange<Expr>, Option<Ident>)> {
+    let bounds: Vec<&Expr> = match &range {
+        model::ValidateRange::GreaterThan(v) | model::ValidateRange::LowerThan(v) => vec![v],
+        model::ValidateRange::Between(min, max) => vec![min, max],
+    };
+
+    let mut widen = false;
+    for bound in bounds {
+        match integer_literal(bound) {
+            Some((negative, lit)) => {
+                let in_range = match lit.base10_parse::<u128>() {
+                    Ok(value) if negative => value <= ty.min.unsigned_abs(),
+                    Ok(value) => value <= ty.max,
+                    Err(_) => false,
+                };
+                if !in_range {
+                    let sign = if negative { "-" } else { "" };
+                    return Err(syn::Error::new_spanned(
+                        bound,
+                        format!(
+                            "bound `{sign}{}` is out of range for `{}`",
+                            lit.base10_digits(),
+                            ty.ident
+                        ),
+                    ));
+                }
+            }
+            None => {
+                // Constants and fields always have a type of their own, unlike e.g. `x.into()`.
+                widen |= matches!(bound, Expr::Path(_) | Expr::Field(_));
+            }
+        }
+    }
+
+    if !widen {
+        return Ok((range, None));
+    }
+    let suffix = |expr: Expr| -> Expr {
+        match integer_literal(&expr) {
+            Some((negative, lit)) if lit.suffix().is_empty() => {
+                let lit =
+                    syn::LitInt::new(&format!("{}{}", lit.base10_digits(), ty.ident), lit.span());
+                match negative {
+                    true => parse_quote!(-#lit),
+                    false => parse_quote!(#lit),
+                }
+            }
+            _ => expr,
+        }
+    };
+    let range = match range {
+        model::ValidateRange::GreaterThan(v) => model::ValidateRange::GreaterThan(suffix(v)),
+        model::ValidateRange::LowerThan(v) => model::ValidateRange::LowerThan(suffix(v)),
+        model::ValidateRange::Between(min, max) => {
+            model::ValidateRange::Between(suffix(min), suffix(max))
+        }
+    };
+    Ok((range, Some(ty.ident)))
+}
+
+fn check_fs_path(value: model::FsPath, rule_span: Span) -> syn::Result<model::FsPath> {
+    if value.anchor.is_none() && value.extensions.is_none() {
+        return Err(syn::Error::new(
+            rule_span,
+            "fs_path must have at least one of `absolute`, `relative`, `extension`",
+        ));
+    }
+    Ok(value)
+}
+
+fn check_enum_variant(
+    field_ty: &syn::Type,
+    path: syn::Path,
+    is_inner: bool,
+) -> syn::Result<model::EnumVariant> {
+    let Some(variant) = path.segments.last() else {
+        return Err(syn::Error::new(path.span(), "expected a variant"));
+    };
+    let name = variant.ident.to_string();
+
+    // A full path names the enum itself, e.g. `Status::Active`.
+    if path.segments.len() > 1 {
+        let mut enum_path = path.clone();
+        enum_path.segments.pop();
+        enum_path.segments.pop_punct();
+        let ty = syn::Type::Path(syn::TypePath {
+            qself: None,
+            path: enum_path,
+        });
+        return Ok(model::EnumVariant { ty, path, name });
+    }
+
+    if is_inner {
+        return Err(syn::Error::new(
+            path.span(),
+            "`variant` in `inner` must be a full path, e.g. `variant(Status::Active)`",
+        ));
+    }
+    let Some(enum_ty) = named_type(field_ty) else {
+        return Err(syn::Error::new(
+            field_ty.span(),
+            "`variant` requires an enum field, or a full path such as `variant(Status::Active)`",
+        ));
+    };
+    // Generic arguments are inferred in patterns, and `Status<T>::Active` would not parse.
+    let mut variant_path = enum_ty.path.clone();
+    if let Some(last) = variant_path.segments.last_mut() {
+        last.arguments = syn::PathArguments::None;
+    }
+    variant_path.segments.push(variant.clone());
+    Ok(model::EnumVariant {
+        ty: syn::Type::Path(enum_ty.clone()),
+        path: variant_path,
+        name,
+    })
+}
+
+/// Finds the named type in a field type by removing references and an `Option`.
+fn named_type(ty: &syn::Type) -> Option<&syn::TypePath> {
+    match ty {
+        syn::Type::Reference(v) => named_type(&v.elem),
+        syn::Type::Paren(v) => named_type(&v.elem),
+        syn::Type::Group(v) => named_type(&v.elem),
+        syn::Type::Path(v) if v.qself.is_none() => {
+            let segment = v.path.segments.last()?;
+            if segment.ident == "Option" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    if let Some(syn::GenericArgument::Type(inner)) = args.args.first() {
+                        return named_type(inner);
+                    }
+                }
+            }
+            Some(v)
+        }
+        _ => None,
+    }
+}
+
+/// Rejects `any = []` and `all = []`, which would always fail or always pass.
+///
+/// Needles which are not array literals can't be checked here.
+fn check_needle(needle: model::Needle) -> syn::Result<model::Needle> {
+    let (name, expr) = match &needle {
+        model::Needle::One(_) => return Ok(needle),
+        model::Needle::Any(expr) => ("any", expr),
+        model::Needle::All(expr) => ("all", expr),
+    };
+    if let Expr::Array(array) = expr {
+        if array.elems.is_empty() {
+            return Err(syn::Error::new(
+                array.span(),
+                format!("`{name}` must list at least one needle"),
+            ));
+        }
+    }
+    Ok(needle)
+}
+
+fn check_pattern_groups(value: &model::PatternRule) -> syn::Result<Vec<String>> {
+    let Some(require) = &value.require else {
+        return Ok(Vec::new());
+    };
+    if require.contents.is_empty() {
+        return Err(syn::Error::new(
+            require.span,
+            "`require` must list at least one group",
+        ));
+    }
+
+    let mut error = None;
+    let mut groups = Vec::<String>::new();
+    for group in &require.contents {
+        if groups.contains(&group.value) {
+            error.maybe_fold(syn::Error::new(group.span, "duplicate group"));
+            continue;
+        }
+        #[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+        if let model::Pattern::Lit(lit) = &value.pattern {
+            if let Ok(names) = compile_regex(&lit.value) {
+                if !names.contains(&group.value) {
+                    error.maybe_fold(syn::Error::new(
+                        group.span,
+                        format!("pattern has no group named `{}`", group.value),
+                    ));
+                    continue;
+                }
+            }
+        }
+        groups.push(group.value.clone());
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(groups),
+    }
+}
+
 fn check_regex(value: model::Pattern) -> syn::Result<model::ValidatePattern> {
     match value {
         model::Pattern::Lit(lit) => {
-            #[cfg(feature = "regex")]
+            #[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
             {
-                if let Err(e) = regex::Regex::new(&lit.value) {
+                if let Err(e) = compile_regex(&lit.value) {
                     return Err(syn::Error::new(lit.span, format!("invalid regex: {e}")));
                 }
                 Ok(model::ValidatePattern::Lit(lit.value))
             }
-            #[cfg(not(feature = "regex"))]
+            #[cfg(not(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex")))]
             Err(syn::Error::new(
                 lit.span,
-                "regex feature must be enabled to use literal patterns",
+                "one of the `regex`, `regex-lite`, or `fancy-regex` features must be enabled to use literal patterns",
             ))
         }
         model::Pattern::Expr(expr) => Ok(model::ValidatePattern::Expr(expr)),
     }
 }
+
+/// Compiles `pattern` with the same engine that `garde` uses at runtime, returning the names of its groups.
+///
+/// If multiple engines are enabled, `fancy-regex` takes precedence over `regex`,
+/// which takes precedence over `regex-lite`. This must match `garde::rules::pattern::regex::Engine`.
+#[cfg(feature = "fancy-regex")]
+fn compile_regex(pattern: &str) -> Result<Vec<String>, String> {
+    match fancy_regex::Regex::new(pattern) {
+        Ok(re) => Ok(re.capture_names().flatten().map(String::from).collect()),
+        // `regex` points at the problem, so prefer its error if it rejects the pattern too.
+        Err(e) => match regex::Regex::new(pattern) {
+            Ok(_) => Err(e.to_string()),
+            Err(e) => Err(e.to_string()),
+        },
+    }
+}
+
+#[cfg(all(feature = "regex", not(feature = "fancy-regex")))]
+fn compile_regex(pattern: &str) -> Result<Vec<String>, String> {
+    regex::Regex::new(pattern)
+        .map(|re| re.capture_names().flatten().map(String::from).collect())
+        .map_err(|e| e.to_string())
+}
+
+#[cfg(all(
+    feature = "regex-lite",
+    not(any(feature = "regex", feature = "fancy-regex"))
+))]
+fn compile_regex(pattern: &str) -> Result<Vec<String>, String> {
+    regex_lite::Regex::new(pattern)
+        .map(|re| re.capture_names().flatten().map(String::from).collect())
+        .map_err(|e| e.to_string())
+}
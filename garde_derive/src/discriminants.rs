@@ -0,0 +1,76 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields, Ident};
+
+const INTEGER_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Implements `garde::rules::enum_value::Discriminants` for a fieldless enum.
+///
+/// The discriminants are computed via `as` casts, so implicit and constant discriminants
+/// don't have to be evaluated here.
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(
+            input.ident.span(),
+            "`Discriminants` may only be derived for enums",
+        ));
+    };
+
+    let repr = get_repr(&input)?;
+    let mut error: Option<syn::Error> = None;
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            let e = syn::Error::new(
+                variant.fields.span(),
+                "`Discriminants` may only be derived for enums without fields",
+            );
+            match &mut error {
+                Some(error) => error.combine(e),
+                None => error = Some(e),
+            }
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants = data.variants.iter().map(|variant| &variant.ident);
+
+    Ok(quote! {
+        impl #impl_generics ::garde::rules::enum_value::Discriminants for #ident #ty_generics #where_clause {
+            type Repr = #repr;
+
+            const DISCRIMINANTS: &'static [Self::Repr] = &[#(Self::#variants as #repr),*];
+        }
+    })
+}
+
+fn get_repr(input: &DeriveInput) -> syn::Result<Ident> {
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGER_REPRS.contains(&ident.to_string().as_str()) {
+                    repr = Some(ident.clone());
+                }
+            }
+            Ok(())
+        })?;
+    }
+
+    repr.ok_or_else(|| {
+        syn::Error::new(
+            input.ident.span(),
+            "`Discriminants` requires a `#[repr(<integer>)]` attribute",
+        )
+    })
+}
@@ -2,6 +2,7 @@ use std::cell::RefCell;
 
 use proc_macro2::{Ident, TokenStream as TokenStream2};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 
 use crate::model;
@@ -25,11 +26,72 @@ impl ToTokens for model::Validate {
         let (context_ty, context_ident) = &self.context;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
         let kind = &self.kind;
+        let capacity_hint = self.kind.rule_count() + self.container_rules.len();
+        let try_from = self.options.try_from.map(|_| TryFrom(self));
+        #[cfg(feature = "reflection")]
+        let reflect = crate::reflect::Reflect(self);
+        #[cfg(not(feature = "reflection"))]
+        let reflect = quote!();
+        #[cfg(feature = "schema")]
+        let schema = crate::reflect::JsonSchema(self);
+        #[cfg(not(feature = "schema"))]
+        let schema = quote!();
+        // Container-level rules run after all fields have been validated,
+        // and receive `self` as the value.
+        let abort = quote!(return;);
+        let code = self
+            .options
+            .code_prefix
+            .clone()
+            .map(model::ErrorCode::Prefix);
+        let map_error = MapError {
+            code: code.as_ref(),
+            message: None,
+            label: None,
+            capture_value: false,
+        };
+        let container_rules = match self.container_rules.is_empty() {
+            true => None,
+            false => {
+                let rules = self.container_rules.iter().map(|rule| match rule {
+                    model::ContainerRule::Custom(rule) => Custom {
+                        rule,
+                        args: &[],
+                        with_self: false,
+                        abort: &abort,
+                        // There's nothing left to skip after container-level rules
+                        // other than the remaining container-level rules.
+                        skip: &abort,
+                        map_error,
+                    }
+                    .to_token_stream(),
+                    model::ContainerRule::FieldGroup(group) => {
+                        FieldGroup { group, map_error }.to_token_stream()
+                    }
+                    model::ContainerRule::DependentRequired(rule) => {
+                        DependentRequired { rule, map_error }.to_token_stream()
+                    }
+                });
+                Some(quote! {
+                    if __garde_report.is_full() {
+                        return;
+                    }
+                    let __garde_binding = self;
+                    #(#rules)*
+                })
+            }
+        };
 
         quote! {
             impl #impl_generics ::garde::Validate for #ident #ty_generics #where_clause {
                 type Context = #context_ty ;
 
+                fn validate(&self, __garde_ctx: &Self::Context) -> ::core::result::Result<(), ::garde::error::Report> {
+                    let mut __garde_report = ::garde::error::Report::with_capacity(#capacity_hint);
+                    ::garde::Validate::validate_into(self, __garde_ctx, &mut ::garde::Path::empty, &mut __garde_report);
+                    __garde_report.into_result()
+                }
+
                 #[allow(clippy::needless_borrow)]
                 fn validate_into(
                     &self,
@@ -38,8 +100,51 @@ impl ToTokens for model::Validate {
                     __garde_report: &mut ::garde::error::Report,
                 ) {
                     let __garde_user_ctx = &#context_ident;
+                    #[allow(unused_mut, unused_variables)]
+                    let mut __garde_abort = false;
 
                     #kind
+
+                    #container_rules
+                }
+            }
+
+            #try_from
+
+            #reflect
+
+            #schema
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct TryFrom<'a>(&'a model::Validate);
+
+impl<'a> ToTokens for TryFrom<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.0.ident;
+        let (impl_generics, ty_generics, where_clause) = self.0.generics.split_for_impl();
+
+        let (ty, construct) = match &self.0.kind {
+            model::ValidateKind::Struct(model::ValidateVariant::Struct(fields)) => {
+                let (name, field) = &fields[0];
+                (&field.ty, quote!(Self { #name: __garde_value }))
+            }
+            model::ValidateKind::Struct(model::ValidateVariant::Tuple(fields)) => {
+                (&fields[0].ty, quote!(Self(__garde_value)))
+            }
+            model::ValidateKind::Enum(_) => unreachable!("`try_from` is rejected on enums"),
+        };
+
+        quote! {
+            impl #impl_generics ::core::convert::TryFrom<#ty> for #ident #ty_generics #where_clause {
+                type Error = ::garde::error::Report;
+
+                fn try_from(__garde_value: #ty) -> ::core::result::Result<Self, Self::Error> {
+                    let __garde_value = #construct;
+                    ::garde::Validate::validate(&__garde_value, &::core::default::Default::default())?;
+                    ::core::result::Result::Ok(__garde_value)
                 }
             }
         }
@@ -103,7 +208,7 @@ impl<'a> ToTokens for Struct<'a> {
         Fields::new(
             self.0
                 .iter()
-                .map(|(key, field)| (Binding::Ident(key), field, key.to_string())),
+                .map(|(key, field)| (Binding::Ident(key), field, key.unraw().to_string())),
             |key, value| {
                 quote! {{
                     let mut __garde_path = ::garde::util::nested_path!(__garde_path, #key);
@@ -135,47 +240,310 @@ impl<'a> ToTokens for Tuple<'a> {
     }
 }
 
-struct Inner<'a>(&'a model::RuleSet);
+#[derive(Clone, Copy)]
+enum InnerKind {
+    /// Items of a container, via `inner(..)`.
+    Items,
+    /// Keys of a map, via `keys(..)`.
+    Keys,
+    /// The value of an `Option`, via `optional(..)`.
+    Optional,
+}
+
+struct Inner<'a>(&'a model::RuleSet, InnerKind, MapError<'a>);
+
+impl<'a> Inner<'a> {
+    fn items(rule_set: &'a model::RuleSet, map_error: MapError<'a>) -> Self {
+        Self(rule_set, InnerKind::Items, map_error)
+    }
+
+    fn keys(rule_set: &'a model::RuleSet, map_error: MapError<'a>) -> Self {
+        Self(rule_set, InnerKind::Keys, map_error)
+    }
+
+    fn optional(rule_set: &'a model::RuleSet, map_error: MapError<'a>) -> Self {
+        Self(rule_set, InnerKind::Optional, map_error)
+    }
+}
+
+/// Validates the current binding via its `Validate` impl, for `dive`.
+fn dive() -> TokenStream2 {
+    quote! {
+        ::garde::validate::Validate::validate_into(
+            &*__garde_binding,
+            __garde_user_ctx,
+            &mut __garde_path,
+            __garde_report,
+        );
+    }
+}
 
 impl<'a> ToTokens for Inner<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let Inner(rule_set) = self;
+        let Inner(rule_set, kind, map_error) = self;
 
         let outer = match rule_set.has_top_level_rules() {
             true => {
-                let rules = Rules(rule_set);
+                let rules = Rules {
+                    rule_set,
+                    is_inner: true,
+                    map_error: *map_error,
+                };
                 Some(quote! {#rules})
             }
             false => None,
         };
-        let inner = rule_set.inner.as_deref().map(Inner);
+        let inner = rule_set
+            .inner
+            .as_deref()
+            .map(|v| Inner::items(v, *map_error));
+        let keys = rule_set.keys.as_deref().map(|v| Inner::keys(v, *map_error));
+        let optional = rule_set
+            .optional
+            .as_deref()
+            .map(|v| Inner::optional(v, *map_error));
+        let dive = rule_set.dive.map(|_| dive());
 
-        let value = match (outer, inner) {
-            (Some(outer), Some(inner)) => quote! {
-                #outer
-                #inner
+        if outer.is_none()
+            && inner.is_none()
+            && keys.is_none()
+            && optional.is_none()
+            && dive.is_none()
+        {
+            return;
+        }
+        let value = quote! {
+            #outer
+            #keys
+            #inner
+            #dive
+            #optional
+        };
+
+        // A custom rule may abort validation from within the closure,
+        // in which case we skip any remaining items, and also return
+        // from whatever scope we're in.
+        let check_abort = match rule_set.has_custom_rules() {
+            true => Some(quote! {
+                if __garde_abort {
+                    return;
+                }
+            }),
+            false => None,
+        };
+
+        match kind {
+            InnerKind::Items => quote! {
+                ::garde::rules::inner::apply(
+                    &*__garde_binding,
+                    |__garde_binding, __garde_inner_key| {
+                        #check_abort
+                        let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_inner_key);
+                        #value
+                    }
+                );
+                #check_abort
             },
-            (None, Some(inner)) => quote! {
-                #inner
+            InnerKind::Keys => quote! {
+                ::garde::rules::keys::apply(
+                    &*__garde_binding,
+                    |__garde_binding| {
+                        #check_abort
+                        let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_binding);
+                        let mut __garde_path = ::garde::util::nested_path!(__garde_path, ::garde::rules::keys::KEY);
+                        #value
+                    }
+                );
+                #check_abort
             },
-            (Some(outer), None) => outer,
-            (None, None) => return,
-        };
+            InnerKind::Optional => quote! {
+                ::garde::rules::optional::apply(
+                    &*__garde_binding,
+                    |__garde_binding| {
+                        #value
+                    }
+                );
+                #check_abort
+            },
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct Custom<'a> {
+    rule: &'a syn::Expr,
+    /// Extra arguments passed after the value and the context.
+    args: &'a [syn::Expr],
+    /// Whether `self` is passed between the value and the context.
+    with_self: bool,
+    /// Tokens which stop validation of the whole struct.
+    abort: &'a TokenStream2,
+    /// Tokens which stop validation of the current value.
+    skip: &'a TokenStream2,
+    map_error: MapError<'a>,
+}
+
+impl<'a> ToTokens for Custom<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Custom {
+            rule: custom_rule,
+            args,
+            with_self,
+            abort,
+            skip,
+            map_error: error,
+        } = self;
+
+        let whole = with_self.then(|| quote!(self,));
 
         quote! {
-            ::garde::rules::inner::apply(
-                &*__garde_binding,
-                |__garde_binding, __garde_inner_key| {
-                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_inner_key);
-                    #value
+            match ::garde::rules::custom::IntoFlow::into_flow(
+                (#custom_rule)(&*__garde_binding, #whole &__garde_user_ctx #(, #args)*)
+            ) {
+                ::garde::rules::custom::Flow::Ok => {}
+                ::garde::rules::custom::Flow::Err(__garde_error) => {
+                    __garde_report.append(__garde_path(), #error);
                 }
-            );
+                ::garde::rules::custom::Flow::Abort(__garde_error) => {
+                    if let Some(__garde_error) = __garde_error {
+                        __garde_report.append(__garde_path(), #error);
+                    }
+                    #abort
+                }
+                ::garde::rules::custom::Flow::Skip(__garde_error) => {
+                    if let Some(__garde_error) = __garde_error {
+                        __garde_report.append(__garde_path(), #error);
+                    }
+                    #skip
+                }
+                // `Flow` is non-exhaustive, variants from a newer `garde` are treated as `Ok`.
+                _ => {}
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct FieldGroup<'a> {
+    group: &'a model::FieldGroup,
+    map_error: MapError<'a>,
+}
+
+impl<'a> ToTokens for FieldGroup<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let FieldGroup {
+            group: model::FieldGroup { kind, fields, path },
+            map_error: error,
+        } = self;
+        let rule = format_ident!("{}", kind.name());
+
+        let fields = fields.iter().map(|field| {
+            let name = field.unraw().to_string();
+            quote! {
+                (#name, ::garde::rules::at_least_one::Present::is_present(&__garde_binding.#field))
+            }
+        });
+        let path = match path {
+            Some(path) => quote!(__garde_path().join(#path)),
+            None => quote!(__garde_path()),
+        };
+
+        quote! {
+            if let Err(__garde_error) = ::garde::rules::#rule::apply(&[#(#fields),*]) {
+                __garde_report.append(#path, #error);
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+struct DependentRequired<'a> {
+    rule: &'a model::DependentRequired,
+    map_error: MapError<'a>,
+}
+
+impl<'a> ToTokens for DependentRequired<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let DependentRequired {
+            rule: model::DependentRequired { condition, field },
+            map_error: error,
+        } = self;
+        let name = field.unraw().to_string();
+
+        // The error is reported at the path of the required field.
+        quote! {
+            if let Err(__garde_error) = ::garde::rules::dependent_required::apply(
+                &__garde_binding.#field,
+                #condition,
+            ) {
+                __garde_report.append(__garde_path().join(#name), #error);
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// The `__garde_error` binding, with its code rewritten according to `model::ErrorCode`,
+/// and the field's `label` attached.
+#[derive(Clone, Copy)]
+struct MapError<'a> {
+    code: Option<&'a model::ErrorCode>,
+    /// Replaces the message of every error, from `#[garde(message(..))]`.
+    message: Option<&'a model::Message>,
+    label: Option<&'a str>,
+    /// Whether errors of built-in rules may include the value, see `Report::with_value_debug`.
+    /// This is `false` for `#[garde(sensitive)]` fields.
+    capture_value: bool,
+}
+
+impl<'a> MapError<'a> {
+    /// Attaches the value to `__garde_error` if the report asks for it.
+    fn capture_value(&self) -> Option<TokenStream2> {
+        self.capture_value.then(|| {
+            quote! {
+                let __garde_error = match __garde_report.captures_value_debug() {
+                    true => {
+                        #[allow(unused_imports)]
+                        use ::garde::util::{CaptureDebug as _, CaptureNone as _};
+                        (&::garde::util::Capture(&*__garde_binding)).capture(__garde_error)
+                    }
+                    false => __garde_error,
+                };
+            }
+        })
+    }
+}
+
+impl<'a> ToTokens for MapError<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let label = self.label.map(|label| quote!(.with_label(#label)));
+        let message = self.message.map(|message| match message {
+            model::Message::Fmt(s) => {
+                let value = &s.value;
+                quote_spanned!(s.span => .with_static_message(#value))
+            }
+            model::Message::Func(func) => quote_spanned!(func.span() => .with_message(
+                (#func)(&*__garde_binding, &__garde_user_ctx)
+            )),
+        });
+        match self.code {
+            Some(model::ErrorCode::Override(code)) => {
+                quote!(__garde_error.with_code(#code) #message #label)
+            }
+            Some(model::ErrorCode::Prefix(prefix)) => {
+                quote!(__garde_error.with_code_prefix(#prefix) #message #label)
+            }
+            None => quote!(__garde_error #message #label),
         }
         .to_tokens(tokens)
     }
 }
 
-struct Rules<'a>(&'a model::RuleSet);
+struct Rules<'a> {
+    rule_set: &'a model::RuleSet,
+    is_inner: bool,
+    map_error: MapError<'a>,
+}
 
 #[derive(Clone, Copy)]
 enum Binding<'a> {
@@ -194,66 +562,407 @@ impl<'a> ToTokens for Binding<'a> {
 
 impl<'a> ToTokens for Rules<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let Rules(rule_set) = self;
+        let Rules {
+            rule_set,
+            is_inner,
+            map_error: error,
+        } = self;
 
-        for custom_rule in rule_set.custom_rules.iter() {
-            quote! {
-                if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
-                    __garde_report.append(__garde_path(), __garde_error);
+        // Within `inner`, we're inside of a closure, so the abort
+        // has to be propagated out of it via `__garde_abort`.
+        let abort = match is_inner {
+            true => quote! {
+                __garde_abort = true;
+                return;
+            },
+            false => quote! {
+                return;
+            },
+        };
+        // Within `inner`, this skips the rest of the current item,
+        // otherwise it skips the rest of the field, see `Fields`.
+        let skip = match is_inner {
+            true => quote! {
+                return;
+            },
+            false => quote! {
+                break '__garde_field;
+            },
+        };
+
+        for rule in rule_set.rules.iter() {
+            match rule {
+                model::FieldRule::Custom(custom_rule) => Custom {
+                    rule: &custom_rule.func,
+                    args: &custom_rule.args,
+                    with_self: custom_rule.with_self,
+                    abort: &abort,
+                    skip: &skip,
+                    map_error: *error,
+                }
+                .to_token_stream(),
+                model::FieldRule::Rule(rule) => {
+                    let call = RuleCall(rule);
+                    let capture = error.capture_value();
+                    quote! {
+                        if let Err(__garde_error) = #call {
+                            #capture
+                            __garde_report.append(__garde_path(), #error);
+                        }
+                    }
+                }
+                model::FieldRule::Not(rule) => {
+                    let call = RuleCall(rule);
+                    let negated = negated_error(rule);
+                    let code = format!("not.{}", rule.name());
+                    let capture = error.capture_value();
+                    quote! {
+                        if let Err(__garde_error) = ::garde::rules::not::apply(
+                            &*__garde_binding,
+                            #call,
+                            || #negated,
+                            #code,
+                        ) {
+                            #capture
+                            __garde_report.append(__garde_path(), #error);
+                        }
+                    }
                 }
             }
-            .to_tokens(tokens);
+            .to_tokens(tokens)
         }
+    }
+}
 
-        for rule in rule_set.rules.iter() {
-            let name = format_ident!("{}", rule.name());
-            use model::ValidateRule::*;
-            let args = match rule {
-                Ascii | Alphanumeric | Email | Url | CreditCard | PhoneNumber | Required => {
-                    quote!(())
+/// A call to the `apply` function of a built-in rule, evaluating to its `Result`.
+struct RuleCall<'a>(&'a model::ValidateRule);
+
+impl<'a> ToTokens for RuleCall<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let rule = self.0;
+        let name = format_ident!("{}", rule.name());
+        let mut func = format_ident!("apply");
+        use model::ValidateRule::*;
+        let args = match rule {
+            Lowercase | Uppercase | CreditCard | PhoneNumber | Isbn | Imei | E164 | Required
+            | Finite | StartsWithUppercase | Titlecase | NotBlank | Hostname | Luhn => quote!(()),
+            Semver(options) => match options {
+                Some(model::SemverOptions { min, max }) => {
+                    func = format_ident!("apply_range");
+                    let bound = |bound: &Option<model::Str>| match bound {
+                        Some(model::Str { value, .. }) => quote!({
+                            static VERSION: ::garde::rules::semver::StaticVersion =
+                                ::garde::rules::semver::StaticVersion::new(|| {
+                                    ::garde::rules::semver::parse_bound(#value)
+                                });
+                            ::core::option::Option::Some(&*VERSION)
+                        }),
+                        None => quote!(::core::option::Option::None),
+                    };
+                    let min = bound(min);
+                    let max = bound(max);
+                    quote!((#min, #max))
                 }
-                Ip => {
-                    quote!((::garde::rules::ip::IpKind::Any,))
+                None => quote!(()),
+            },
+            Email(options) => match options {
+                Some(model::EmailOptions { max_len }) => {
+                    func = format_ident!("apply_max_len");
+                    quote_spanned!(max_len.span() => (#max_len,))
                 }
-                IpV4 => {
-                    quote!((::garde::rules::ip::IpKind::V4,))
+                None => quote!(()),
+            },
+            AsciiPrintable => {
+                func = format_ident!("apply_printable");
+                quote!(())
+            }
+            NoControlChars(allow) => match allow {
+                Some(expr) => {
+                    func = format_ident!("apply_allow");
+                    quote_spanned!(expr.span() => (&#expr,))
                 }
-                IpV6 => {
-                    quote!((::garde::rules::ip::IpKind::V6,))
+                None => quote!(()),
+            },
+            Ascii(extra) | Alphanumeric(extra) => match extra {
+                Some(expr) => {
+                    func = format_ident!("apply_extra");
+                    quote_spanned!(expr.span() => (&#expr,))
                 }
-                Length(range) | ByteLength(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => quote!((#min, usize::MAX)),
-                    model::ValidateRange::LowerThan(max) => quote!((0usize, #max)),
-                    model::ValidateRange::Between(min, max) => quote!((#min, #max)),
-                },
-                Range(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
-                    model::ValidateRange::LowerThan(max) => quote!((None, Some(#max))),
+                None => quote!(()),
+            },
+            Url(options) => match options.as_deref() {
+                Some(model::UrlOptions {
+                    schemes: Some(schemes),
+                    host: None,
+                    host_suffix: None,
+                    max_len: None,
+                }) => {
+                    func = format_ident!("apply_schemes");
+                    quote_spanned!(schemes.span() => (&#schemes,))
+                }
+                Some(model::UrlOptions {
+                    schemes,
+                    host,
+                    host_suffix,
+                    max_len,
+                }) => {
+                    func = format_ident!("apply_options");
+                    let list = |list: &Option<syn::Expr>| match list {
+                        Some(expr) => {
+                            quote_spanned!(expr.span() => ::core::option::Option::Some(&#expr))
+                        }
+                        None => quote!(::core::option::Option::None),
+                    };
+                    let schemes = list(schemes);
+                    let host = list(host);
+                    let host_suffix = list(host_suffix);
+                    let max_len = match max_len {
+                        Some(expr) => {
+                            quote_spanned!(expr.span() => ::core::option::Option::Some(#expr))
+                        }
+                        None => quote!(::core::option::Option::None),
+                    };
+                    quote!((#schemes, #host, #host_suffix, #max_len))
+                }
+                None => quote!(()),
+            },
+            Base64(decoded_len) | Hex(decoded_len) => match decoded_len {
+                Some(expr) => quote_spanned!(expr.span() => (::core::option::Option::Some(#expr),)),
+                None => quote!((::core::option::Option::None,)),
+            },
+            Checksum(algo) => match algo {
+                model::ChecksumAlgo::Mod10 => {
+                    quote!((::garde::rules::checksum::Algorithm::Mod10,))
+                }
+                model::ChecksumAlgo::Mod11 => {
+                    quote!((::garde::rules::checksum::Algorithm::Mod11,))
+                }
+            },
+            Ip => {
+                quote!((::garde::rules::ip::IpKind::Any,))
+            }
+            IpV4 => {
+                quote!((::garde::rules::ip::IpKind::V4,))
+            }
+            IpV6 => {
+                quote!((::garde::rules::ip::IpKind::V6,))
+            }
+            Length(range) | ByteLength(range) => match range {
+                model::ValidateRange::GreaterThan(min) => quote!((#min, usize::MAX)),
+                model::ValidateRange::LowerThan(max) => quote!((0usize, #max)),
+                model::ValidateRange::Between(min, max) => quote!((#min, #max)),
+            },
+            Words((range, separator)) => {
+                let (min, max) = match range {
+                    model::ValidateRange::GreaterThan(min) => (quote!(#min), quote!(usize::MAX)),
+                    model::ValidateRange::LowerThan(max) => (quote!(0usize), quote!(#max)),
+                    model::ValidateRange::Between(min, max) => (quote!(#min), quote!(#max)),
+                };
+                match separator {
+                    Some(separator) => {
+                        func = format_ident!("apply_separator");
+                        quote!((#min, #max, #separator))
+                    }
+                    None => quote!((#min, #max)),
+                }
+            }
+            Range((model::ValidateRange::Between(min, max), true, integer)) => {
+                func = match integer {
+                    Some(_) => format_ident!("apply_integer_combined"),
+                    None => format_ident!("apply_combined"),
+                };
+                quote!((#min, #max))
+            }
+            Range((range, _, Some(integer))) => {
+                func = format_ident!("apply_integer");
+                match range {
+                    model::ValidateRange::GreaterThan(min) => {
+                        quote!((Some(#min), None::<#integer>))
+                    }
+                    model::ValidateRange::LowerThan(max) => {
+                        quote!((None::<#integer>, Some(#max)))
+                    }
                     model::ValidateRange::Between(min, max) => quote!((Some(#min), Some(#max))),
-                },
-                Contains(expr) | Prefix(expr) | Suffix(expr) => {
+                }
+            }
+            Range((range, _, None)) => match range {
+                model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
+                model::ValidateRange::LowerThan(max) => quote!((None, Some(#max))),
+                model::ValidateRange::Between(min, max) => quote!((Some(#min), Some(#max))),
+            },
+            Contains(needle) | Prefix(needle) | Suffix(needle) => match needle {
+                model::Needle::One(expr) => quote_spanned!(expr.span() => (&#expr,)),
+                model::Needle::Any(expr) => {
+                    func = format_ident!("apply_any");
+                    quote_spanned!(expr.span() => (&#expr,))
+                }
+                model::Needle::All(expr) => {
+                    func = format_ident!("apply_all");
                     quote_spanned!(expr.span() => (&#expr,))
                 }
-                Pattern(pat) => match pat {
-                    model::ValidatePattern::Expr(expr) => quote_spanned!(expr.span() => (&#expr,)),
+            },
+            Extension(expr) | OneOf(expr) => quote_spanned!(expr.span() => (&#expr,)),
+            Excludes(excludes) => {
+                if excludes.ignore_case {
+                    func = format_ident!("apply_ignore_case");
+                }
+                let terms = &excludes.terms;
+                quote_spanned!(terms.span() => (&#terms,))
+            }
+            ContainsKey(expr) | ContainsValue(expr) => {
+                quote_spanned!(expr.span() => (#expr,))
+            }
+            FsPath(fs_path) => {
+                let anchor = match &fs_path.anchor {
+                    Some(model::FsPathAnchor::Absolute) => {
+                        quote!(::core::option::Option::Some(
+                            ::garde::rules::fs_path::Anchor::Absolute
+                        ))
+                    }
+                    Some(model::FsPathAnchor::Relative) => {
+                        quote!(::core::option::Option::Some(
+                            ::garde::rules::fs_path::Anchor::Relative
+                        ))
+                    }
+                    None => quote!(::core::option::Option::None),
+                };
+                let extensions = match &fs_path.extensions {
+                    Some(expr) => {
+                        quote_spanned!(expr.span() => ::core::option::Option::Some(&#expr))
+                    }
+                    None => quote!(::core::option::Option::None),
+                };
+                quote!((#anchor, #extensions))
+            }
+            Date(date) => {
+                let tense = match date.tense {
+                    model::DateTense::Past => quote!(::garde::rules::date::Tense::Past),
+                    model::DateTense::Future => quote!(::garde::rules::date::Tense::Future),
+                };
+                let now = match &date.now {
+                    Some(expr) => {
+                        quote_spanned!(expr.span() => ::core::option::Option::Some(#expr))
+                    }
+                    None => quote!(::core::option::Option::None),
+                };
+                quote!((#tense, #now))
+            }
+            Variant(model::EnumVariant { ty, path, name }) => quote!((
+                |__garde_value: &#ty| ::core::matches!(__garde_value, #path { .. }),
+                #name,
+            )),
+            EnumValue(ty) => quote_spanned!(ty.span() => (
+                <#ty as ::garde::rules::enum_value::Discriminants>::DISCRIMINANTS,
+            )),
+            Numeric(numeric) => {
+                let ty = match numeric.mode {
+                    model::NumericMode::Int => {
+                        func = format_ident!("apply_int");
+                        quote!(i128)
+                    }
+                    model::NumericMode::Float => {
+                        func = format_ident!("apply_float");
+                        quote!(f64)
+                    }
+                };
+                let bound = |bound: &Option<syn::Expr>| match bound {
+                    Some(expr) => quote_spanned!(expr.span() => ::core::option::Option::Some(
+                        ::core::convert::Into::<#ty>::into(#expr)
+                    )),
+                    None => quote!(::core::option::Option::None),
+                };
+                let min = bound(&numeric.min);
+                let max = bound(&numeric.max);
+                quote!((#min, #max))
+            }
+            Pattern((pat, require)) => {
+                let groups = if require.is_empty() {
+                    quote!()
+                } else {
+                    func = format_ident!("apply_groups");
+                    quote!(&[#(#require),*],)
+                };
+                match pat {
+                    model::ValidatePattern::Expr(expr) => {
+                        quote_spanned!(expr.span() => (&#expr, #groups))
+                    }
+                    #[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
                     model::ValidatePattern::Lit(s) => quote!({
                         static PATTERN: ::garde::rules::pattern::regex::StaticPattern =
                             ::garde::rules::pattern::regex::init_pattern!(#s);
-                        (&PATTERN,)
+                        (&PATTERN, #groups)
                     }),
-                },
-            };
-
-            quote! {
-                if let Err(__garde_error) = (::garde::rules::#name::apply)(&*__garde_binding, #args) {
-                    __garde_report.append(__garde_path(), __garde_error);
                 }
             }
-            .to_tokens(tokens)
-        }
+        };
+
+        quote!((::garde::rules::#name::#func)(&*__garde_binding, #args)).to_tokens(tokens)
     }
 }
 
+/// The error produced when a rule inside of `not(..)` passes.
+///
+/// Constant messages are not copied, see `Error::new_static`.
+fn negated_error(rule: &model::ValidateRule) -> TokenStream2 {
+    use model::ValidateRule::*;
+    let needle = |verb: &str, needle: &model::Needle| match needle {
+        model::Needle::One(expr) => {
+            let fmt = format!("must not {verb} \"{{}}\"");
+            quote_spanned!(expr.span() => ::garde::error::Error::new(::std::format!(#fmt, #expr)))
+        }
+        model::Needle::Any(expr) => {
+            let fmt = format!("must not {verb} any of {{:?}}");
+            quote_spanned!(expr.span() => ::garde::error::Error::new(::std::format!(#fmt, #expr)))
+        }
+        model::Needle::All(expr) => {
+            let fmt = format!("must not {verb} all of {{:?}}");
+            quote_spanned!(expr.span() => ::garde::error::Error::new(::std::format!(#fmt, #expr)))
+        }
+    };
+    let message = match rule {
+        Ascii(_) => "must not be ascii",
+        AsciiPrintable => "must not be printable ascii",
+        Alphanumeric(_) => "must not be alphanumeric",
+        Lowercase => "must not be lowercase",
+        Uppercase => "must not be uppercase",
+        Email(_) => "must not be an email",
+        Url(_) => "must not be a url",
+        Ip => "must not be an IP address",
+        IpV4 => "must not be an IPv4 address",
+        IpV6 => "must not be an IPv6 address",
+        E164 => "must not be an E.164 phone number",
+        Hostname => "must not be a hostname",
+        Contains(v) => return needle("contain", v),
+        Prefix(v) => return needle("begin with", v),
+        Suffix(v) => return needle("end with", v),
+        Extension(expr) => {
+            return quote_spanned!(expr.span() => ::garde::error::Error::new(::std::format!(
+                "must not have one of the extensions {:?}",
+                #expr
+            )))
+        }
+        OneOf(expr) => {
+            return quote_spanned!(expr.span() => ::garde::error::Error::new(::std::format!(
+                "must not be one of {:?}",
+                #expr
+            )))
+        }
+        #[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+        Pattern((model::ValidatePattern::Lit(s), _)) => {
+            let message = format!("must not match pattern /{s}/");
+            return quote!(::garde::error::Error::new_static(#message));
+        }
+        Pattern((model::ValidatePattern::Expr(expr), _)) => {
+            return quote_spanned!(expr.span() => ::garde::error::Error::new(::std::format!(
+                "must not match pattern /{}/",
+                ::garde::rules::AsStr::as_str(&#expr)
+            )))
+        }
+        _ => unreachable!("rule `{}` is rejected in `not`", rule.name()),
+    };
+    quote!(::garde::error::Error::new_static(#message))
+}
+
 struct Fields<I, F>(RefCell<Option<I>>, F);
 
 impl<I, F> Fields<I, F> {
@@ -274,42 +983,89 @@ where
         };
         let fields = fields.filter(|(_, field, _)| field.skip.is_none());
         for (binding, field, extra) in fields {
-            let rules = Rules(&field.rule_set);
+            let map_error = MapError {
+                code: field.error_code.as_ref(),
+                message: field.message.as_ref(),
+                label: field.label.as_deref(),
+                capture_value: field.sensitive.is_none(),
+            };
+            let rules = Rules {
+                rule_set: &field.rule_set,
+                is_inner: false,
+                map_error,
+            };
             let outer = match field.has_top_level_rules() {
                 true => Some(quote! {{#rules}}),
                 false => None,
             };
             let inner = match (&field.dive, &field.rule_set.inner) {
-                (Some(..), None) => Some(quote! {
-                    ::garde::validate::Validate::validate_into(
-                        &*__garde_binding,
-                        __garde_user_ctx,
-                        &mut __garde_path,
-                        __garde_report,
-                    );
-                }),
-                (None, Some(inner)) => Some(Inner(inner).to_token_stream()),
+                (Some(..), None) => Some(dive()),
+                (None, Some(inner)) => Some(Inner::items(inner, map_error).to_token_stream()),
                 (None, None) => None,
                 // TODO: encode this via the type system instead?
                 _ => unreachable!("`dive` and `inner` are mutually exclusive"),
             };
 
-            let value = match (outer, inner) {
-                (Some(outer), Some(inner)) => quote! {
-                    let __garde_binding = &*#binding;
-                    #inner
-                    #outer
-                },
-                (None, Some(inner)) => quote! {
-                    let __garde_binding = &*#binding;
-                    #inner
+            let keys = field
+                .rule_set
+                .keys
+                .as_deref()
+                .map(|keys| Inner::keys(keys, map_error));
+            let optional = field
+                .rule_set
+                .optional
+                .as_deref()
+                .map(|optional| Inner::optional(optional, map_error));
+
+            if outer.is_none() && inner.is_none() && keys.is_none() && optional.is_none() {
+                unreachable!("field should already be skipped");
+            }
+            // Rules on the field itself run first, followed by its keys and items,
+            // the same order which is used within `inner`.
+            let value = quote! {
+                let __garde_binding = &*#binding;
+                #outer
+                #keys
+                #inner
+                #optional
+            };
+            // `Flow::Skip` in a custom rule breaks out of this block.
+            let value = match field.rule_set.has_top_level_custom_rules() {
+                false => value,
+                true => quote! {
+                    '__garde_field: {
+                        #value
+                    }
                 },
-                (Some(outer), None) => quote! {
-                    let __garde_binding = &*#binding;
-                    #outer
+            };
+            // The conditions are evaluated before any of the field's rules.
+            let condition = match (&field.when, &field.skip_if) {
+                (None, None) => None,
+                (Some(when), None) => Some(quote!(#when)),
+                (None, Some(skip_if)) => Some(quote!(!(#skip_if))),
+                (Some(when), Some(skip_if)) => Some(quote!((#when) && !(#skip_if))),
+            };
+            let value = match condition {
+                None => value,
+                Some(condition) => quote! {
+                    if #condition {
+                        #value
+                    }
                 },
-                (None, None) => unreachable!("field should already be skipped"),
             };
+            // Stops validation once the report reached its limit, see `Report::with_options`.
+            let value = quote! {
+                if __garde_report.is_full() {
+                    return;
+                }
+                #value
+            };
+
+            // A flattened field is validated at the path of its parent.
+            if field.flatten.is_some() {
+                quote!({#value}).to_tokens(tokens);
+                continue;
+            }
 
             let add = &self.1;
 
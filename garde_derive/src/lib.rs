@@ -1,6 +1,9 @@
 mod check;
+mod discriminants;
 mod emit;
 mod model;
+#[cfg(feature = "reflection")]
+mod reflect;
 mod syntax;
 mod util;
 
@@ -22,6 +25,15 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     emit::emit(input).into()
 }
 
+#[proc_macro_derive(Discriminants)]
+pub fn derive_discriminants(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match discriminants::derive(input) {
+        Ok(v) => v.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
 #[proc_macro]
 pub fn select(input: TokenStream) -> TokenStream {
     fn parse_literal_digits_only(lit: Literal) -> syn::Result<String> {
@@ -1,7 +1,5 @@
-use std::collections::{BTreeMap, BTreeSet};
-
-use proc_macro2::{Ident, Span};
-use syn::{Expr, Generics, Type};
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::{Expr, Generics, Path, Type};
 
 pub struct Input {
     pub ident: Ident,
@@ -14,6 +12,16 @@ pub struct Input {
 pub enum Attr {
     Context(Box<Type>, Ident),
     AllowUnvalidated,
+    TryFrom,
+    Transparent,
+    Custom(Box<Expr>),
+    FieldGroup(FieldGroup),
+    DependentRequired(Box<DependentRequired>),
+    CodePrefix(String),
+    /// Where predicates which are added to the derived impls.
+    Bound(Vec<syn::WherePredicate>),
+    /// Rules which are parsed again for every field of a matching category.
+    DefaultRules(Vec<(Category, TokenStream)>),
 }
 
 impl Attr {
@@ -29,6 +37,53 @@ impl Attr {
         match self {
             Attr::Context(..) => "context",
             Attr::AllowUnvalidated => "allow_unvalidated",
+            Attr::TryFrom => "try_from",
+            Attr::Transparent => "transparent",
+            Attr::Custom(..) => "custom",
+            Attr::FieldGroup(group) => group.kind.name(),
+            Attr::DependentRequired(..) => "dependent_required",
+            Attr::CodePrefix(..) => "code_prefix",
+            Attr::Bound(..) => "bound",
+            Attr::DefaultRules(..) => "default_rules",
+        }
+    }
+}
+
+/// A container-level rule over a list of fields.
+#[derive(Clone)]
+pub struct FieldGroup {
+    pub kind: FieldGroupKind,
+    pub fields: Vec<Ident>,
+    /// Appended to the path of the container, or `None` to report the error at the container itself.
+    pub path: Option<String>,
+}
+
+/// A container-level rule which requires `field` to be present if `condition` is `true`.
+#[derive(Clone)]
+pub struct DependentRequired {
+    pub condition: Expr,
+    pub field: Ident,
+}
+
+#[derive(Clone, Copy)]
+pub enum FieldGroupKind {
+    AtLeastOne,
+    MutuallyExclusive,
+}
+
+impl FieldGroupKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            FieldGroupKind::AtLeastOne => "at_least_one",
+            FieldGroupKind::MutuallyExclusive => "mutually_exclusive",
+        }
+    }
+
+    /// The least number of fields for which the rule can fail.
+    pub fn min_fields(&self) -> usize {
+        match self {
+            FieldGroupKind::AtLeastOne => 1,
+            FieldGroupKind::MutuallyExclusive => 2,
         }
     }
 }
@@ -45,7 +100,8 @@ impl InputKind {
 }
 
 pub enum Variant {
-    Struct(BTreeMap<Ident, Field>),
+    /// Fields in declaration order.
+    Struct(Vec<(Ident, Field)>),
     Tuple(Vec<Field>),
 }
 
@@ -55,11 +111,22 @@ impl Variant {
     }
 }
 
+/// A kind of field, inferred from the shape of its type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Strings,
+    Numbers,
+    Lists,
+    Maps,
+    Paths,
+}
+
 pub struct Field {
     pub ty: Type,
     pub rules: Vec<RawRule>,
 }
 
+#[allow(dead_code)]
 pub enum Message {
     Fmt(Str),
     Func(Expr),
@@ -72,29 +139,65 @@ pub struct RawRule {
 
 pub enum RawRuleKind {
     Skip,
+    SkipDefaults,
     Rename(Str),
     Message(Message),
     Code(Str),
-    Dive,
+    Label(Str),
+    When(When),
+    SkipIf(Expr),
+    Dive(Option<DiveOptions>),
+    Flatten,
+    Sensitive,
     Required,
-    Ascii,
-    Alphanumeric,
-    Email,
-    Url,
+    Ascii(Option<AsciiOptions>),
+    Alphanumeric(Option<Extra>),
+    Lowercase,
+    Uppercase,
+    StartsWithUppercase,
+    Titlecase,
+    NotBlank,
+    Email(Option<EmailOptions>),
+    Url(Option<Box<UrlOptions>>),
     Ip,
     IpV4,
     IpV6,
     CreditCard,
     PhoneNumber,
+    Checksum(Checksum),
+    Isbn,
+    Imei,
+    E164,
+    Hostname,
+    Luhn,
+    Semver(Option<SemverOptions>),
+    NoControlChars(Option<AllowChars>),
+    Base64(Option<Encoded>),
+    Hex(Option<Encoded>),
     Length(Range<Either<usize, Expr>>),
     ByteLength(Range<Either<usize, Expr>>),
+    Words(Words),
     Range(Range<Expr>),
-    Contains(Expr),
-    Prefix(Expr),
-    Suffix(Expr),
-    Pattern(Pattern),
-    Custom(Expr),
+    Finite,
+    Numeric(Option<Numeric>),
+    Contains(Needle),
+    ContainsKey(Expr),
+    ContainsValue(Expr),
+    Prefix(Needle),
+    Suffix(Needle),
+    Extension(Expr),
+    OneOf(Expr),
+    Excludes(Excludes),
+    Pattern(PatternRule),
+    FsPath(FsPath),
+    Date(Date),
+    Variant(Path),
+    EnumValue(Type),
+    Custom(CustomRule),
     Inner(List<RawRule>),
+    Optional(List<RawRule>),
+    Keys(List<RawRule>),
+    Not(Box<RawRule>),
 }
 
 pub enum Either<L, R> {
@@ -115,23 +218,139 @@ where
     }
 }
 
+pub struct DiveOptions {
+    pub required: Option<Span>,
+}
+
+pub struct UrlOptions {
+    pub schemes: Option<Expr>,
+    pub host: Option<Expr>,
+    pub host_suffix: Option<Expr>,
+    pub max_len: Option<Expr>,
+}
+
+pub struct EmailOptions {
+    pub max_len: Expr,
+}
+
+/// `when(<condition>, code = "..", label = "..")`, whose code and label are attached to every error of the field.
+pub struct When {
+    pub condition: Expr,
+    pub code: Option<Str>,
+    pub label: Option<Str>,
+}
+
+/// Inclusive bounds of `semver(min = "..", max = "..")`, which are version literals.
+pub struct SemverOptions {
+    pub min: Option<Str>,
+    pub max: Option<Str>,
+}
+
+pub struct Extra {
+    pub expr: Expr,
+}
+
+pub enum AsciiOptions {
+    Extra(Extra),
+    Printable,
+}
+
+pub struct AllowChars {
+    pub expr: Expr,
+}
+
+#[derive(Default)]
+pub struct Encoded {
+    pub decoded_len: Option<Expr>,
+}
+
+pub struct Checksum {
+    pub algo: ChecksumAlgo,
+}
+
+#[derive(Clone, Copy)]
+pub enum ChecksumAlgo {
+    Mod10,
+    Mod11,
+}
+
+#[derive(Default)]
+pub struct Numeric {
+    pub mode: NumericMode,
+    pub min: Option<Expr>,
+    pub max: Option<Expr>,
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum NumericMode {
+    Int,
+    #[default]
+    Float,
+}
+
+pub enum Needle {
+    One(Expr),
+    Any(Expr),
+    All(Expr),
+}
+
+pub struct FsPath {
+    pub anchor: Option<FsPathAnchor>,
+    pub extensions: Option<Expr>,
+}
+
+pub enum FsPathAnchor {
+    Absolute,
+    Relative,
+}
+
+pub struct Date {
+    pub tense: DateTense,
+    /// The current time, if it shouldn't be read from the system clock.
+    pub now: Option<Expr>,
+}
+
+pub enum DateTense {
+    Past,
+    Future,
+}
+
 pub enum Pattern {
     Lit(Str),
     Expr(Expr),
 }
 
+pub struct Words {
+    pub range: Range<Either<usize, Expr>>,
+    pub separator: Option<Expr>,
+}
+
+pub struct Excludes {
+    pub terms: Expr,
+    pub ignore_case: bool,
+}
+
+pub struct PatternRule {
+    pub pattern: Pattern,
+    /// Named groups which must capture a value, from `require = [..]`.
+    pub require: Option<List<Str>>,
+}
+
 pub struct Str {
     pub span: Span,
     pub value: String,
 }
 
 pub struct Range<T> {
-    pub span: Span,
     pub min: Option<T>,
     pub max: Option<T>,
+    /// The span of the `min` value, for errors about the bounds.
+    pub min_span: Option<Span>,
+    pub combined_message: Option<Span>,
 }
 
 pub struct List<T> {
+    #[allow(dead_code)]
     pub span: Span,
     pub contents: Vec<T>,
 }
@@ -141,11 +360,24 @@ pub struct Validate {
     pub generics: Generics,
     pub context: (Type, Ident),
     pub kind: ValidateKind,
+    /// Rules from the container attributes, in declaration order.
+    pub container_rules: Vec<ContainerRule>,
+    #[allow(dead_code)]
     pub options: Options,
 }
 
+pub enum ContainerRule {
+    Custom(Expr),
+    FieldGroup(FieldGroup),
+    DependentRequired(DependentRequired),
+}
+
 pub struct Options {
     pub allow_unvalidated: bool,
+    pub try_from: Option<Span>,
+    /// The only field is validated at the path of the container.
+    pub transparent: Option<Span>,
+    pub code_prefix: Option<String>,
 }
 
 pub enum ValidateKind {
@@ -160,11 +392,29 @@ pub struct ValidateField {
     pub alias: Option<String>,
     pub message: Option<Message>,
     pub code: Option<String>,
+    pub error_code: Option<ErrorCode>,
+    pub label: Option<String>,
+    /// The field is only validated if this is `true`, from `#[garde(when(..))]`.
+    pub when: Option<Expr>,
+    /// The field is not validated if this is `true`, from `#[garde(skip_if(..))]`.
+    pub skip_if: Option<Expr>,
 
     pub dive: Option<Span>,
+    /// Set together with `dive`, validates the value at the path of the parent.
+    pub flatten: Option<Span>,
+    /// Errors never include the value, see `Report::with_value_debug`.
+    pub sensitive: Option<Span>,
     pub rule_set: RuleSet,
 }
 
+/// How the codes of errors produced by a field's rules are rewritten.
+pub enum ErrorCode {
+    /// Replace the code, from `#[garde(code(".."))]` on the field.
+    Override(String),
+    /// Prefix the code, from `#[garde(code_prefix = "..")]` on the container.
+    Prefix(String),
+}
+
 impl ValidateField {
     pub fn is_empty(&self) -> bool {
         self.dive.is_none() && self.rule_set.is_empty()
@@ -173,21 +423,65 @@ impl ValidateField {
     pub fn has_top_level_rules(&self) -> bool {
         self.rule_set.has_top_level_rules()
     }
+
+    pub fn rule_count(&self) -> usize {
+        if self.skip.is_some() {
+            return 0;
+        }
+        self.dive.is_some() as usize + self.rule_set.rule_count()
+    }
 }
 
 pub struct RuleSet {
-    pub rules: BTreeSet<ValidateRule>,
-    pub custom_rules: Vec<Expr>,
+    /// Rules in the order they were declared in.
+    pub rules: Vec<FieldRule>,
     pub inner: Option<Box<RuleSet>>,
+    pub keys: Option<Box<RuleSet>>,
+    /// Rules for the value of an `Option`, via `optional(..)`.
+    pub optional: Option<Box<RuleSet>>,
+    /// `dive` inside of `optional(..)`, which validates the value of the `Option`.
+    pub dive: Option<Span>,
+}
+
+pub enum FieldRule {
+    Rule(ValidateRule),
+    /// A rule wrapped in `not(..)`, which fails if the rule passes.
+    Not(ValidateRule),
+    Custom(CustomRule),
+}
+
+/// A custom validator, called with the value, the context, and then `args` in order.
+pub struct CustomRule {
+    pub func: Expr,
+    pub args: Vec<Expr>,
+    /// Whether `self` is passed between the value and the context.
+    pub with_self: bool,
 }
 
 impl RuleSet {
     pub fn empty() -> Self {
         Self {
-            rules: BTreeSet::new(),
-            custom_rules: Vec::new(),
+            rules: Vec::new(),
             inner: None,
+            keys: None,
+            optional: None,
+            dive: None,
+        }
+    }
+
+    /// Appends `rule`, returning `false` if a rule of the same kind is already present.
+    ///
+    /// Any number of custom rules may be added.
+    pub fn insert(&mut self, rule: FieldRule) -> bool {
+        let duplicate = self.rules.iter().any(|existing| match (existing, &rule) {
+            (FieldRule::Rule(a), FieldRule::Rule(b)) => a == b,
+            (FieldRule::Not(a), FieldRule::Not(b)) => a == b,
+            _ => false,
+        });
+        if !duplicate {
+            self.rules.push(rule);
         }
+        !duplicate
     }
 
     pub fn is_empty(&self) -> bool {
@@ -195,60 +489,180 @@ impl RuleSet {
             Some(inner) => inner.is_empty(),
             None => true,
         };
-        inner_empty && self.rules.is_empty() && self.custom_rules.is_empty()
+        let keys_empty = match &self.keys {
+            Some(keys) => keys.is_empty(),
+            None => true,
+        };
+        let optional_empty = match &self.optional {
+            Some(optional) => optional.is_empty(),
+            None => true,
+        };
+        inner_empty && keys_empty && optional_empty && self.dive.is_none() && self.rules.is_empty()
     }
 
     pub fn has_top_level_rules(&self) -> bool {
-        !self.rules.is_empty() || !self.custom_rules.is_empty()
+        !self.rules.is_empty()
+    }
+
+    pub fn has_top_level_custom_rules(&self) -> bool {
+        self.rules
+            .iter()
+            .any(|rule| matches!(rule, FieldRule::Custom(_)))
+    }
+
+    pub fn has_custom_rules(&self) -> bool {
+        let inner_custom = match &self.inner {
+            Some(inner) => inner.has_custom_rules(),
+            None => false,
+        };
+        let keys_custom = match &self.keys {
+            Some(keys) => keys.has_custom_rules(),
+            None => false,
+        };
+        let optional_custom = match &self.optional {
+            Some(optional) => optional.has_custom_rules(),
+            None => false,
+        };
+        inner_custom || keys_custom || optional_custom || self.has_top_level_custom_rules()
+    }
+
+    pub fn rule_count(&self) -> usize {
+        let inner = match &self.inner {
+            Some(inner) => inner.rule_count(),
+            None => 0,
+        };
+        let keys = match &self.keys {
+            Some(keys) => keys.rule_count(),
+            None => 0,
+        };
+        let optional = match &self.optional {
+            Some(optional) => optional.rule_count(),
+            None => 0,
+        };
+        inner + keys + optional + self.dive.is_some() as usize + self.rules.len()
     }
 }
 
-#[repr(u8)]
 pub enum ValidateRule {
     Required,
-    Ascii,
-    Alphanumeric,
-    Email,
-    Url,
+    Ascii(Option<Expr>),
+    /// `ascii(printable)`, which only allows `' '..='~'`.
+    AsciiPrintable,
+    Alphanumeric(Option<Expr>),
+    Lowercase,
+    Uppercase,
+    StartsWithUppercase,
+    Titlecase,
+    NotBlank,
+    Email(Option<EmailOptions>),
+    Url(Option<Box<UrlOptions>>),
     Ip,
     IpV4,
     IpV6,
     CreditCard,
     PhoneNumber,
+    Checksum(ChecksumAlgo),
+    Isbn,
+    Imei,
+    E164,
+    Hostname,
+    Luhn,
+    Semver(Option<SemverOptions>),
+    /// The allowed control characters, from `allow`.
+    NoControlChars(Option<Expr>),
+    /// The expected number of decoded bytes, from `decoded_len`.
+    Base64(Option<Expr>),
+    Hex(Option<Expr>),
     Length(ValidateRange<Either<usize, Expr>>),
     ByteLength(ValidateRange<Either<usize, Expr>>),
-    Range(ValidateRange<Expr>),
-    Contains(Expr),
-    Prefix(Expr),
-    Suffix(Expr),
-    Pattern(ValidatePattern),
+    /// The range of the word count, and the separator between words.
+    Words((ValidateRange<Either<usize, Expr>>, Option<Expr>)),
+    /// The `bool` is set if the error message should mention both bounds (`combined_message`).
+    ///
+    /// The `Ident` is the integer type of the field, if its bounds should be compared
+    /// via `range::apply_integer` because they may be of a different integer type.
+    Range((ValidateRange<Expr>, bool, Option<Ident>)),
+    Finite,
+    Numeric(Numeric),
+    Contains(Needle),
+    ContainsKey(Expr),
+    ContainsValue(Expr),
+    Prefix(Needle),
+    Suffix(Needle),
+    Extension(Expr),
+    OneOf(Expr),
+    Excludes(Excludes),
+    /// The pattern, and the named groups which must capture a value.
+    Pattern((ValidatePattern, Vec<String>)),
+    FsPath(FsPath),
+    Date(Date),
+    Variant(EnumVariant),
+    /// The enum type whose discriminants are accepted.
+    EnumValue(Type),
 }
 
 impl ValidateRule {
     pub fn name(&self) -> &'static str {
         match self {
             ValidateRule::Required => "required",
-            ValidateRule::Ascii => "ascii",
-            ValidateRule::Alphanumeric => "alphanumeric",
-            ValidateRule::Email => "email",
-            ValidateRule::Url => "url",
+            ValidateRule::Ascii(_) => "ascii",
+            ValidateRule::AsciiPrintable => "ascii",
+            ValidateRule::Alphanumeric(_) => "alphanumeric",
+            ValidateRule::Lowercase => "lowercase",
+            ValidateRule::Uppercase => "uppercase",
+            ValidateRule::StartsWithUppercase => "starts_with_uppercase",
+            ValidateRule::Titlecase => "titlecase",
+            ValidateRule::NotBlank => "not_blank",
+            ValidateRule::Email(_) => "email",
+            ValidateRule::Url(_) => "url",
             ValidateRule::Ip => "ip",
             ValidateRule::IpV4 => "ip",
             ValidateRule::IpV6 => "ip",
             ValidateRule::CreditCard => "credit_card",
             ValidateRule::PhoneNumber => "phone_number",
+            ValidateRule::Checksum(_) => "checksum",
+            ValidateRule::Isbn => "isbn",
+            ValidateRule::Imei => "imei",
+            ValidateRule::E164 => "e164",
+            ValidateRule::Hostname => "hostname",
+            ValidateRule::Luhn => "luhn",
+            ValidateRule::Semver(_) => "semver",
+            ValidateRule::NoControlChars(_) => "no_control_chars",
+            ValidateRule::Base64(_) => "base64",
+            ValidateRule::Hex(_) => "hex",
             ValidateRule::Length { .. } => "length",
             ValidateRule::ByteLength { .. } => "byte_length",
+            ValidateRule::Words(_) => "words",
             ValidateRule::Range { .. } => "range",
+            ValidateRule::Finite => "finite",
+            ValidateRule::Numeric(_) => "numeric",
             ValidateRule::Contains(_) => "contains",
+            ValidateRule::ContainsKey(_) => "contains_key",
+            ValidateRule::ContainsValue(_) => "contains_value",
             ValidateRule::Prefix(_) => "prefix",
             ValidateRule::Suffix(_) => "suffix",
-            ValidateRule::Pattern(_) => "pattern",
+            ValidateRule::Extension(_) => "extension",
+            ValidateRule::OneOf(_) => "one_of",
+            ValidateRule::Excludes(_) => "excludes",
+            ValidateRule::Pattern(..) => "pattern",
+            ValidateRule::FsPath(_) => "fs_path",
+            ValidateRule::Date(_) => "date",
+            ValidateRule::Variant(_) => "variant",
+            ValidateRule::EnumValue(_) => "enum_value",
         }
     }
 }
 
+pub struct EnumVariant {
+    /// The enum type, e.g. `Status<'a>`.
+    pub ty: Type,
+    /// The path of the variant, usable in a pattern, e.g. `Status::Active`.
+    pub path: Path,
+    pub name: String,
+}
+
 pub enum ValidatePattern {
+    #[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
     Lit(String),
     Expr(Expr),
 }
@@ -268,36 +682,35 @@ impl ValidateVariant {
     pub fn empty() -> Self {
         Self::Tuple(Vec::new())
     }
-}
 
-impl PartialEq for ValidateRule {
-    fn eq(&self, other: &Self) -> bool {
-        core::mem::discriminant(self) == core::mem::discriminant(other)
+    pub fn rule_count(&self) -> usize {
+        match self {
+            ValidateVariant::Struct(fields) => fields.iter().map(|(_, f)| f.rule_count()).sum(),
+            ValidateVariant::Tuple(fields) => fields.iter().map(|f| f.rule_count()).sum(),
+        }
     }
 }
 
-impl Eq for ValidateRule {}
-
-impl ValidateRule {
-    fn discriminant(&self) -> u8 {
-        // SAFETY: Because `Self` is marked `repr(u8)`, its layout is a `repr(C)`
-        // `union` between `repr(C)` structs, each of which has the `u8`
-        // discriminant as its first field, so we can read the discriminant
-        // without offsetting the pointer.
-        unsafe { <*const _>::from(self).cast::<u8>().read() }
+impl ValidateKind {
+    /// Upper bound on the number of errors emitted by top-level rules,
+    /// used as a capacity hint for the report.
+    pub fn rule_count(&self) -> usize {
+        match self {
+            ValidateKind::Struct(variant) => variant.rule_count(),
+            ValidateKind::Enum(variants) => variants
+                .iter()
+                .map(|(_, v)| v.rule_count())
+                .max()
+                .unwrap_or(0),
+        }
     }
 }
 
-impl PartialOrd for ValidateRule {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-impl Ord for ValidateRule {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // `ValidateRuleKind` is intentionally only compared by the discriminant,
+impl PartialEq for ValidateRule {
+    fn eq(&self, other: &Self) -> bool {
+        // `ValidateRule` is intentionally only compared by the discriminant,
         // because we want there to only be one of each kind, without caring about
         // the value.
-        self.discriminant().cmp(&other.discriminant())
+        core::mem::discriminant(self) == core::mem::discriminant(other)
     }
 }
@@ -0,0 +1,388 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt;
+use syn::Type;
+
+use crate::model;
+use crate::syntax::type_category;
+
+/// Implements `garde::reflection::Reflect`, describing the rules of every field.
+pub struct Reflect<'a>(pub &'a model::Validate);
+
+impl<'a> ToTokens for Reflect<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.0.ident;
+        let (impl_generics, ty_generics, where_clause) = self.0.generics.split_for_impl();
+
+        let mut fields = Vec::new();
+        match &self.0.kind {
+            model::ValidateKind::Struct(variant) => variant_fields(variant, None, &mut fields),
+            model::ValidateKind::Enum(variants) => {
+                for (name, variant) in variants {
+                    variant_fields(variant, Some(&name.to_string()), &mut fields);
+                }
+            }
+        }
+
+        quote! {
+            impl #impl_generics ::garde::reflection::Reflect for #ident #ty_generics #where_clause {
+                fn rules() -> &'static [::garde::reflection::FieldRuleMeta] {
+                    const RULES: &[::garde::reflection::FieldRuleMeta] = &[#(#fields),*];
+                    RULES
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+fn variant_fields(
+    variant: &model::ValidateVariant,
+    prefix: Option<&str>,
+    out: &mut Vec<TokenStream2>,
+) {
+    let name = |name: String| match prefix {
+        Some(prefix) => format!("{prefix}.{name}"),
+        None => name,
+    };
+    match variant {
+        model::ValidateVariant::Struct(fields) => {
+            for (ident, field) in fields {
+                out.extend(field_meta(name(ident.unraw().to_string()), field));
+            }
+        }
+        model::ValidateVariant::Tuple(fields) => {
+            for (index, field) in fields.iter().enumerate() {
+                out.extend(field_meta(name(index.to_string()), field));
+            }
+        }
+    }
+}
+
+fn field_meta(name: String, field: &model::ValidateField) -> Option<TokenStream2> {
+    if field.skip.is_some() {
+        return None;
+    }
+
+    let category = match type_category(&field.ty) {
+        Some((category, _)) => {
+            let category = match category {
+                model::Category::Strings => quote!(Strings),
+                model::Category::Numbers => quote!(Numbers),
+                model::Category::Lists => quote!(Lists),
+                model::Category::Maps => quote!(Maps),
+                model::Category::Paths => quote!(Paths),
+            };
+            quote!(::core::option::Option::Some(::garde::reflection::Category::#category))
+        }
+        None => quote!(::core::option::Option::None),
+    };
+    let optional = is_option(&field.ty);
+    let integer = is_integer(&field.ty);
+
+    let mut rules = rule_set_meta(&field.rule_set);
+    if field.dive.is_some() {
+        rules.push(rule_meta(
+            if field.flatten.is_some() {
+                "flatten"
+            } else {
+                "dive"
+            },
+            &[],
+        ));
+    }
+    let mut inner = match &field.rule_set.inner {
+        Some(inner) => rule_set_meta(inner),
+        None => Vec::new(),
+    };
+    // Rules inside of `optional(..)` apply to the value, just like most rules on an `Option` field.
+    if let Some(optional) = &field.rule_set.optional {
+        rules.extend(rule_set_meta(optional));
+        if optional.dive.is_some() {
+            rules.push(rule_meta("dive", &[]));
+        }
+        if let Some(optional_inner) = &optional.inner {
+            inner.extend(rule_set_meta(optional_inner));
+        }
+    }
+
+    Some(quote! {
+        ::garde::reflection::FieldRuleMeta {
+            name: #name,
+            category: #category,
+            optional: #optional,
+            integer: #integer,
+            rules: &[#(#rules),*],
+            inner: &[#(#inner),*],
+        }
+    })
+}
+
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(v) => is_option(&v.elem),
+        Type::Paren(v) => is_option(&v.elem),
+        Type::Group(v) => is_option(&v.elem),
+        Type::Path(v) => v
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Whether the type is an integer primitive, looking through the same wrappers as `type_category`.
+fn is_integer(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(v) => is_integer(&v.elem),
+        Type::Paren(v) => is_integer(&v.elem),
+        Type::Group(v) => is_integer(&v.elem),
+        Type::Path(v) => {
+            let Some(segment) = v.path.segments.last() else {
+                return false;
+            };
+            match segment.ident.to_string().as_str() {
+                "Option" | "Box" | "Rc" | "Arc" | "Cow" => {
+                    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                        return false;
+                    };
+                    args.args.iter().any(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => is_integer(ty),
+                        _ => false,
+                    })
+                }
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+                | "i128" | "isize" => true,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+fn rule_set_meta(rule_set: &model::RuleSet) -> Vec<TokenStream2> {
+    rule_set
+        .rules
+        .iter()
+        .map(|rule| match rule {
+            model::FieldRule::Rule(rule) => rule_meta(rule.name(), &params(rule)),
+            model::FieldRule::Not(rule) => {
+                rule_meta(&format!("not.{}", rule.name()), &params(rule))
+            }
+            model::FieldRule::Custom(custom) => {
+                rule_meta("custom", &[("func", source(&custom.func))])
+            }
+        })
+        .collect()
+}
+
+fn rule_meta(name: &str, params: &[(&str, String)]) -> TokenStream2 {
+    let params = params.iter().map(|(key, value)| quote!((#key, #value)));
+    quote! {
+        ::garde::reflection::RuleMeta {
+            name: #name,
+            params: &[#(#params),*],
+        }
+    }
+}
+
+/// The source text of an argument.
+fn source(value: &impl ToTokens) -> String {
+    value.to_token_stream().to_string()
+}
+
+fn range<T>(
+    range: &model::ValidateRange<T>,
+    source: impl Fn(&T) -> String,
+) -> Vec<(&'static str, String)> {
+    match range {
+        model::ValidateRange::GreaterThan(min) => vec![("min", source(min))],
+        model::ValidateRange::LowerThan(max) => vec![("max", source(max))],
+        model::ValidateRange::Between(min, max) => vec![("min", source(min)), ("max", source(max))],
+    }
+}
+
+/// Literal lengths are printed without their `usize` suffix.
+fn length(bound: &model::Either<usize, syn::Expr>) -> String {
+    match bound {
+        model::Either::Left(value) => value.to_string(),
+        model::Either::Right(expr) => source(expr),
+    }
+}
+
+fn params(rule: &model::ValidateRule) -> Vec<(&'static str, String)> {
+    use model::ValidateRule::*;
+    let optional = |key: &'static str, value: &Option<syn::Expr>| {
+        value.iter().map(|value| (key, source(value))).collect()
+    };
+    match rule {
+        Required | Lowercase | Uppercase | CreditCard | PhoneNumber | Isbn | Imei | E164
+        | AsciiPrintable | Finite | StartsWithUppercase | Titlecase | NotBlank | Hostname
+        | Luhn => Vec::new(),
+        Ascii(extra) | Alphanumeric(extra) => optional("extra", extra),
+        NoControlChars(allow) => optional("allow", allow),
+        Semver(options) => match options {
+            Some(options) => {
+                let mut params = Vec::new();
+                if let Some(min) = &options.min {
+                    params.push(("min", min.value.clone()));
+                }
+                if let Some(max) = &options.max {
+                    params.push(("max", max.value.clone()));
+                }
+                params
+            }
+            None => Vec::new(),
+        },
+        Email(options) => match options {
+            Some(options) => vec![("max_len", source(&options.max_len))],
+            None => Vec::new(),
+        },
+        Url(options) => match options {
+            Some(options) => [
+                ("schemes", &options.schemes),
+                ("host", &options.host),
+                ("host_suffix", &options.host_suffix),
+                ("max_len", &options.max_len),
+            ]
+            .into_iter()
+            .flat_map(|(key, value)| optional(key, value))
+            .collect(),
+            None => Vec::new(),
+        },
+        Ip => vec![("kind", "any".into())],
+        IpV4 => vec![("kind", "v4".into())],
+        IpV6 => vec![("kind", "v6".into())],
+        Checksum(algo) => match algo {
+            model::ChecksumAlgo::Mod10 => vec![("algorithm", "mod10".into())],
+            model::ChecksumAlgo::Mod11 => vec![("algorithm", "mod11".into())],
+        },
+        Base64(decoded_len) | Hex(decoded_len) => optional("decoded_len", decoded_len),
+        Length(v) | ByteLength(v) => range(v, length),
+        Words((v, separator)) => {
+            let mut params = range(v, length);
+            params.extend(optional("separator", separator));
+            params
+        }
+        Range((v, _, _)) => range(v, source),
+        Numeric(numeric) => {
+            let mode = match numeric.mode {
+                model::NumericMode::Int => "int",
+                model::NumericMode::Float => "float",
+            };
+            let mut params = vec![("mode", mode.into())];
+            params.extend(optional("min", &numeric.min));
+            params.extend(optional("max", &numeric.max));
+            params
+        }
+        Contains(needle) | Prefix(needle) | Suffix(needle) => match needle {
+            model::Needle::One(expr) => vec![("value", source(expr))],
+            model::Needle::Any(expr) => vec![("any", source(expr))],
+            model::Needle::All(expr) => vec![("all", source(expr))],
+        },
+        ContainsKey(expr) => vec![("key", source(expr))],
+        ContainsValue(expr) => vec![("value", source(expr))],
+        Extension(expr) => vec![("extensions", source(expr))],
+        OneOf(expr) => vec![("values", source(expr))],
+        Excludes(excludes) => {
+            let mut params = vec![("terms", source(&excludes.terms))];
+            if excludes.ignore_case {
+                params.push(("ignore_case", "true".into()));
+            }
+            params
+        }
+        Pattern((pattern, require)) => {
+            let mut params = match pattern {
+                #[cfg(any(feature = "regex", feature = "regex-lite", feature = "fancy-regex"))]
+                model::ValidatePattern::Lit(s) => vec![("pattern", s.clone())],
+                // Not a regex, but e.g. the name of a `static` matcher.
+                model::ValidatePattern::Expr(expr) => vec![("matcher", source(expr))],
+            };
+            if !require.is_empty() {
+                params.push(("require", require.join(", ")));
+            }
+            params
+        }
+        FsPath(fs_path) => {
+            let mut params = Vec::new();
+            match fs_path.anchor {
+                Some(model::FsPathAnchor::Absolute) => params.push(("anchor", "absolute".into())),
+                Some(model::FsPathAnchor::Relative) => params.push(("anchor", "relative".into())),
+                None => {}
+            }
+            params.extend(optional("extensions", &fs_path.extensions));
+            params
+        }
+        Date(date) => {
+            let tense = match date.tense {
+                model::DateTense::Past => "past",
+                model::DateTense::Future => "future",
+            };
+            let mut params = vec![("tense", tense.into())];
+            params.extend(optional("now", &date.now));
+            params
+        }
+        Variant(variant) => vec![("variant", variant.name.clone())],
+        EnumValue(ty) => vec![("enum", source(ty))],
+    }
+}
+
+/// Implements `garde::schema::JsonSchema` for structs, see `garde::schema::object`.
+#[cfg(feature = "schema")]
+pub struct JsonSchema<'a>(pub &'a model::Validate);
+
+#[cfg(feature = "schema")]
+impl<'a> ToTokens for JsonSchema<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let model::ValidateKind::Struct(variant) = &self.0.kind else {
+            return;
+        };
+        let ident = &self.0.ident;
+        let (impl_generics, ty_generics, where_clause) = self.0.generics.split_for_impl();
+
+        let fields: Vec<(String, &model::ValidateField)> = match variant {
+            model::ValidateVariant::Struct(fields) => fields
+                .iter()
+                .map(|(ident, field)| (ident.unraw().to_string(), field))
+                .collect(),
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| (index.to_string(), field))
+                .collect(),
+        };
+        let nested = fields
+            .iter()
+            .filter(|(_, field)| {
+                let optional_dive = field
+                    .rule_set
+                    .optional
+                    .as_ref()
+                    .is_some_and(|optional| optional.dive.is_some());
+                field.skip.is_none() && (field.dive.is_some() || optional_dive)
+            })
+            .map(|(name, field)| {
+                let ty = &field.ty;
+                quote!((#name, || {
+                    #[allow(unused_imports)]
+                    use ::garde::schema::{NestedSchema as _, NoSchema as _};
+                    (&::garde::schema::Probe::<#ty>::new()).nested_schema()
+                }))
+            });
+
+        quote! {
+            impl #impl_generics ::garde::schema::JsonSchema for #ident #ty_generics #where_clause {
+                fn json_schema() -> ::garde::external::serde_json::Value {
+                    ::garde::schema::guard::<Self>(|| {
+                        ::garde::schema::object(
+                            <Self as ::garde::reflection::Reflect>::rules(),
+                            &[#(#nested),*],
+                        )
+                    })
+                }
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
@@ -1,6 +1,4 @@
-use std::collections::BTreeMap;
-
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream};
 use syn::ext::IdentExt;
 use syn::parse::Parse;
 use syn::punctuated::Punctuated;
@@ -24,9 +22,17 @@ pub fn parse(input: DeriveInput) -> syn::Result<model::Input> {
             Vec::new()
         }
     };
+    let defaults = attrs
+        .iter()
+        .filter_map(|(_, attr)| match attr {
+            model::Attr::DefaultRules(defaults) => Some(defaults.as_slice()),
+            _ => None,
+        })
+        .next()
+        .unwrap_or_default();
     let kind = match &input.data {
-        syn::Data::Struct(v) => parse_struct(v),
-        syn::Data::Enum(v) => parse_enum(v),
+        syn::Data::Struct(v) => parse_struct(v, defaults),
+        syn::Data::Enum(v) => parse_enum(v, defaults),
         syn::Data::Union(v) => parse_union(v),
     };
     let kind = match kind {
@@ -100,15 +106,151 @@ impl Parse for model::Attr {
                 Ok(model::Attr::Context(Box::new(ty), ident))
             }
             "allow_unvalidated" => Ok(model::Attr::AllowUnvalidated),
+            "try_from" => Ok(model::Attr::TryFrom),
+            "transparent" => Ok(model::Attr::Transparent),
+            "code_prefix" => {
+                input.parse::<Token![=]>()?;
+                let value = input.parse::<syn::LitStr>()?;
+                Ok(model::Attr::CodePrefix(value.value()))
+            }
+            "bound" => {
+                input.parse::<Token![=]>()?;
+                let value = input.parse::<syn::LitStr>()?;
+                let predicates = value
+                    .parse_with(Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated)?;
+                Ok(model::Attr::Bound(predicates.into_iter().collect()))
+            }
+            "custom" => {
+                let content;
+                syn::parenthesized!(content in input);
+                Ok(model::Attr::Custom(Box::new(content.parse()?)))
+            }
+            "at_least_one" => Ok(model::Attr::FieldGroup(parse_field_group(
+                input,
+                model::FieldGroupKind::AtLeastOne,
+            )?)),
+            "mutually_exclusive" => Ok(model::Attr::FieldGroup(parse_field_group(
+                input,
+                model::FieldGroupKind::MutuallyExclusive,
+            )?)),
+            "dependent_required" => Ok(model::Attr::DependentRequired(Box::new(
+                parse_dependent_required(input)?,
+            ))),
+            "default_rules" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let mut defaults = Vec::new();
+                while !content.is_empty() {
+                    let ident = Ident::parse_any(&content)?;
+                    let category = match ident.to_string().as_str() {
+                        "strings" => model::Category::Strings,
+                        "numbers" => model::Category::Numbers,
+                        "lists" => model::Category::Lists,
+                        "maps" => model::Category::Maps,
+                        "paths" => model::Category::Paths,
+                        _ => {
+                            return Err(syn::Error::new(
+                                ident.span(),
+                                "unrecognized category, expected one of `strings`, `numbers`, `lists`, `maps`, `paths`",
+                            ))
+                        }
+                    };
+                    let rules;
+                    syn::parenthesized!(rules in content);
+                    let rules = rules.parse::<TokenStream>()?;
+                    // Report syntax errors once here, instead of once per field.
+                    syn::parse::Parser::parse2(parse_rule_list(None), rules.clone())?;
+                    defaults.push((category, rules));
+                    if content.is_empty() {
+                        break;
+                    }
+                    content.parse::<Token![,]>()?;
+                }
+                Ok(model::Attr::DefaultRules(defaults))
+            }
             _ => Err(syn::Error::new(ident.span(), "unrecognized attribute")),
         }
     }
 }
 
-fn parse_struct(node: &syn::DataStruct) -> syn::Result<model::InputKind> {
+/// Parses `(<field>, ..., path = "...")`.
+fn parse_field_group(
+    input: syn::parse::ParseStream,
+    kind: model::FieldGroupKind,
+) -> syn::Result<model::FieldGroup> {
+    let content;
+    syn::parenthesized!(content in input);
+    let mut fields = Vec::new();
+    let mut path = None;
+    while !content.is_empty() {
+        if content.peek2(Token![=]) {
+            let key = content.parse::<Ident>()?;
+            if key != "path" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "unrecognized option, expected `path`",
+                ));
+            }
+            content.parse::<Token![=]>()?;
+            path = Some(content.parse::<syn::LitStr>()?.value());
+        } else {
+            fields.push(Ident::parse_any(&content)?);
+        }
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+    Ok(model::FieldGroup { kind, fields, path })
+}
+
+/// Parses `(if = <expr>, then = <field>)`.
+///
+/// The condition may also be given as a string literal, e.g. `if = "self.has_company"`.
+fn parse_dependent_required(
+    input: syn::parse::ParseStream,
+) -> syn::Result<model::DependentRequired> {
+    let content;
+    syn::parenthesized!(content in input);
+    let span = content.span();
+    let mut condition = None;
+    let mut field = None;
+    while !content.is_empty() {
+        let key = Ident::parse_any(&content)?;
+        content.parse::<Token![=]>()?;
+        match key.to_string().as_str() {
+            "if" => {
+                condition = Some(match content.parse::<syn::LitStr>() {
+                    Ok(lit) => lit.parse::<syn::Expr>()?,
+                    Err(_) => content.parse::<syn::Expr>()?,
+                })
+            }
+            "then" => field = Some(Ident::parse_any(&content)?),
+            _ => {
+                return Err(syn::Error::new(
+                    key.span(),
+                    "unrecognized option, expected `if` or `then`",
+                ))
+            }
+        }
+        if content.is_empty() {
+            break;
+        }
+        content.parse::<Token![,]>()?;
+    }
+    match (condition, field) {
+        (Some(condition), Some(field)) => Ok(model::DependentRequired { condition, field }),
+        (None, _) => Err(syn::Error::new(span, "missing `if = <condition>`")),
+        (_, None) => Err(syn::Error::new(span, "missing `then = <field>`")),
+    }
+}
+
+type DefaultRules = [(model::Category, TokenStream)];
+
+fn parse_struct(node: &syn::DataStruct, defaults: &DefaultRules) -> syn::Result<model::InputKind> {
     let mut error = None;
 
-    let fields = match parse_variant(&node.fields) {
+    let fields = match parse_variant(&node.fields, defaults) {
         Ok(Some(v)) => v,
         Ok(None) => {
             error.maybe_fold(syn::Error::new(
@@ -130,12 +272,12 @@ fn parse_struct(node: &syn::DataStruct) -> syn::Result<model::InputKind> {
     Ok(model::InputKind::Struct(fields))
 }
 
-fn parse_enum(node: &syn::DataEnum) -> syn::Result<model::InputKind> {
+fn parse_enum(node: &syn::DataEnum, defaults: &DefaultRules) -> syn::Result<model::InputKind> {
     let mut error = None;
     let mut variants = Vec::new();
 
     for variant in node.variants.iter() {
-        match parse_variant(&variant.fields) {
+        match parse_variant(&variant.fields, defaults) {
             Ok(Some(v)) => variants.push((variant.ident.clone(), v)),
             Ok(None) => {}
             Err(e) => error.maybe_fold(e),
@@ -156,23 +298,26 @@ fn parse_union(node: &syn::DataUnion) -> syn::Result<model::InputKind> {
     ))
 }
 
-fn parse_variant(fields: &syn::Fields) -> syn::Result<Option<model::Variant>> {
+fn parse_variant(
+    fields: &syn::Fields,
+    defaults: &DefaultRules,
+) -> syn::Result<Option<model::Variant>> {
     let mut error = None;
 
     let variant = match fields {
         syn::Fields::Named(v) => {
-            let mut fields = BTreeMap::new();
+            let mut fields = Vec::new();
             for field in v.named.iter() {
                 let ident = field.ident.clone().unwrap();
                 let ty = field.ty.clone();
-                let rules = match parse_field_attr_list(&field.attrs) {
+                let rules = match parse_field_attr_list(&field.attrs, &ty, defaults) {
                     Ok(v) => v,
                     Err(e) => {
                         error.maybe_fold(e);
                         Vec::new()
                     }
                 };
-                fields.insert(ident, model::Field { ty, rules });
+                fields.push((ident, model::Field { ty, rules }));
             }
             Some(model::Variant::Struct(fields))
         }
@@ -180,7 +325,7 @@ fn parse_variant(fields: &syn::Fields) -> syn::Result<Option<model::Variant>> {
             let mut fields = Vec::new();
             for field in v.unnamed.iter() {
                 let ty = field.ty.clone();
-                let rules = match parse_field_attr_list(&field.attrs) {
+                let rules = match parse_field_attr_list(&field.attrs, &ty, defaults) {
                     Ok(v) => v,
                     Err(e) => {
                         error.maybe_fold(e);
@@ -201,13 +346,17 @@ fn parse_variant(fields: &syn::Fields) -> syn::Result<Option<model::Variant>> {
     Ok(variant)
 }
 
-fn parse_field_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<model::RawRule>> {
+fn parse_field_attr_list(
+    attrs: &[syn::Attribute],
+    ty: &Type,
+    defaults: &DefaultRules,
+) -> syn::Result<Vec<model::RawRule>> {
     let mut error = None;
     let mut rules = Vec::new();
 
     for attr in attrs.iter() {
         if attr.path().is_ident("garde") {
-            match attr.parse_args_with(Punctuated::<_, syn::token::Comma>::parse_terminated) {
+            match attr.parse_args_with(parse_rule_list(Some(ty))) {
                 Ok(list) => {
                     for rule in list {
                         match rule {
@@ -225,9 +374,81 @@ fn parse_field_attr_list(attrs: &[syn::Attribute]) -> syn::Result<Vec<model::Raw
         return Err(error);
     }
 
+    apply_default_rules(&mut rules, ty, defaults)?;
+
     Ok(rules)
 }
 
+/// A parser for a comma-separated list of rules, which continues after a rule fails to parse.
+fn parse_rule_list(
+    ty: Option<&Type>,
+) -> impl FnOnce(syn::parse::ParseStream) -> syn::Result<Vec<ContinueOnFail<model::RawRule>>> + '_ {
+    move |input: syn::parse::ParseStream| {
+        let mut list = Vec::new();
+        while !input.is_empty() {
+            list.push(match parse_rule(input, ty) {
+                Ok(v) => ContinueOnFail::Ok(v),
+                Err(e) => ContinueOnFail::Err(e),
+            });
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        Ok(list)
+    }
+}
+
+/// Prepends the default rules of the field's category to `rules`.
+///
+/// Defaults are not applied to fields with `skip` or `skip_defaults`,
+/// and a default is left out if the field has a rule of the same kind.
+fn apply_default_rules(
+    rules: &mut Vec<model::RawRule>,
+    ty: &Type,
+    defaults: &DefaultRules,
+) -> syn::Result<()> {
+    let skip_defaults = rules
+        .iter()
+        .any(|rule| matches!(rule.kind, model::RawRuleKind::SkipDefaults));
+    rules.retain(|rule| !matches!(rule.kind, model::RawRuleKind::SkipDefaults));
+    if skip_defaults
+        || rules
+            .iter()
+            .any(|rule| matches!(rule.kind, model::RawRuleKind::Skip))
+    {
+        return Ok(());
+    }
+
+    let Some((category, _)) = type_category(ty) else {
+        return Ok(());
+    };
+    let mut error = None;
+    let mut applied = Vec::new();
+    for (_, tokens) in defaults.iter().filter(|(c, _)| *c == category) {
+        let list = syn::parse::Parser::parse2(parse_rule_list(Some(ty)), tokens.clone())?;
+        for rule in list {
+            match rule {
+                ContinueOnFail::Ok(rule) => {
+                    let kind = std::mem::discriminant(&rule.kind);
+                    if !rules
+                        .iter()
+                        .any(|own| std::mem::discriminant(&own.kind) == kind)
+                    {
+                        applied.push(rule);
+                    }
+                }
+                ContinueOnFail::Err(e) => error.maybe_fold(e),
+            }
+        }
+    }
+    if let Some(error) = error {
+        return Err(error);
+    }
+    rules.splice(0..0, applied);
+    Ok(())
+}
+
 enum ContinueOnFail<T> {
     Ok(T),
     Err(syn::Error),
@@ -247,58 +468,272 @@ where
 
 impl Parse for model::RawRule {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let ident = Ident::parse_any(input)?;
+        parse_rule(input, None)
+    }
+}
 
-        macro_rules! rules {
-            (($input:ident, $ident:ident) {
-                $($name:literal => $rule:ident $(($content:ident))?,)*
-            }) => {
-                match $ident.to_string().as_str() {
-                    $(
-                        $name => {
-                            $(
-                                let $content;
-                                syn::parenthesized!($content in $input);
-                            )?
-                            Ok(model::RawRule {
-                                span: $ident.span(),
-                                kind: model::RawRuleKind::$rule $(($content.parse()?))?
-                            })
-                        }
-                    )*
-                    _ => Err(syn::Error::new($ident.span(), "unrecognized validation rule")),
-                }
-            };
+/// Parses a single rule.
+///
+/// `ty` is the type of the field the rule is applied to, if known,
+/// and is only used to improve the error for unknown rules.
+fn parse_rule(input: syn::parse::ParseStream, ty: Option<&Type>) -> syn::Result<model::RawRule> {
+    let ident = Ident::parse_any(input)?;
+
+    macro_rules! content {
+        ($input:ident, $content:ident) => {{
+            let $content;
+            syn::parenthesized!($content in $input);
+            $content.parse()?
+        }};
+        ($input:ident, $content:ident =) => {{
+            if $input.peek(Token![=]) {
+                <Token![=]>::parse($input)?;
+                $input.parse()?
+            } else {
+                let $content;
+                syn::parenthesized!($content in $input);
+                $content.parse()?
+            }
+        }};
+        ($input:ident, $content:ident?) => {{
+            if $input.peek(syn::token::Paren) {
+                let $content;
+                syn::parenthesized!($content in $input);
+                Some($content.parse()?)
+            } else {
+                None
+            }
+        }};
+    }
+
+    macro_rules! rules {
+        (($input:ident, $ident:ident) {
+            $($name:literal => $rule:ident $(($content:ident $($optional:tt)?))?,)*
+        }) => {
+            match $ident.to_string().as_str() {
+                $(
+                    $name => {
+                        Ok(model::RawRule {
+                            span: $ident.span(),
+                            kind: model::RawRuleKind::$rule $((content!($input, $content $($optional)?)))?
+                        })
+                    }
+                )*
+                _ => Err(unknown_rule($input, &$ident, ty, &[$($name),*])),
+            }
+        };
+    }
+
+    rules! {
+        (input, ident) {
+            "skip" => Skip,
+            "skip_defaults" => SkipDefaults,
+            "rename" => Rename(content),
+            "message" => Message(content =),
+            "code" => Code(content =),
+            "label" => Label(content =),
+            "when" => When(content),
+            "skip_if" => SkipIf(content),
+            "dive" => Dive(content?),
+            "flatten" => Flatten,
+            "sensitive" => Sensitive,
+            "required" => Required,
+            "ascii" => Ascii(content?),
+            "alphanumeric" => Alphanumeric(content?),
+            "lowercase" => Lowercase,
+            "uppercase" => Uppercase,
+            "starts_with_uppercase" => StartsWithUppercase,
+            "titlecase" => Titlecase,
+            "not_blank" => NotBlank,
+            "email" => Email(content?),
+            "url" => Url(content?),
+            "ip" => Ip,
+            "ipv4" => IpV4,
+            "ipv6" => IpV6,
+            "credit_card" => CreditCard,
+            "phone_number" => PhoneNumber,
+            "checksum" => Checksum(content),
+            "isbn" => Isbn,
+            "imei" => Imei,
+            "luhn" => Luhn,
+            "e164" => E164,
+            "hostname" => Hostname,
+            "semver" => Semver(content?),
+            "no_control_chars" => NoControlChars(content?),
+            "base64" => Base64(content?),
+            "hex" => Hex(content?),
+            "length" => Length(content),
+            "byte_length" => ByteLength(content),
+            "words" => Words(content),
+            "range" => Range(content),
+            "finite" => Finite,
+            "numeric" => Numeric(content?),
+            "contains" => Contains(content),
+            "contains_key" => ContainsKey(content),
+            "contains_value" => ContainsValue(content),
+            "prefix" => Prefix(content),
+            "suffix" => Suffix(content),
+            "extension" => Extension(content),
+            "excludes" => Excludes(content),
+            "one_of" => OneOf(content),
+            "pattern" => Pattern(content),
+            "fs_path" => FsPath(content),
+            "date" => Date(content),
+            "variant" => Variant(content),
+            "enum_value" => EnumValue(content),
+            "custom" => Custom(content),
+            "inner" => Inner(content),
+            "optional" => Optional(content),
+            "keys" => Keys(content),
+            "not" => Not(content),
         }
+    }
+}
 
-        rules! {
-            (input, ident) {
-                "skip" => Skip,
-                "rename" => Rename(content),
-                "message" => Message(content),
-                "code" => Code(content),
-                "dive" => Dive,
-                "required" => Required,
-                "ascii" => Ascii,
-                "alphanumeric" => Alphanumeric,
-                "email" => Email,
-                "url" => Url,
-                "ip" => Ip,
-                "ipv4" => IpV4,
-                "ipv6" => IpV6,
-                "credit_card" => CreditCard,
-                "phone_number" => PhoneNumber,
-                "length" => Length(content),
-                "byte_length" => ByteLength(content),
-                "range" => Range(content),
-                "contains" => Contains(content),
-                "prefix" => Prefix(content),
-                "suffix" => Suffix(content),
-                "pattern" => Pattern(content),
-                "custom" => Custom(content),
-                "inner" => Inner(content),
+fn unknown_rule(
+    input: syn::parse::ParseStream,
+    ident: &Ident,
+    ty: Option<&Type>,
+    names: &[&str],
+) -> syn::Error {
+    // Skip the arguments of the unknown rule, so that they don't produce another error.
+    if input.peek(syn::token::Paren) {
+        let _ = input.parse::<proc_macro2::Group>();
+    }
+
+    let name = ident.to_string();
+    let mut message = format!("unrecognized validation rule `{name}`");
+    if let Some(suggestion) = crate::util::closest_match(&name, names.iter().copied()) {
+        message.push_str(&format!("\nhelp: did you mean `{suggestion}`?"));
+    }
+    if let Some((category, rules)) = ty.and_then(rule_category) {
+        message.push_str(&format!(
+            "\nnote: rules applicable to {category}: {}",
+            rules.join(", ")
+        ));
+    }
+    syn::Error::new(ident.span(), message)
+}
+
+/// Infers a set of applicable rules from the shape of a field's type.
+///
+/// This is a best-effort guess based on well-known type names,
+/// because the actual trait implementations are not visible to the derive.
+fn rule_category(ty: &Type) -> Option<(&'static str, Vec<&'static str>)> {
+    const STRING: &[&str] = &[
+        "ascii",
+        "alphanumeric",
+        "lowercase",
+        "uppercase",
+        "starts_with_uppercase",
+        "titlecase",
+        "email",
+        "url",
+        "ip",
+        "ipv4",
+        "ipv6",
+        "credit_card",
+        "phone_number",
+        "checksum",
+        "isbn",
+        "imei",
+        "luhn",
+        "e164",
+        "hostname",
+        "semver",
+        "no_control_chars",
+        "not_blank",
+        "base64",
+        "hex",
+        "length",
+        "byte_length",
+        "words",
+        "numeric",
+        "contains",
+        "prefix",
+        "suffix",
+        "extension",
+        "excludes",
+        "one_of",
+        "pattern",
+        "not",
+        "custom",
+    ];
+    const NUMBER: &[&str] = &["range", "finite", "enum_value", "custom"];
+    const LIST: &[&str] = &["length", "excludes", "inner", "dive", "custom"];
+    const MAP: &[&str] = &[
+        "length",
+        "contains_key",
+        "contains_value",
+        "keys",
+        "inner",
+        "dive",
+        "custom",
+    ];
+    const FS_PATH: &[&str] = &["fs_path", "custom"];
+
+    let (category, optional) = type_category(ty)?;
+    let (name, rules) = match category {
+        model::Category::Strings => ("strings", STRING),
+        model::Category::Numbers => ("numbers", NUMBER),
+        model::Category::Lists => ("lists", LIST),
+        model::Category::Maps => ("maps", MAP),
+        model::Category::Paths => ("filesystem paths", FS_PATH),
+    };
+    let mut rules = rules.to_vec();
+    if optional {
+        rules.splice(0..0, ["required", "optional"]);
+    }
+    Some((name, rules))
+}
+
+/// Infers the category of a field from the shape of its type,
+/// and whether it is wrapped in an `Option`.
+///
+/// This looks through references, `Option`, `Box`, `Rc`, `Arc`, and `Cow`.
+pub fn type_category(ty: &Type) -> Option<(model::Category, bool)> {
+    match ty {
+        Type::Reference(v) => type_category(&v.elem),
+        Type::Paren(v) => type_category(&v.elem),
+        Type::Group(v) => type_category(&v.elem),
+        Type::Slice(_) | Type::Array(_) => Some((model::Category::Lists, false)),
+        Type::Path(v) => {
+            let segment = v.path.segments.last()?;
+            match segment.ident.to_string().as_str() {
+                "Option" => {
+                    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                        return None;
+                    };
+                    match args.args.first()? {
+                        syn::GenericArgument::Type(ty) => {
+                            type_category(ty).map(|(category, _)| (category, true))
+                        }
+                        _ => None,
+                    }
+                }
+                "Box" | "Rc" | "Arc" | "Cow" => {
+                    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+                        return None;
+                    };
+                    args.args.iter().find_map(|arg| match arg {
+                        syn::GenericArgument::Type(ty) => type_category(ty),
+                        _ => None,
+                    })
+                }
+                "str" | "String" => Some((model::Category::Strings, false)),
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+                | "i128" | "isize" | "f32" | "f64" | "Duration" => {
+                    Some((model::Category::Numbers, false))
+                }
+                "Vec" | "VecDeque" | "HashSet" | "BTreeSet" | "IndexSet" => {
+                    Some((model::Category::Lists, false))
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => Some((model::Category::Maps, false)),
+                "Path" | "PathBuf" => Some((model::Category::Paths, false)),
+                _ => None,
             }
         }
+        _ => None,
     }
 }
 
@@ -312,6 +747,289 @@ impl Parse for model::Pattern {
     }
 }
 
+impl Parse for model::Excludes {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let terms = syn::Expr::parse(input)?;
+        let mut ignore_case = false;
+        if input.peek(Token![,]) {
+            <Token![,]>::parse(input)?;
+            if !input.is_empty() {
+                let ident = Ident::parse_any(input)?;
+                if ident != "ignore_case" {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "unexpected argument, expected `ignore_case`",
+                    ));
+                }
+                ignore_case = true;
+                if input.peek(Token![,]) {
+                    <Token![,]>::parse(input)?;
+                }
+            }
+        }
+        Ok(Self { terms, ignore_case })
+    }
+}
+
+impl Parse for model::PatternRule {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pattern = model::Pattern::parse(input)?;
+        let mut require = None;
+        if input.peek(Token![,]) {
+            <Token![,]>::parse(input)?;
+            if !input.is_empty() {
+                let ident = Ident::parse_any(input)?;
+                if ident != "require" {
+                    return Err(syn::Error::new(
+                        ident.span(),
+                        "unexpected argument, expected `require`",
+                    ));
+                }
+                <Token![=]>::parse(input)?;
+                let content;
+                syn::bracketed!(content in input);
+                require = Some(List::parse(&content)?);
+                if input.peek(Token![,]) {
+                    <Token![,]>::parse(input)?;
+                }
+            }
+        }
+        Ok(Self { pattern, require })
+    }
+}
+
+impl Parse for model::DiveOptions {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents = Punctuated::<Ident, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+        let mut required = None;
+        for ident in idents {
+            if ident == "required" && required.is_none() {
+                required = Some(ident.span());
+            } else if ident == "required" {
+                error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+            } else {
+                error.maybe_fold(syn::Error::new(
+                    ident.span(),
+                    "unexpected argument, expected `required`",
+                ));
+            }
+        }
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(Self { required }),
+        }
+    }
+}
+
+impl Parse for model::Extra {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        <Token![=]>::parse(input)?;
+        let expr = syn::Expr::parse(input)?;
+        if ident != "extra" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "unexpected argument, expected `extra`",
+            ));
+        }
+        Ok(Self { expr })
+    }
+}
+
+impl Parse for model::AsciiOptions {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek2(Token![=]) {
+            return Ok(Self::Extra(model::Extra::parse(input)?));
+        }
+        let ident = Ident::parse_any(input)?;
+        if ident != "printable" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "unexpected argument, expected `printable` or `extra`",
+            ));
+        }
+        Ok(Self::Printable)
+    }
+}
+
+impl Parse for model::AllowChars {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        <Token![=]>::parse(input)?;
+        let expr = syn::Expr::parse(input)?;
+        if ident != "allow" {
+            return Err(syn::Error::new(
+                ident.span(),
+                "unexpected argument, expected `allow`",
+            ));
+        }
+        Ok(Self { expr })
+    }
+}
+
+impl Parse for model::UrlOptions {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs =
+            syn::punctuated::Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut schemes = None::<syn::Expr>;
+        let mut host = None::<syn::Expr>;
+        let mut host_suffix = None::<syn::Expr>;
+        let mut max_len = None::<syn::Expr>;
+
+        for pair in pairs {
+            let slot = if pair.path.is_ident("schemes") {
+                &mut schemes
+            } else if pair.path.is_ident("host") {
+                &mut host
+            } else if pair.path.is_ident("host_suffix") {
+                &mut host_suffix
+            } else if pair.path.is_ident("max_len") {
+                &mut max_len
+            } else {
+                error.maybe_fold(syn::Error::new(
+                    pair.path.span(),
+                    "unexpected argument, expected one of `schemes`, `host`, `host_suffix`, `max_len`",
+                ));
+                continue;
+            };
+            if slot.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            *slot = Some(pair.value);
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::UrlOptions {
+                schemes,
+                host,
+                host_suffix,
+                max_len,
+            })
+        }
+    }
+}
+
+impl Parse for model::EmailOptions {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && !input.peek2(Token![=]) {
+            let ident = Ident::parse_any(input)?;
+            if ident == "mx" {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "`email(mx)` is not supported, because MX lookups are async; use `garde::rules::email::mx::apply` instead",
+                ));
+            }
+            return Err(syn::Error::new(
+                ident.span(),
+                "unexpected argument, expected `max_len`",
+            ));
+        }
+        let pair = syn::MetaNameValue::parse(input)?;
+        if !pair.path.is_ident("max_len") {
+            return Err(syn::Error::new(
+                pair.path.span(),
+                "unexpected argument, expected `max_len`",
+            ));
+        }
+        Ok(model::EmailOptions {
+            max_len: pair.value,
+        })
+    }
+}
+
+impl Parse for model::When {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let condition = syn::Expr::parse(input)?;
+        let mut code = None;
+        let mut label = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key = Ident::parse_any(input)?;
+            input.parse::<Token![=]>()?;
+            let slot = match key.to_string().as_str() {
+                "code" => &mut code,
+                "label" => &mut label,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "unexpected argument, expected `code` or `label`",
+                    ))
+                }
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(key.span(), "duplicate argument"));
+            }
+            *slot = Some(model::Str::parse(input)?);
+        }
+        Ok(model::When {
+            condition,
+            code,
+            label,
+        })
+    }
+}
+
+impl Parse for model::SemverOptions {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut min = None;
+        let mut max = None;
+        while !input.is_empty() {
+            let key = Ident::parse_any(input)?;
+            input.parse::<Token![=]>()?;
+            let slot = match key.to_string().as_str() {
+                "min" => &mut min,
+                "max" => &mut max,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "unexpected argument, expected `min` or `max`",
+                    ))
+                }
+            };
+            if slot.is_some() {
+                return Err(syn::Error::new(key.span(), "duplicate argument"));
+            }
+            *slot = Some(model::Str::parse(input)?);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+        if min.is_none() && max.is_none() {
+            return Err(input.error("expected `min` or `max`"));
+        }
+        Ok(model::SemverOptions { min, max })
+    }
+}
+
+impl Parse for model::Needle {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let ident = Ident::parse_any(input)?;
+            <Token![=]>::parse(input)?;
+            let expr = syn::Expr::parse(input)?;
+            match ident.to_string().as_str() {
+                "any" => Ok(Self::Any(expr)),
+                "all" => Ok(Self::All(expr)),
+                _ => Err(syn::Error::new(
+                    ident.span(),
+                    "unexpected argument, expected `any` or `all`",
+                )),
+            }
+        } else {
+            Ok(Self::One(syn::Expr::parse(input)?))
+        }
+    }
+}
+
 impl Parse for model::Str {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         Ok(model::Str {
@@ -321,6 +1039,51 @@ impl Parse for model::Str {
     }
 }
 
+impl Parse for model::CustomRule {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let func = syn::Expr::parse(input)?;
+        let mut args = None;
+        let mut explicit_self = None;
+        while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let meta = syn::Meta::parse(input)?;
+            match meta {
+                syn::Meta::List(list) if list.path.is_ident("args") && args.is_none() => {
+                    args = Some(
+                        list.parse_args_with(Punctuated::<syn::Expr, Token![,]>::parse_terminated)?
+                            .into_iter()
+                            .collect::<Vec<_>>(),
+                    );
+                }
+                syn::Meta::Path(path) if path.is_ident("with_self") && explicit_self.is_none() => {
+                    explicit_self = Some(path.span());
+                }
+                meta => return Err(syn::Error::new(meta.path().span(), "unexpected argument")),
+            }
+        }
+        let args = args.unwrap_or_default();
+        // The signature of a function is not visible to the macro, so a function
+        // which takes the extra `&Self` parameter has to be marked with `with_self`.
+        let with_self = match &func {
+            syn::Expr::Closure(closure) => {
+                let with_self = closure.inputs.len() == args.len() + 3;
+                if let Some(span) = explicit_self.filter(|_| !with_self) {
+                    return Err(syn::Error::new(
+                        span,
+                        "a closure with `with_self` must take the value, `&Self` and the context before its `args`",
+                    ));
+                }
+                with_self
+            }
+            _ => explicit_self.is_some(),
+        };
+        Ok(Self {
+            func,
+            args,
+            with_self,
+        })
+    }
+}
+
 impl Parse for model::Message {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         if input.peek(syn::LitStr) {
@@ -336,22 +1099,36 @@ where
     T: FromExpr,
 {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let span = input.span();
-
-        let pairs =
-            syn::punctuated::Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+        let metas = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
 
         let mut error = None;
 
         let mut min = None::<T>;
         let mut max = None::<T>;
+        let mut min_span = None;
+        let mut combined_message = None;
 
-        for pair in pairs {
+        for meta in metas {
+            let pair = match meta {
+                syn::Meta::NameValue(pair) => pair,
+                syn::Meta::Path(path) if path.is_ident("combined_message") => {
+                    if combined_message.is_some() {
+                        error.maybe_fold(syn::Error::new(path.span(), "duplicate argument"));
+                    }
+                    combined_message = Some(path.span());
+                    continue;
+                }
+                meta => {
+                    error.maybe_fold(syn::Error::new(meta.path().span(), "unexpected argument"));
+                    continue;
+                }
+            };
             if pair.path.is_ident("min") {
                 if min.is_some() {
                     error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
                     continue;
                 }
+                let value_span = pair.value.span();
                 let value = match <T as FromExpr>::from_expr(pair.value) {
                     Ok(v) => v,
                     Err(e) => {
@@ -360,6 +1137,7 @@ where
                     }
                 };
                 min = Some(value);
+                min_span = Some(value_span);
             } else if pair.path.is_ident("max") {
                 if max.is_some() {
                     error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
@@ -382,7 +1160,287 @@ where
         if let Some(error) = error {
             Err(error)
         } else {
-            Ok(model::Range { span, min, max })
+            Ok(model::Range {
+                min,
+                max,
+                min_span,
+                combined_message,
+            })
+        }
+    }
+}
+
+impl Parse for model::Words {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let metas = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut separator = None::<syn::Expr>;
+        let mut range = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::new();
+        for meta in metas {
+            match meta {
+                syn::Meta::NameValue(pair) if pair.path.is_ident("separator") => {
+                    if separator.is_some() {
+                        return Err(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    }
+                    separator = Some(pair.value);
+                }
+                meta => range.push(meta),
+            }
+        }
+
+        // The remaining arguments are the bounds, which are parsed like the ones of `length`.
+        let range = syn::parse2(quote::ToTokens::into_token_stream(range))?;
+        Ok(Self { range, separator })
+    }
+}
+
+impl Parse for model::Encoded {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let args = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut decoded_len = None::<syn::Expr>;
+
+        for arg in args {
+            let arg_span = arg.path().span();
+            match arg {
+                syn::Meta::NameValue(pair) if pair.path.is_ident("decoded_len") => {
+                    if decoded_len.is_some() {
+                        error.maybe_fold(syn::Error::new(arg_span, "duplicate argument"));
+                        continue;
+                    }
+                    decoded_len = Some(pair.value);
+                }
+                _ => {
+                    error.maybe_fold(syn::Error::new(arg_span, "unexpected argument"));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::Encoded { decoded_len })
+        }
+    }
+}
+
+impl Parse for model::Checksum {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let args = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut algo = None::<model::ChecksumAlgo>;
+
+        for arg in args {
+            let arg_span = arg.path().span();
+            match arg {
+                syn::Meta::NameValue(pair) if pair.path.is_ident("algo") => {
+                    if algo.is_some() {
+                        error.maybe_fold(syn::Error::new(arg_span, "duplicate argument"));
+                        continue;
+                    }
+                    let value = match pair.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        }) => lit,
+                        other => {
+                            error.maybe_fold(syn::Error::new(other.span(), "expected a string"));
+                            continue;
+                        }
+                    };
+                    match value.value().as_str() {
+                        "mod10" => algo = Some(model::ChecksumAlgo::Mod10),
+                        "mod11" => algo = Some(model::ChecksumAlgo::Mod11),
+                        other => error.maybe_fold(syn::Error::new(
+                            value.span(),
+                            format!(
+                                "unknown checksum algorithm `{other}`, expected `mod10` or `mod11`"
+                            ),
+                        )),
+                    }
+                }
+                _ => {
+                    error.maybe_fold(syn::Error::new(arg_span, "unexpected argument"));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+        match algo {
+            Some(algo) => Ok(model::Checksum { algo }),
+            None => Err(syn::Error::new(
+                span,
+                "checksum requires `algo = \"mod10\"` or `algo = \"mod11\"`",
+            )),
+        }
+    }
+}
+
+impl Parse for model::Numeric {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let args = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut mode = None::<model::NumericMode>;
+        let mut min = None::<syn::Expr>;
+        let mut max = None::<syn::Expr>;
+
+        for arg in args {
+            let arg_span = arg.path().span();
+            match arg {
+                syn::Meta::Path(path) if path.is_ident("int") || path.is_ident("float") => {
+                    let value = match path.is_ident("int") {
+                        true => model::NumericMode::Int,
+                        false => model::NumericMode::Float,
+                    };
+                    if mode.is_some() {
+                        error.maybe_fold(syn::Error::new(
+                            arg_span,
+                            "`int` and `float` may only be specified once",
+                        ));
+                        continue;
+                    }
+                    mode = Some(value);
+                }
+                syn::Meta::NameValue(pair) if pair.path.is_ident("min") => {
+                    if min.is_some() {
+                        error.maybe_fold(syn::Error::new(arg_span, "duplicate argument"));
+                        continue;
+                    }
+                    min = Some(pair.value);
+                }
+                syn::Meta::NameValue(pair) if pair.path.is_ident("max") => {
+                    if max.is_some() {
+                        error.maybe_fold(syn::Error::new(arg_span, "duplicate argument"));
+                        continue;
+                    }
+                    max = Some(pair.value);
+                }
+                _ => {
+                    error.maybe_fold(syn::Error::new(arg_span, "unexpected argument"));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::Numeric {
+                mode: mode.unwrap_or_default(),
+                min,
+                max,
+            })
+        }
+    }
+}
+
+impl Parse for model::FsPath {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let args = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut anchor = None::<model::FsPathAnchor>;
+        let mut extensions = None::<syn::Expr>;
+
+        for arg in args {
+            let arg_span = arg.path().span();
+            match arg {
+                syn::Meta::Path(path) if path.is_ident("absolute") || path.is_ident("relative") => {
+                    let value = match path.is_ident("absolute") {
+                        true => model::FsPathAnchor::Absolute,
+                        false => model::FsPathAnchor::Relative,
+                    };
+                    if anchor.is_some() {
+                        error.maybe_fold(syn::Error::new(
+                            arg_span,
+                            "`absolute` and `relative` may only be specified once",
+                        ));
+                        continue;
+                    }
+                    anchor = Some(value);
+                }
+                syn::Meta::NameValue(pair) if pair.path.is_ident("extension") => {
+                    if extensions.is_some() {
+                        error.maybe_fold(syn::Error::new(arg_span, "duplicate argument"));
+                        continue;
+                    }
+                    extensions = Some(pair.value);
+                }
+                _ => {
+                    error.maybe_fold(syn::Error::new(arg_span, "unexpected argument"));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::FsPath { anchor, extensions })
+        }
+    }
+}
+
+impl Parse for model::Date {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let args = syn::punctuated::Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut tense = None::<model::DateTense>;
+        let mut now = None::<syn::Expr>;
+
+        for arg in args {
+            let arg_span = arg.path().span();
+            match arg {
+                syn::Meta::Path(path) if path.is_ident("past") || path.is_ident("future") => {
+                    let value = match path.is_ident("past") {
+                        true => model::DateTense::Past,
+                        false => model::DateTense::Future,
+                    };
+                    if tense.is_some() {
+                        error.maybe_fold(syn::Error::new(
+                            arg_span,
+                            "`past` and `future` may only be specified once",
+                        ));
+                        continue;
+                    }
+                    tense = Some(value);
+                }
+                syn::Meta::NameValue(pair) if pair.path.is_ident("now") => {
+                    if now.is_some() {
+                        error.maybe_fold(syn::Error::new(arg_span, "duplicate argument"));
+                        continue;
+                    }
+                    now = Some(pair.value);
+                }
+                _ => {
+                    error.maybe_fold(syn::Error::new(arg_span, "unexpected argument"));
+                    continue;
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        match tense {
+            Some(tense) => Ok(model::Date { tense, now }),
+            None => Err(syn::Error::new(span, "expected `past` or `future`")),
         }
     }
 }
@@ -19,6 +19,7 @@ struct Person {
 #[derive(Serialize, Deserialize, Debug, Validate)]
 struct PathTuple(#[garde(length(min = 1))] pub String);
 
+#[allow(dead_code)]
 #[derive(Debug, Error)]
 enum CustomRejection {
     #[cfg_attr(feature = "json", error(transparent))]